@@ -18,5 +18,6 @@ criterion_group!(
     contrived,
     schedule,
     build_schedule,
+    update_schedule,
     empty_schedule_run,
 );
@@ -1,6 +1,6 @@
 use bevy_app::{App, Update};
 use bevy_ecs::prelude::*;
-use criterion::Criterion;
+use criterion::{BatchSize, Criterion};
 
 pub fn schedule(c: &mut Criterion) {
     #[derive(Component)]
@@ -119,6 +119,62 @@ pub fn build_schedule(criterion: &mut Criterion) {
     group.finish();
 }
 
+pub fn update_schedule(criterion: &mut Criterion) {
+    fn empty_system() {}
+
+    #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct NumSet(usize);
+
+    let mut group = criterion.benchmark_group("update_schedule");
+    group.warm_up_time(core::time::Duration::from_millis(500));
+    group.measurement_time(core::time::Duration::from_secs(15));
+
+    // Builds a schedule of `graph_size` systems that run one after the other.
+    let build = |graph_size: usize| {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        for i in 0..graph_size {
+            let mut system = empty_system.in_set(NumSet(i));
+            if i > 0 {
+                system = system.after(NumSet(i - 1));
+            }
+            schedule.add_systems(system);
+        }
+        schedule.initialize(&mut world).unwrap();
+        (world, schedule)
+    };
+
+    for graph_size in [100, 500, 1000] {
+        // Removing a system updates the executable schedule in place.
+        group.bench_function(format!("{graph_size}_remove_system"), |bencher| {
+            bencher.iter_batched(
+                || build(graph_size),
+                |(mut world, mut schedule)| {
+                    schedule.remove_systems_in_set(NumSet(graph_size / 2));
+                    schedule.initialize(&mut world).unwrap();
+                    (world, schedule)
+                },
+                BatchSize::LargeInput,
+            );
+        });
+
+        // Adding a system rebuilds the whole schedule.
+        group.bench_function(format!("{graph_size}_add_system"), |bencher| {
+            bencher.iter_batched(
+                || build(graph_size),
+                |(mut world, mut schedule)| {
+                    schedule.add_systems(empty_system.after(NumSet(graph_size / 2)));
+                    schedule.initialize(&mut world).unwrap();
+                    (world, schedule)
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
 pub fn empty_schedule_run(criterion: &mut Criterion) {
     let mut app = App::default();
 
@@ -1,6 +1,6 @@
 use crate::{
     First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    SubAppCommands, SubAppId, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -10,7 +10,7 @@ use alloc::{
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventRetention},
     intern::Interned,
     prelude::*,
     schedule::{InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
@@ -121,6 +121,7 @@ impl Default for App {
                 .run_if(bevy_ecs::event::event_update_condition),
         );
         app.add_event::<AppExit>();
+        app.init_resource::<SubAppCommands>();
 
         app
     }
@@ -141,6 +142,7 @@ impl App {
             sub_apps: SubApps {
                 main: SubApp::new(),
                 sub_apps: HashMap::default(),
+                dynamic_sub_apps: HashMap::default(),
             },
             runner: Box::new(run_once),
         }
@@ -363,6 +365,33 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling like [`add_event`](Self::add_event), with the given
+    /// [`EventRetention`] policy instead of the default one, which drops events after two updates.
+    ///
+    /// If the event was already added, only its policy is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{event::EventRetention, prelude::*};
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// // Keep events until every `EventReader<MyEvent>` has read them,
+    /// // even if some of them run in `FixedUpdate`.
+    /// app.add_event_with_retention::<MyEvent>(EventRetention::UntilRead);
+    /// ```
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.main_mut().add_event_with_retention::<T>(retention);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -1124,6 +1153,18 @@ impl App {
         self.sub_apps.sub_apps.remove(&label.intern())
     }
 
+    /// Returns a reference to the [`SubApp`] inserted with [`SubAppCommands::insert`] that has
+    /// the given id, if it exists.
+    pub fn get_sub_app_by_id(&self, id: SubAppId) -> Option<&SubApp> {
+        self.sub_apps.dynamic_sub_apps.get(&id)
+    }
+
+    /// Returns a mutable reference to the [`SubApp`] inserted with [`SubAppCommands::insert`]
+    /// that has the given id, if it exists.
+    pub fn get_sub_app_by_id_mut(&mut self, id: SubAppId) -> Option<&mut SubApp> {
+        self.sub_apps.dynamic_sub_apps.get_mut(&id)
+    }
+
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
     pub fn update_sub_app_by_label(&mut self, label: impl AppLabel) {
         self.sub_apps.update_subapp_by_label(label);
@@ -1422,6 +1463,7 @@ impl Termination for AppExit {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use core::marker::PhantomData;
     use std::sync::Mutex;
 
@@ -1438,7 +1480,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, AppLabel, Main, Plugin, SubApp, SubAppCommands, SubAppId, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1829,4 +1871,69 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn parallel_sub_apps() {
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+        struct Match(u32);
+
+        #[derive(Resource, Default)]
+        struct Ticks(u32);
+
+        #[derive(Resource)]
+        struct Source(u32);
+
+        let mut app = App::new();
+        app.insert_resource(Source(10));
+        for id in 0..4 {
+            let mut sub_app = SubApp::new();
+            sub_app.update_schedule = Some(Main.intern());
+            sub_app
+                .init_resource::<Ticks>()
+                .add_systems(Main, |mut ticks: ResMut<Ticks>| ticks.0 += 1)
+                .set_extract(|main_world, sub_world| {
+                    sub_world.resource_mut::<Ticks>().0 += main_world.resource::<Source>().0;
+                })
+                .set_parallel(id % 2 == 0);
+            app.insert_sub_app(Match(id), sub_app);
+        }
+
+        app.update();
+        app.update();
+
+        for id in 0..4 {
+            let world = app.sub_app(Match(id)).world();
+            assert_eq!(world.resource::<Ticks>().0, 22);
+        }
+    }
+
+    #[test]
+    fn sub_app_commands() {
+        #[derive(Resource, Default)]
+        struct Matches(Vec<SubAppId>);
+
+        fn spawn_matches(mut matches: ResMut<Matches>, mut commands: ResMut<SubAppCommands>) {
+            let mut sub_app = SubApp::new();
+            sub_app.set_parallel(true);
+            if let Some(&previous) = matches.0.last() {
+                commands.remove(previous);
+            }
+            matches.0.push(commands.insert(sub_app));
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Matches>()
+            .add_systems(Update, spawn_matches);
+
+        app.update();
+        let first = app.world().resource::<Matches>().0[0];
+        assert!(app.get_sub_app_by_id(first).is_some());
+        app.update();
+        let second = app.world().resource::<Matches>().0[1];
+        assert_ne!(first, second);
+        assert!(app.get_sub_app_by_id(first).is_none());
+        assert!(app.get_sub_app_by_id(second).unwrap().is_parallel());
+        assert_eq!(app.sub_apps().iter().count(), 2);
+        assert!(app.world().resource::<SubAppCommands>().is_empty());
+    }
 }
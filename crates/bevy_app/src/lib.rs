@@ -28,6 +28,7 @@ mod main_schedule;
 mod panic_handler;
 mod plugin;
 mod plugin_group;
+mod prefab_plugin;
 mod schedule_runner;
 mod sub_app;
 mod task_pool_plugin;
@@ -39,6 +40,7 @@ pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
 pub use plugin_group::*;
+pub use prefab_plugin::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
 pub use task_pool_plugin::*;
@@ -36,6 +36,9 @@ use bevy_ecs::{
 /// * [`PostUpdate`]
 /// * [`Last`]
 ///
+/// Before these schedules, it applies the commands queued by async tasks with
+/// [`World::apply_async_commands`].
+///
 /// # Rendering
 ///
 /// Note rendering is not executed in the main schedule by default.
@@ -330,6 +333,10 @@ impl Plugin for MainSchedulePlugin {
             use bevy_ecs::schedule::{IntoScheduleConfigs, Stepping};
             app.add_systems(Main, Stepping::begin_frame.before(Main::run_main));
         }
+
+        // Apply the commands queued by async tasks once per frame, before any other schedules.
+        #[cfg(feature = "std")]
+        app.add_systems(Main, World::apply_async_commands.before(Main::run_main));
     }
 }
 
@@ -0,0 +1,21 @@
+use crate::{App, Plugin, PostUpdate};
+use bevy_ecs::prefab::propagate_prefab_changes;
+
+/// Adds support for [prefabs](bevy_ecs::prefab): runs [`propagate_prefab_changes`] in
+/// [`PostUpdate`], so that changes to the components of a prefab are applied to its instances.
+///
+/// This plugin is part of the `DefaultPlugins`. Prefabs can be instantiated without it, but their
+/// instances are not updated when they change.
+#[derive(Default)]
+pub struct PrefabPlugin;
+
+impl Plugin for PrefabPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<bevy_ecs::prefab::Prefab>()
+            .register_type::<bevy_ecs::prefab::InstanceOf>()
+            .register_type::<bevy_ecs::prefab::Instances>();
+
+        app.add_systems(PostUpdate, propagate_prefab_changes);
+    }
+}
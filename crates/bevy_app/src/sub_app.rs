@@ -1,12 +1,14 @@
 use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventRetention},
     prelude::*,
     schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
     system::{ScheduleSystem, SystemId, SystemInput},
 };
 use bevy_platform_support::collections::{HashMap, HashSet};
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+use bevy_utils::synccell::SyncCell;
 use core::fmt::Debug;
 
 #[cfg(feature = "trace")]
@@ -74,6 +76,8 @@ pub struct SubApp {
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// Whether this app is updated in parallel with other parallel sub-apps.
+    parallel: bool,
 }
 
 impl Debug for SubApp {
@@ -94,6 +98,7 @@ impl Default for SubApp {
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            parallel: false,
         }
     }
 }
@@ -145,6 +150,26 @@ impl SubApp {
         self.world.clear_trackers();
     }
 
+    /// Sets whether [`SubApps::update`] updates this app in parallel with the other parallel
+    /// sub-apps, on the [`ComputeTaskPool`].
+    ///
+    /// Parallel sub-apps are still [extracted](Self::extract) one after another, but their
+    /// schedules then run at the same time, after the schedules of the other sub-apps. This is
+    /// only suitable for sub-apps whose systems don't need to run on the main thread, e.g.
+    /// because they access non-send resources.
+    ///
+    /// This is disabled by default.
+    pub fn set_parallel(&mut self, parallel: bool) -> &mut Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Returns `true` if this app is updated in parallel with other parallel sub-apps.
+    /// See [`set_parallel`](Self::set_parallel).
+    pub fn is_parallel(&self) -> bool {
+        self.parallel
+    }
+
     /// Extracts data from `world` into the app's world using the registered extract method.
     ///
     /// **Note:** There is no default extract method. Calling `extract` does nothing if
@@ -347,6 +372,15 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_with_retention`].
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        EventRegistry::register_event_with_retention::<T>(self.world_mut(), retention);
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         self.run_as_app(|app| plugins.add_to_app(app));
@@ -471,6 +505,9 @@ impl SubApp {
     }
 }
 
+/// The label or id of a sub-app, used to name its spans.
+type SubAppName = dyn Debug + Sync;
+
 /// The collection of sub-apps that belong to an [`App`].
 #[derive(Default)]
 pub struct SubApps {
@@ -478,11 +515,17 @@ pub struct SubApps {
     pub main: SubApp,
     /// Other, labeled sub-apps.
     pub sub_apps: HashMap<InternedAppLabel, SubApp>,
+    /// The sub-apps inserted with [`SubAppCommands`].
+    pub dynamic_sub_apps: HashMap<SubAppId, SubApp>,
 }
 
 impl SubApps {
-    /// Calls [`update`](SubApp::update) for the main sub-app, and then calls
-    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest.
+    /// Calls [`update`](SubApp::update) for the main sub-app, applies the [`SubAppCommands`]
+    /// queued by it, and then calls [`extract`](SubApp::extract) and [`update`](SubApp::update)
+    /// for the rest.
+    ///
+    /// The sub-apps are updated in an unspecified order, except that the sub-apps that are
+    /// [parallel](SubApp::set_parallel) are updated after all the others, at the same time.
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_update_span = info_span!("update").entered();
@@ -491,24 +534,84 @@ impl SubApps {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.main.run_default_schedule();
         }
-        for (_label, sub_app) in self.sub_apps.iter_mut() {
+        self.apply_commands();
+
+        let mut parallel = Vec::new();
+        let labeled = self
+            .sub_apps
+            .iter_mut()
+            .map(|(label, sub_app)| (label as &SubAppName, sub_app));
+        let dynamic = self
+            .dynamic_sub_apps
+            .iter_mut()
+            .map(|(id, sub_app)| (id as &SubAppName, sub_app));
+        for (_name, sub_app) in labeled.chain(dynamic) {
             #[cfg(feature = "trace")]
-            let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+            let _sub_app_span = info_span!("sub app", name = ?_name).entered();
             sub_app.extract(&mut self.main.world);
-            sub_app.update();
+            if sub_app.parallel {
+                parallel.push((_name, sub_app));
+            } else {
+                sub_app.update();
+            }
         }
+        Self::update_parallel(parallel);
 
         self.main.world.clear_trackers();
     }
 
+    /// Updates the given sub-apps at the same time on the [`ComputeTaskPool`].
+    fn update_parallel(mut sub_apps: Vec<(&SubAppName, &mut SubApp)>) {
+        if let [(_label, sub_app)] = &mut sub_apps[..] {
+            #[cfg(feature = "trace")]
+            let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+            sub_app.update();
+            return;
+        }
+        if sub_apps.is_empty() {
+            return;
+        }
+        ComputeTaskPool::get_or_init(TaskPool::default).scope(|scope| {
+            for (_label, sub_app) in sub_apps {
+                scope.spawn(async move {
+                    #[cfg(feature = "trace")]
+                    let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+                    sub_app.update();
+                });
+            }
+        });
+    }
+
+    /// Inserts and removes the sub-apps queued in the [`SubAppCommands`] of the main world.
+    fn apply_commands(&mut self) {
+        let Some(mut commands) = self.main.world.get_resource_mut::<SubAppCommands>() else {
+            return;
+        };
+        for (id, sub_app) in commands.queue.drain(..) {
+            match sub_app {
+                Some(sub_app) => {
+                    self.dynamic_sub_apps
+                        .insert(id, SyncCell::to_inner(sub_app));
+                }
+                None => {
+                    self.dynamic_sub_apps.remove(&id);
+                }
+            }
+        }
+    }
+
     /// Returns an iterator over the sub-apps (starting with the main one).
     pub fn iter(&self) -> impl Iterator<Item = &SubApp> + '_ {
-        core::iter::once(&self.main).chain(self.sub_apps.values())
+        core::iter::once(&self.main)
+            .chain(self.sub_apps.values())
+            .chain(self.dynamic_sub_apps.values())
     }
 
     /// Returns a mutable iterator over the sub-apps (starting with the main one).
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut SubApp> + '_ {
-        core::iter::once(&mut self.main).chain(self.sub_apps.values_mut())
+        core::iter::once(&mut self.main)
+            .chain(self.sub_apps.values_mut())
+            .chain(self.dynamic_sub_apps.values_mut())
     }
 
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
@@ -519,3 +622,77 @@ impl SubApps {
         }
     }
 }
+
+/// Identifies a [`SubApp`] that was inserted with [`SubAppCommands::insert`].
+///
+/// Unlike [`AppLabel`]s, ids are not interned, so sub-apps can be created and dropped at runtime
+/// without leaking memory. Ids are not reused within an [`App`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubAppId(u64);
+
+/// Resource that queues [`SubApp`]s to be inserted into or removed from the [`App`] while it
+/// runs.
+///
+/// The queued changes are applied by [`SubApps::update`] after the main app has been updated,
+/// so that systems can create and drop sub-apps, e.g. one per match of a game server. The
+/// inserted sub-apps are identified by a [`SubAppId`] rather than an [`AppLabel`]:
+///
+/// ```
+/// # use bevy_app::{App, Main, SubApp, SubAppCommands, SubAppId, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// #[derive(Resource)]
+/// struct Match(SubAppId);
+///
+/// fn start_match(mut commands: Commands, mut sub_apps: ResMut<SubAppCommands>) {
+///     let mut sub_app = SubApp::new();
+///     sub_app.update_schedule = Some(Main.intern());
+///     sub_app.add_systems(Main, || {
+///         // Simulate the match.
+///     });
+///     // Independent matches can be simulated at the same time.
+///     sub_app.set_parallel(true);
+///     let id = sub_apps.insert(sub_app);
+///     commands.insert_resource(Match(id));
+/// }
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, start_match);
+/// app.update();
+/// let id = app.world().resource::<Match>().0;
+/// assert!(app.get_sub_app_by_id(id).is_some());
+/// ```
+#[derive(Resource, Default)]
+pub struct SubAppCommands {
+    next_id: u64,
+    queue: Vec<(SubAppId, Option<SyncCell<SubApp>>)>,
+}
+
+impl SubAppCommands {
+    /// Queues `sub_app` to be inserted, and returns the id that identifies it once it has been
+    /// inserted.
+    pub fn insert(&mut self, sub_app: SubApp) -> SubAppId {
+        let id = SubAppId(self.next_id);
+        self.next_id += 1;
+        self.queue.push((id, Some(SyncCell::new(sub_app))));
+        id
+    }
+
+    /// Queues the sub-app with the given id to be removed and dropped, if it exists.
+    pub fn remove(&mut self, id: SubAppId) {
+        self.queue.push((id, None));
+    }
+
+    /// Returns `true` if no changes are queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl Debug for SubAppCommands {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SubAppCommands")
+            .field("queued", &self.queue.len())
+            .finish()
+    }
+}
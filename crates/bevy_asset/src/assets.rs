@@ -1,12 +1,19 @@
 use crate::asset_changed::AssetChanges;
-use crate::{Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer, Handle, UntypedHandle};
+use crate::{
+    Asset, AssetEvent, AssetEvictedEvent, AssetEvictionReason, AssetHandleProvider, AssetId,
+    AssetRetentionPolicy, AssetServer, AssetSize, Handle, RetainedAsset, UntypedAssetId,
+    UntypedHandle,
+};
 use alloc::{sync::Arc, vec::Vec};
 use bevy_ecs::{
     prelude::EventWriter,
     resource::Resource,
     system::{Res, ResMut, SystemChangeTick},
 };
-use bevy_platform_support::collections::HashMap;
+use bevy_platform_support::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
 use bevy_reflect::{Reflect, TypePath};
 use core::{any::TypeId, iter::Enumerate, marker::PhantomData, sync::atomic::AtomicU32};
 use crossbeam_channel::{Receiver, Sender};
@@ -280,6 +287,9 @@ impl<A: Asset> DenseAssetStorage<A> {
 /// Assets identified by [`AssetId::Uuid`] will be stored in a hashmap. This is less efficient, but it means that the assets can be referenced
 /// at compile time.
 ///
+/// By default, an asset is removed from the collection once its last strong [`Handle`] is dropped. An
+/// [`AssetRetentionPolicy`] can be set to keep unused assets cached for a while instead.
+///
 /// This tracks (and queues) [`AssetEvent`] events whenever changes to the collection occur.
 /// To check whether the asset used by a given component has changed (due to a change in the handle or the underlying asset)
 /// use the [`AssetChanged`](crate::asset_changed::AssetChanged) query filter.
@@ -292,6 +302,17 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    retention_policy: AssetRetentionPolicy<A>,
+    /// Assets without live strong `Handle`s that are kept alive by the `retention_policy`.
+    retained: HashMap<AssetId<A>, RetainedAsset>,
+    queued_evictions: Vec<AssetEvictedEvent<A>>,
+    /// The size of every asset as measured by the memory budget of the `retention_policy`, if it
+    /// has one. Kept up to date as assets are inserted and removed.
+    sizes: HashMap<AssetId<A>, usize>,
+    /// The sum of `sizes`.
+    total_size: usize,
+    /// Assets that were mutably accessed since their size was last measured.
+    resized: HashSet<AssetId<A>>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -305,6 +326,12 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            retention_policy: Default::default(),
+            retained: Default::default(),
+            queued_evictions: Default::default(),
+            sizes: Default::default(),
+            total_size: 0,
+            resized: Default::default(),
         }
     }
 }
@@ -357,6 +384,7 @@ impl<A: Asset> Assets<A> {
 
     pub(crate) fn insert_with_uuid(&mut self, uuid: Uuid, asset: A) -> Option<A> {
         let result = self.hash_map.insert(uuid, asset);
+        self.measure(uuid.into());
         if result.is_some() {
             self.queued_events
                 .push(AssetEvent::Modified { id: uuid.into() });
@@ -372,6 +400,7 @@ impl<A: Asset> Assets<A> {
         asset: A,
     ) -> Result<bool, InvalidGenerationError> {
         let replaced = self.dense_storage.insert(index, asset)?;
+        self.measure(index.into());
         if replaced {
             self.queued_events
                 .push(AssetEvent::Modified { id: index.into() });
@@ -433,6 +462,9 @@ impl<A: Asset> Assets<A> {
         };
         if result.is_some() {
             self.queued_events.push(AssetEvent::Modified { id });
+            if self.retention_policy.budget().is_some() {
+                self.resized.insert(id);
+            }
         }
         result
     }
@@ -453,14 +485,16 @@ impl<A: Asset> Assets<A> {
     pub fn remove_untracked(&mut self, id: impl Into<AssetId<A>>) -> Option<A> {
         let id: AssetId<A> = id.into();
         self.duplicate_handles.remove(&id);
-        match id {
+        let result = match id {
             AssetId::Index { index, .. } => self.dense_storage.remove_still_alive(index),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid),
-        }
+        };
+        self.measure(id);
+        result
     }
 
-    /// Removes the [`Asset`] with the given `id`.
-    pub(crate) fn remove_dropped(&mut self, id: AssetId<A>) {
+    /// Removes the [`Asset`] with the given `id`. Returns `true` if the asset was removed.
+    pub(crate) fn remove_dropped(&mut self, id: AssetId<A>) -> bool {
         match self.duplicate_handles.get_mut(&id) {
             None => {}
             Some(0) => {
@@ -468,7 +502,7 @@ impl<A: Asset> Assets<A> {
             }
             Some(value) => {
                 *value -= 1;
-                return;
+                return false;
             }
         }
 
@@ -476,11 +510,62 @@ impl<A: Asset> Assets<A> {
             AssetId::Index { index, .. } => self.dense_storage.remove_dropped(index).is_some(),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid).is_some(),
         };
+        self.measure(id);
 
         self.queued_events.push(AssetEvent::Unused { id });
         if existed {
             self.queued_events.push(AssetEvent::Removed { id });
         }
+        existed
+    }
+
+    /// Returns the [`AssetRetentionPolicy`] of this collection.
+    pub fn retention_policy(&self) -> &AssetRetentionPolicy<A> {
+        &self.retention_policy
+    }
+
+    /// Sets the [`AssetRetentionPolicy`] of this collection, which controls how long assets are
+    /// kept after their last strong [`Handle`] is dropped.
+    ///
+    /// The new policy is applied to assets that are already retained the next time
+    /// [`Assets::track_assets`] runs.
+    pub fn set_retention_policy(&mut self, policy: AssetRetentionPolicy<A>) {
+        self.retention_policy = policy;
+        self.sizes.clear();
+        self.resized.clear();
+        self.total_size = 0;
+        if let Some((_, size)) = self.retention_policy.budget() {
+            self.sizes = self.iter().map(|(id, asset)| (id, size(asset))).collect();
+            self.total_size = self.sizes.values().sum();
+        }
+    }
+
+    /// Updates the tracked size of the asset with the given `id` after it was inserted, removed or
+    /// mutated, if the retention policy has a memory budget.
+    fn measure(&mut self, id: AssetId<A>) {
+        let Some((_, size)) = self.retention_policy.budget() else {
+            return;
+        };
+        let previous = match self.get(id).map(size) {
+            Some(bytes) => {
+                self.total_size += bytes;
+                self.sizes.insert(id, bytes)
+            }
+            None => self.sizes.remove(&id),
+        };
+        self.total_size -= previous.unwrap_or(0);
+    }
+
+    /// Returns `true` if the asset with the given `id` has no live strong [`Handle`]s, and is only
+    /// kept in this collection by its [`AssetRetentionPolicy`].
+    pub fn is_retained(&self, id: impl Into<AssetId<A>>) -> bool {
+        self.retained.contains_key(&id.into())
+    }
+
+    /// Returns an iterator over the [`AssetId`] of every asset that is only kept in this
+    /// collection by its [`AssetRetentionPolicy`].
+    pub fn retained_ids(&self) -> impl Iterator<Item = AssetId<A>> + '_ {
+        self.retained.keys().copied()
     }
 
     /// Returns `true` if there are no assets in this collection.
@@ -534,6 +619,11 @@ impl<A: Asset> Assets<A> {
             dense_storage: self.dense_storage.storage.iter_mut().enumerate(),
             hash_map: self.hash_map.iter_mut(),
             queued_events: &mut self.queued_events,
+            resized: self
+                .retention_policy
+                .budget()
+                .is_some()
+                .then_some(&mut self.resized),
         }
     }
 
@@ -546,20 +636,118 @@ impl<A: Asset> Assets<A> {
         // re-loads are kicked off appropriately. This function must be "transactional" relative
         // to other asset info operations
         let mut infos = asset_server.data.infos.write();
+        // the process_handle_drop call checks whether new handles have been created since the drop event was fired, before removing the asset
+        let mut process_handle_drop = |id| infos.process_handle_drop(id);
+        let now = Instant::now();
         while let Ok(drop_event) = assets.handle_provider.drop_receiver.try_recv() {
             let id = drop_event.id.typed();
 
-            if drop_event.asset_server_managed {
-                let untyped_id = id.untyped();
+            // Unused assets are kept by the retention policy, and their drops are only processed once they're evicted.
+            if assets.retention_policy.retains() && assets.contains(id) {
+                let retained = assets.retained.entry(id).or_insert(RetainedAsset {
+                    unused_since: now,
+                    deferred_drops: 0,
+                    asset_server_managed: drop_event.asset_server_managed,
+                });
+                retained.unused_since = now;
+                retained.deferred_drops += 1;
+                continue;
+            }
 
-                // the process_handle_drop call checks whether new handles have been created since the drop event was fired, before removing the asset
-                if !infos.process_handle_drop(untyped_id) {
-                    // a new handle has been created, or the asset doesn't exist
-                    continue;
-                }
+            assets.process_drop(
+                id,
+                drop_event.asset_server_managed,
+                &mut process_handle_drop,
+            );
+        }
+
+        if !assets.retained.is_empty() {
+            assets.evict_retained(now, &mut process_handle_drop);
+        }
+    }
+
+    /// Removes the [`Asset`] with the given `id` after one of its strong handles was dropped, unless
+    /// new handles have been created in the meantime. Returns `true` if the asset was removed.
+    fn process_drop(
+        &mut self,
+        id: AssetId<A>,
+        asset_server_managed: bool,
+        process_handle_drop: &mut impl FnMut(UntypedAssetId) -> bool,
+    ) -> bool {
+        if asset_server_managed && !process_handle_drop(id.untyped()) {
+            // a new handle has been created, or the asset doesn't exist
+            return false;
+        }
+        self.remove_dropped(id)
+    }
+
+    /// Evicts the retained assets whose grace period has elapsed, then the least recently used
+    /// ones while the collection exceeds its memory budget.
+    fn evict_retained(
+        &mut self,
+        now: Instant,
+        process_handle_drop: &mut impl FnMut(UntypedAssetId) -> bool,
+    ) {
+        let grace_period = self.retention_policy.grace_period();
+        let expired: Vec<_> = self
+            .retained
+            .iter()
+            .filter(|(_, retained)| {
+                now.saturating_duration_since(retained.unused_since) >= grace_period
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        for id in expired {
+            self.evict(
+                id,
+                AssetEvictionReason::GracePeriodElapsed,
+                process_handle_drop,
+            );
+        }
+
+        let Some((budget, _)) = self.retention_policy.budget() else {
+            return;
+        };
+        for id in core::mem::take(&mut self.resized) {
+            self.measure(id);
+        }
+        if self.retained.is_empty() || self.total_size <= budget {
+            return;
+        }
+        let mut candidates: Vec<_> = self
+            .retained
+            .iter()
+            .map(|(id, retained)| (retained.unused_since, *id))
+            .collect();
+        candidates.sort_by_key(|(unused_since, _)| *unused_since);
+        for (_, id) in candidates {
+            if self.total_size <= budget {
+                break;
             }
+            self.evict(
+                id,
+                AssetEvictionReason::MemoryBudgetExceeded,
+                process_handle_drop,
+            );
+        }
+    }
 
-            assets.remove_dropped(id);
+    /// Processes the handle drops deferred while the asset with the given `id` was retained. The
+    /// asset is kept if it has been given a new strong handle since.
+    fn evict(
+        &mut self,
+        id: AssetId<A>,
+        reason: AssetEvictionReason,
+        process_handle_drop: &mut impl FnMut(UntypedAssetId) -> bool,
+    ) {
+        let Some(retained) = self.retained.remove(&id) else {
+            return;
+        };
+        for _ in 0..retained.deferred_drops {
+            if self.process_drop(id, retained.asset_server_managed, process_handle_drop) {
+                self.queued_evictions.push(AssetEvictedEvent { id, reason });
+                return;
+            }
         }
     }
 
@@ -569,6 +757,7 @@ impl<A: Asset> Assets<A> {
     pub(crate) fn asset_events(
         mut assets: ResMut<Self>,
         mut events: EventWriter<AssetEvent<A>>,
+        mut evictions: EventWriter<AssetEvictedEvent<A>>,
         asset_changes: Option<ResMut<AssetChanges<A>>>,
         ticks: SystemChangeTick,
     ) {
@@ -585,6 +774,7 @@ impl<A: Asset> Assets<A> {
             }
         }
         events.write_batch(assets.queued_events.drain(..));
+        evictions.write_batch(assets.queued_evictions.drain(..));
     }
 
     /// A run condition for [`asset_events`]. The system will not run if there are no events to
@@ -592,13 +782,22 @@ impl<A: Asset> Assets<A> {
     ///
     /// [`asset_events`]: Self::asset_events
     pub(crate) fn asset_events_condition(assets: Res<Self>) -> bool {
-        !assets.queued_events.is_empty()
+        !assets.queued_events.is_empty() || !assets.queued_evictions.is_empty()
+    }
+}
+
+impl<A: Asset + AssetSize> Assets<A> {
+    /// Returns the approximate amount of memory used by every asset in this collection, in bytes,
+    /// as reported by [`AssetSize`].
+    pub fn memory_usage(&self) -> usize {
+        self.iter().map(|(_, asset)| asset.asset_size()).sum()
     }
 }
 
 /// A mutable iterator over [`Assets`].
 pub struct AssetsMutIterator<'a, A: Asset> {
     queued_events: &'a mut Vec<AssetEvent<A>>,
+    resized: Option<&'a mut HashSet<AssetId<A>>>,
     dense_storage: Enumerate<core::slice::IterMut<'a, Entry<A>>>,
     hash_map: bevy_platform_support::collections::hash_map::IterMut<'a, Uuid, A>,
 }
@@ -621,6 +820,9 @@ impl<'a, A: Asset> Iterator for AssetsMutIterator<'a, A> {
                         marker: PhantomData,
                     };
                     self.queued_events.push(AssetEvent::Modified { id });
+                    if let Some(resized) = &mut self.resized {
+                        resized.insert(id);
+                    }
                     if let Some(value) = value {
                         return Some((id, value));
                     }
@@ -630,6 +832,9 @@ impl<'a, A: Asset> Iterator for AssetsMutIterator<'a, A> {
         if let Some((key, value)) = self.hash_map.next() {
             let id = AssetId::Uuid { uuid: *key };
             self.queued_events.push(AssetEvent::Modified { id });
+            if let Some(resized) = &mut self.resized {
+                resized.insert(id);
+            }
             Some((id, value))
         } else {
             None
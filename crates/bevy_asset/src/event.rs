@@ -44,6 +44,28 @@ impl<A: Asset> From<&AssetLoadFailedEvent<A>> for UntypedAssetLoadFailedEvent {
     }
 }
 
+/// An event emitted when an unused [`Asset`] that was kept alive by its
+/// [`AssetRetentionPolicy`](crate::AssetRetentionPolicy) is evicted.
+///
+/// This is sent alongside [`AssetEvent::Unused`] and [`AssetEvent::Removed`].
+#[derive(Event, Clone, Debug)]
+pub struct AssetEvictedEvent<A: Asset> {
+    /// The stable identifier of the evicted asset.
+    pub id: AssetId<A>,
+    /// Why the asset was evicted.
+    pub reason: AssetEvictionReason,
+}
+
+/// Why an unused [`Asset`] was evicted, as reported by [`AssetEvictedEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AssetEvictionReason {
+    /// The asset was unused for longer than the grace period of its retention policy.
+    GracePeriodElapsed,
+    /// The assets of its type exceeded the memory budget of their retention policy, and the asset
+    /// was among the least recently used ones.
+    MemoryBudgetExceeded,
+}
+
 /// Events that occur for a specific loaded [`Asset`], such as "value changed" events and "dependency" events.
 #[expect(missing_docs, reason = "Documenting the id fields is unhelpful.")]
 #[derive(Event, Reflect)]
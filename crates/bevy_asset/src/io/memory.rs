@@ -1,8 +1,10 @@
-use crate::io::{AssetReader, AssetReaderError, PathStream, Reader};
+use crate::io::{
+    AssetReader, AssetReaderError, AssetWriter, AssetWriterError, PathStream, Reader, Writer,
+};
 use alloc::{borrow::ToOwned, boxed::Box, sync::Arc, vec::Vec};
 use bevy_platform_support::collections::HashMap;
 use core::{pin::Pin, task::Poll};
-use futures_io::AsyncRead;
+use futures_io::{AsyncRead, AsyncWrite};
 use futures_lite::{ready, Stream};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
@@ -63,6 +65,32 @@ impl Dir {
         dir.0.write().assets.remove(&key)
     }
 
+    /// Removes the stored meta of the asset at `path` and returns the `Data` stored if found and otherwise `None`.
+    pub fn remove_meta(&self, path: &Path) -> Option<Data> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_dir(parent)?;
+        }
+        let key: Box<str> = path.file_name()?.to_string_lossy().into();
+        dir.0.write().metadata.remove(&key)
+    }
+
+    /// Removes the directory at `path`, including its contents, and returns it if found and otherwise `None`.
+    pub fn remove_dir(&self, path: &Path) -> Option<Dir> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_dir(parent)?;
+        }
+        let key: Box<str> = path.file_name()?.to_string_lossy().into();
+        dir.0.write().dirs.remove(&key)
+    }
+
+    /// Returns `true` if this directory contains no assets, metadata or directories.
+    pub fn is_empty(&self) -> bool {
+        let dir = self.0.read();
+        dir.assets.is_empty() && dir.metadata.is_empty() && dir.dirs.is_empty()
+    }
+
     pub fn insert_meta(&self, path: &Path, value: impl Into<Value>) {
         let mut dir = self.clone();
         if let Some(parent) = path.parent() {
@@ -325,9 +353,153 @@ impl AssetReader for MemoryAssetReader {
     }
 }
 
+/// In-memory [`AssetWriter`] implementation, which writes to a [`Dir`] that can be read with a
+/// [`MemoryAssetReader`]. This is primarily intended for unit tests.
+#[derive(Default, Clone)]
+pub struct MemoryAssetWriter {
+    pub root: Dir,
+}
+
+/// A [`Writer`] that stores its bytes in a [`Dir`] each time it is flushed or closed.
+struct DataWriter {
+    dir: Dir,
+    path: PathBuf,
+    is_meta: bool,
+    bytes: Vec<u8>,
+}
+
+impl DataWriter {
+    fn store(&self) {
+        if self.is_meta {
+            self.dir.insert_meta(&self.path, self.bytes.clone());
+        } else {
+            self.dir.insert_asset(&self.path, self.bytes.clone());
+        }
+    }
+}
+
+impl AsyncWrite for DataWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.get_mut().bytes.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn not_found() -> AssetWriterError {
+    AssetWriterError::Io(std::io::ErrorKind::NotFound.into())
+}
+
+impl AssetWriter for MemoryAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(DataWriter {
+            dir: self.root.clone(),
+            path: path.to_owned(),
+            is_meta: false,
+            bytes: Vec::new(),
+        }))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(DataWriter {
+            dir: self.root.clone(),
+            path: path.to_owned(),
+            is_meta: true,
+            bytes: Vec::new(),
+        }))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_asset(path)
+            .map(|_| ())
+            .ok_or_else(not_found)
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_meta(path)
+            .map(|_| ())
+            .ok_or_else(not_found)
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self.root.remove_asset(old_path).ok_or_else(not_found)?;
+        self.root.insert_asset(new_path, data.value);
+        Ok(())
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self.root.remove_meta(old_path).ok_or_else(not_found)?;
+        self.root.insert_meta(new_path, data.value);
+        Ok(())
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root.get_or_insert_dir(path);
+        Ok(())
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root.remove_dir(path).map(|_| ()).ok_or_else(not_found)
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let dir = self.root.get_dir(path).ok_or_else(not_found)?;
+        if !dir.is_empty() {
+            return Err(AssetWriterError::Io(
+                std::io::ErrorKind::DirectoryNotEmpty.into(),
+            ));
+        }
+        self.root.remove_dir(path);
+        Ok(())
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let dir = self.root.get_dir(path).ok_or_else(not_found)?;
+        let mut dir = dir.0.write();
+        dir.assets.clear();
+        dir.metadata.clear();
+        dir.dirs.clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub mod test {
-    use super::Dir;
+    use super::{Dir, MemoryAssetWriter};
+    use crate::io::AssetWriter;
+    use bevy_tasks::block_on;
     use std::path::Path;
 
     #[test]
@@ -361,4 +533,27 @@ pub mod test {
         assert_eq!(meta.path(), b_path);
         assert_eq!(meta.value(), b_meta);
     }
+
+    #[test]
+    fn memory_writer() {
+        let writer = MemoryAssetWriter::default();
+        let dir = writer.root.clone();
+        let path = Path::new("x/a.txt");
+        block_on(writer.write_bytes(path, b"a")).unwrap();
+        block_on(writer.write_meta_bytes(path, b"meta")).unwrap();
+        assert_eq!(dir.get_asset(path).unwrap().value(), b"a");
+        assert_eq!(dir.get_metadata(path).unwrap().value(), b"meta");
+
+        let renamed = Path::new("x/b.txt");
+        block_on(writer.rename(path, renamed)).unwrap();
+        block_on(writer.rename_meta(path, renamed)).unwrap();
+        assert!(dir.get_asset(path).is_none());
+        assert_eq!(dir.get_asset(renamed).unwrap().value(), b"a");
+
+        assert!(block_on(writer.remove_empty_directory(Path::new("x"))).is_err());
+        block_on(writer.remove(renamed)).unwrap();
+        block_on(writer.remove_meta(renamed)).unwrap();
+        block_on(writer.remove_empty_directory(Path::new("x"))).unwrap();
+        assert!(dir.get_dir(Path::new("x")).is_none());
+    }
 }
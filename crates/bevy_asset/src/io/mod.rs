@@ -12,9 +12,12 @@ pub mod embedded;
 pub mod file;
 pub mod gated;
 pub mod memory;
+pub mod pak;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
+#[cfg(all(any(feature = "http", feature = "https"), not(target_arch = "wasm32")))]
+pub mod web;
 
 mod source;
 
@@ -771,11 +774,21 @@ pub(crate) fn get_meta_path(path: &Path) -> PathBuf {
     meta_path
 }
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    feature = "http",
+    feature = "https"
+))]
 /// A [`PathBuf`] [`Stream`] implementation that immediately returns nothing.
 struct EmptyPathStream;
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    feature = "http",
+    feature = "https"
+))]
 impl Stream for EmptyPathStream {
     type Item = PathBuf;
 
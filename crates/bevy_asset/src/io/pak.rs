@@ -0,0 +1,790 @@
+//! Reading assets from, and bundling assets into, a single "pak" archive file.
+//!
+//! Shipping the processed output of the [`AssetProcessor`](crate::processor::AssetProcessor) as
+//! thousands of loose files is slow on some filesystems, and makes it easy to tamper with
+//! individual assets. A pak archive bundles every asset and its `.meta` file into one file,
+//! which a [`PakAssetReader`] reads from.
+//!
+//! Archives are created with a [`PakWriter`], usually from the processed asset directory as a
+//! build step once processing has finished.
+//! [`AssetProcessor::write_pak`](crate::processor::AssetProcessor::write_pak) waits for processing
+//! to finish and bundles the processed assets of an asset source, and [`pack_directory`] bundles
+//! a directory that was processed before:
+//!
+//! ```no_run
+//! # use bevy_asset::{io::AssetSourceId, processor::AssetProcessor};
+//! # use bevy_ecs::system::Res;
+//! # use bevy_tasks::IoTaskPool;
+//! fn write_pak(processor: Res<AssetProcessor>) {
+//!     let processor = processor.clone();
+//!     IoTaskPool::get()
+//!         .spawn(async move {
+//!             processor.write_pak(AssetSourceId::Default, "assets.pak").await.unwrap();
+//!         })
+//!         .detach();
+//! }
+//! ```
+//!
+//! ```no_run
+//! # use bevy_asset::io::pak::pack_directory;
+//! pack_directory("imported_assets/Default", "assets.pak").unwrap();
+//! ```
+//!
+//! Release builds then read processed assets from the archive, by registering it as the processed
+//! reader of the default [`AssetSource`](crate::io::AssetSource) before adding
+//! [`AssetPlugin`](crate::AssetPlugin):
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{io::{pak::PakAssetReader, AssetSource, AssetSourceId}, AssetApp, AssetMode, AssetPlugin};
+//! let reader = PakAssetReader::open("assets.pak").unwrap();
+//! let mut app = App::new();
+//! app.register_asset_source(
+//!     AssetSourceId::Default,
+//!     AssetSource::build()
+//!         .with_reader(AssetSource::get_default_reader("assets".into()))
+//!         .with_processed_reader(move || Box::new(reader.clone())),
+//! )
+//! .add_plugins(AssetPlugin {
+//!     mode: AssetMode::Processed,
+//!     ..Default::default()
+//! });
+//! ```
+//!
+//! # Format
+//!
+//! All integers are little-endian. An archive starts with the magic bytes `BEVYPAK\0`, a `u32`
+//! format version and the `u32` number of entries, followed by the index and the data of the
+//! entries. Each index entry consists of a `u8` kind (`0` for an asset, `1` for the `.meta` file
+//! of an asset), the `u32` length and UTF-8 bytes of the asset path using `/` as the separator,
+//! the `u64` offset and length of its data, relative to the end of the index, and the 32 byte
+//! [BLAKE3](blake3) hash of its data.
+//!
+//! # Integrity
+//!
+//! A [`PakAssetReader`] checks the data of every asset and `.meta` file it reads against the hash
+//! stored in the index, and fails to read entries that were modified. This catches corrupted or
+//! edited entries, but not an archive that was rebuilt as a whole: the hashes aren't signed.
+
+use crate::io::{
+    AssetReader, AssetReaderError, ErasedAssetReader, MissingAssetSourceError,
+    MissingProcessedAssetReaderError, PathStream, Reader, VecReader,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use futures_lite::StreamExt;
+use std::{
+    io::{self, Read, Write},
+    path::{Component, Path, PathBuf},
+};
+use thiserror::Error;
+
+const MAGIC: &[u8; 8] = b"BEVYPAK\0";
+const VERSION: u32 = 1;
+const KIND_ASSET: u8 = 0;
+const KIND_META: u8 = 1;
+
+/// An error that occurs while reading or writing a pak archive.
+#[derive(Error, Debug)]
+pub enum PakError {
+    /// Encountered an I/O error while reading or writing the archive.
+    #[error("encountered an io error while accessing the pak archive: {0}")]
+    Io(#[from] io::Error),
+    /// The file doesn't start with the magic bytes of a pak archive.
+    #[error("the file is not a pak archive")]
+    InvalidMagic,
+    /// The archive was written in a format version that isn't supported.
+    #[error("unsupported pak archive version {0}, expected version {VERSION}")]
+    UnsupportedVersion(u32),
+    /// The index of the archive is malformed.
+    #[error("the index of the pak archive is malformed")]
+    InvalidIndex,
+    /// The data of an entry doesn't match the hash stored in the index of the archive.
+    #[error("the data of an entry of the pak archive doesn't match its hash")]
+    HashMismatch,
+    /// An asset changed while it was being added to the archive.
+    #[error("the asset {} changed while it was being bundled", _0.display())]
+    AssetChanged(PathBuf),
+    /// Failed to read an asset that should be added to the archive.
+    #[error(transparent)]
+    AssetReaderError(#[from] AssetReaderError),
+    /// The asset source to bundle doesn't exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The asset source to bundle doesn't have a processed reader.
+    #[error(transparent)]
+    MissingProcessedAssetReader(#[from] MissingProcessedAssetReaderError),
+}
+
+/// The location of the data of an entry, relative to the end of the index, and its hash.
+#[derive(Clone, Copy, Debug)]
+struct PakRange {
+    offset: u64,
+    len: u64,
+    hash: [u8; 32],
+}
+
+/// The length of an index entry, without its path.
+const ENTRY_LEN: u64 = 1 + 4 + 8 + 8 + 32;
+
+#[derive(Default, Debug)]
+struct PakIndex {
+    assets: BTreeMap<String, PakRange>,
+    metas: BTreeMap<String, PakRange>,
+    /// The paths of the assets and directories directly within each directory.
+    dirs: BTreeMap<String, BTreeSet<String>>,
+    /// The position of the end of the index in the archive.
+    data_start: u64,
+}
+
+impl PakIndex {
+    /// Reads the index of an archive that is `archive_len` bytes long. The lengths stored in the
+    /// index are checked against `archive_len`, so a corrupted archive can't cause huge
+    /// allocations or reads past its end.
+    fn read(reader: &mut impl Read, archive_len: u64) -> Result<Self, PakError> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(PakError::InvalidMagic);
+        }
+        let version = read_u32(reader)?;
+        if version != VERSION {
+            return Err(PakError::UnsupportedVersion(version));
+        }
+
+        let mut index = PakIndex {
+            data_start: 16,
+            ..Default::default()
+        };
+        let count = read_u32(reader)?;
+        if u64::from(count) * ENTRY_LEN > archive_len.saturating_sub(index.data_start) {
+            return Err(PakError::InvalidIndex);
+        }
+        for _ in 0..count {
+            let mut kind = [0];
+            reader.read_exact(&mut kind)?;
+            let path_len = u64::from(read_u32(reader)?);
+            if index.data_start + ENTRY_LEN + path_len > archive_len {
+                return Err(PakError::InvalidIndex);
+            }
+            let mut path = vec![0; path_len as usize];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path).map_err(|_| PakError::InvalidIndex)?;
+            let mut range = PakRange {
+                offset: read_u64(reader)?,
+                len: read_u64(reader)?,
+                hash: [0; 32],
+            };
+            reader.read_exact(&mut range.hash)?;
+            index.data_start += ENTRY_LEN + path.len() as u64;
+            match kind[0] {
+                KIND_ASSET => index.insert_asset(path, range),
+                KIND_META => {
+                    index.metas.insert(path, range);
+                }
+                _ => return Err(PakError::InvalidIndex),
+            }
+        }
+        let data_len = archive_len - index.data_start;
+        let in_bounds = |range: &PakRange| {
+            range
+                .offset
+                .checked_add(range.len)
+                .is_some_and(|end| end <= data_len)
+        };
+        if !index
+            .assets
+            .values()
+            .chain(index.metas.values())
+            .all(in_bounds)
+        {
+            return Err(PakError::InvalidIndex);
+        }
+        Ok(index)
+    }
+
+    fn insert_asset(&mut self, path: String, range: PakRange) {
+        let mut child = path.as_str();
+        while !child.is_empty() {
+            let parent = child.rsplit_once('/').map_or("", |(parent, _)| parent);
+            let children = self.dirs.entry(parent.to_owned()).or_default();
+            if !children.insert(child.to_owned()) {
+                break;
+            }
+            child = parent;
+        }
+        self.assets.insert(path, range);
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Returns the path of an entry in the index, which always uses `/` as the separator.
+fn entry_path(path: &Path) -> String {
+    let mut entry = String::new();
+    for component in path.components() {
+        if let Component::Normal(component) = component {
+            if !entry.is_empty() {
+                entry.push('/');
+            }
+            entry.push_str(&component.to_string_lossy());
+        }
+    }
+    entry
+}
+
+#[derive(Clone, Debug)]
+enum PakStorage {
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+    Memory(Arc<[u8]>),
+}
+
+/// An [`AssetReader`] that reads assets and their `.meta` files from a pak archive created by a
+/// [`PakWriter`]. See the [module docs](self) for details.
+///
+/// Clones of a reader share the index of the archive.
+#[derive(Clone, Debug)]
+pub struct PakAssetReader {
+    storage: PakStorage,
+    index: Arc<PakIndex>,
+}
+
+impl PakAssetReader {
+    /// Opens the pak archive at `path`, relative to the directory of the executable (or the
+    /// directory set by the `BEVY_ASSET_ROOT` or `CARGO_MANIFEST_DIR` environment variables),
+    /// like a [`FileAssetReader`](crate::io::file::FileAssetReader).
+    ///
+    /// Only the index is read from the archive, assets are read from the file when they load.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PakError> {
+        let path = super::file::get_base_path().join(path);
+        let file = std::fs::File::open(&path)?;
+        let archive_len = file.metadata()?.len();
+        Ok(Self {
+            index: Arc::new(PakIndex::read(&mut io::BufReader::new(file), archive_len)?),
+            storage: PakStorage::File(path),
+        })
+    }
+
+    /// Reads a pak archive that is stored in memory, for example with [`include_bytes`].
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self, PakError> {
+        let bytes = bytes.into();
+        Ok(Self {
+            index: Arc::new(PakIndex::read(&mut &*bytes, bytes.len() as u64)?),
+            storage: PakStorage::Memory(bytes),
+        })
+    }
+
+    /// Returns an iterator over the paths of all assets in the archive.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.index.assets.keys().map(Path::new)
+    }
+
+    async fn read_range(&self, range: PakRange) -> Result<VecReader, AssetReaderError> {
+        let start = self.index.data_start + range.offset;
+        let invalid_range = || io::Error::new(io::ErrorKind::InvalidData, PakError::InvalidIndex);
+        let len = usize::try_from(range.len).map_err(|_| invalid_range())?;
+        match &self.storage {
+            #[cfg(not(target_arch = "wasm32"))]
+            PakStorage::File(path) => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+                let mut file = async_fs::File::open(path).await?;
+                file.seek(io::SeekFrom::Start(start)).await?;
+                let mut bytes = vec![0; len];
+                file.read_exact(&mut bytes).await?;
+                check_hash(bytes, range)
+            }
+            PakStorage::Memory(data) => {
+                let bytes = usize::try_from(start)
+                    .ok()
+                    .and_then(|start| data.get(start..start.checked_add(len)?))
+                    .ok_or_else(invalid_range)?;
+                check_hash(bytes.to_vec(), range)
+            }
+        }
+    }
+}
+
+/// Returns a reader of the data of an entry, if it matches the hash of the entry.
+fn check_hash(bytes: Vec<u8>, range: PakRange) -> Result<VecReader, AssetReaderError> {
+    if *blake3::hash(&bytes).as_bytes() != range.hash {
+        let err = io::Error::new(io::ErrorKind::InvalidData, PakError::HashMismatch);
+        return Err(AssetReaderError::Io(Arc::new(err)));
+    }
+    Ok(VecReader::new(bytes))
+}
+
+impl AssetReader for PakAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        match self.index.assets.get(&entry_path(path)) {
+            Some(range) => self.read_range(*range).await,
+            None => Err(AssetReaderError::NotFound(path.to_owned())),
+        }
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        match self.index.metas.get(&entry_path(path)) {
+            Some(range) => self.read_range(*range).await,
+            None => Err(AssetReaderError::NotFound(super::get_meta_path(path))),
+        }
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .index
+            .dirs
+            .get(&entry_path(path))
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        let children: Vec<PathBuf> = children.iter().map(PathBuf::from).collect();
+        Ok(Box::new(futures_lite::stream::iter(children)))
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let path = entry_path(path);
+        if self.index.dirs.contains_key(&path) {
+            Ok(true)
+        } else if self.index.assets.contains_key(&path) {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.into()))
+        }
+    }
+}
+
+/// Bundles assets and their `.meta` files into a pak archive, which can be read by a
+/// [`PakAssetReader`].
+///
+/// Assets added with [`PakWriter::add_directory`] aren't kept in memory: only their length and
+/// hash are, and their data is read again from the [`AssetReader`] while the archive is written.
+#[derive(Default, Debug)]
+pub struct PakWriter<'a> {
+    assets: BTreeMap<String, PakEntry<'a>>,
+    metas: BTreeMap<String, PakEntry<'a>>,
+}
+
+/// An entry of a [`PakWriter`].
+#[derive(Debug)]
+struct PakEntry<'a> {
+    len: u64,
+    hash: [u8; 32],
+    source: PakSource<'a>,
+}
+
+/// Where the data of a [`PakEntry`] is read from when the archive is written.
+enum PakSource<'a> {
+    Bytes(Vec<u8>),
+    Asset(&'a dyn ErasedAssetReader, PathBuf),
+    Meta(&'a dyn ErasedAssetReader, PathBuf),
+}
+
+impl core::fmt::Debug for PakSource<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Asset(_, path) => f.debug_tuple("Asset").field(path).finish(),
+            Self::Meta(_, path) => f.debug_tuple("Meta").field(path).finish(),
+        }
+    }
+}
+
+impl PakEntry<'_> {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            len: bytes.len() as u64,
+            hash: *blake3::hash(&bytes).as_bytes(),
+            source: PakSource::Bytes(bytes),
+        }
+    }
+
+    /// Writes the data of this entry to `writer`.
+    async fn write(&self, writer: &mut impl Write) -> Result<(), PakError> {
+        let (reader, path) = match &self.source {
+            PakSource::Bytes(bytes) => return Ok(writer.write_all(bytes)?),
+            PakSource::Asset(reader, path) => (reader.read(path).await?, path),
+            PakSource::Meta(reader, path) => (reader.read_meta(path).await?, path),
+        };
+        let (len, hash) = copy_hashed(reader, writer).await?;
+        if len != self.len || hash != self.hash {
+            return Err(PakError::AssetChanged(path.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Copies the data of `reader` to `writer` in chunks, and returns its length and hash.
+async fn copy_hashed(
+    mut reader: Box<dyn Reader + '_>,
+    writer: &mut impl Write,
+) -> io::Result<(u64, [u8; 32])> {
+    use futures_lite::AsyncReadExt;
+
+    let mut hasher = blake3::Hasher::new();
+    let mut len = 0;
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buf).await?;
+        if read == 0 {
+            return Ok((len, *hasher.finalize().as_bytes()));
+        }
+        hasher.update(&buf[..read]);
+        writer.write_all(&buf[..read])?;
+        len += read as u64;
+    }
+}
+
+impl<'a> PakWriter<'a> {
+    /// Creates an empty [`PakWriter`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the asset at `path`, replacing any asset previously added at the same path.
+    pub fn add_asset(&mut self, path: impl AsRef<Path>, bytes: Vec<u8>) -> &mut Self {
+        self.assets
+            .insert(entry_path(path.as_ref()), PakEntry::from_bytes(bytes));
+        self
+    }
+
+    /// Adds the `.meta` file of the asset at `path`, replacing any `.meta` file previously added
+    /// for the same asset.
+    pub fn add_meta(&mut self, path: impl AsRef<Path>, bytes: Vec<u8>) -> &mut Self {
+        self.metas
+            .insert(entry_path(path.as_ref()), PakEntry::from_bytes(bytes));
+        self
+    }
+
+    /// Adds every asset in the directory at `path` and its subdirectories, along with their
+    /// `.meta` files, as read by `reader`.
+    ///
+    /// Each asset is read once to measure and hash it, and once more when the archive is written,
+    /// so `reader` must keep returning the same data until then.
+    ///
+    /// To bundle the processed assets of the [`AssetProcessor`](crate::processor::AssetProcessor),
+    /// pass the processed reader of its asset source.
+    pub async fn add_directory(
+        &mut self,
+        reader: &'a dyn ErasedAssetReader,
+        path: &Path,
+    ) -> Result<&mut Self, AssetReaderError> {
+        let mut dirs = vec![path.to_owned()];
+        while let Some(dir) = dirs.pop() {
+            let mut entries = reader.read_directory(&dir).await?;
+            while let Some(entry) = entries.next().await {
+                if reader.is_directory(&entry).await? {
+                    dirs.push(entry);
+                    continue;
+                }
+                let (len, hash) = copy_hashed(reader.read(&entry).await?, &mut io::sink()).await?;
+                let asset = PakEntry {
+                    len,
+                    hash,
+                    source: PakSource::Asset(reader, entry.clone()),
+                };
+                match reader.read_meta(&entry).await {
+                    Ok(meta) => {
+                        let (len, hash) = copy_hashed(meta, &mut io::sink()).await?;
+                        let meta = PakEntry {
+                            len,
+                            hash,
+                            source: PakSource::Meta(reader, entry.clone()),
+                        };
+                        self.metas.insert(entry_path(&entry), meta);
+                    }
+                    Err(AssetReaderError::NotFound(_)) => {}
+                    Err(err) => return Err(err),
+                }
+                self.assets.insert(entry_path(&entry), asset);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Returns the number of assets that were added.
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns `true` if no assets were added.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Writes the archive to `writer`, reading the assets added with
+    /// [`PakWriter::add_directory`] again one at a time.
+    pub async fn write(&self, mut writer: impl Write) -> Result<(), PakError> {
+        let entries = self
+            .assets
+            .iter()
+            .map(|(path, entry)| (KIND_ASSET, path, entry))
+            .chain(
+                self.metas
+                    .iter()
+                    .map(|(path, entry)| (KIND_META, path, entry)),
+            );
+        let count = u32::try_from(self.assets.len() + self.metas.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many assets"))?;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&count.to_le_bytes())?;
+        let mut offset = 0u64;
+        for (kind, path, entry) in entries.clone() {
+            let path_len = u32::try_from(path.len())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "asset path too long"))?;
+            writer.write_all(&[kind])?;
+            writer.write_all(&path_len.to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&entry.len.to_le_bytes())?;
+            writer.write_all(&entry.hash)?;
+            offset += entry.len;
+        }
+        for (_, _, entry) in entries {
+            entry.write(&mut writer).await?;
+        }
+        Ok(writer.flush()?)
+    }
+}
+
+/// Bundles every asset in the directory at `path` and its `.meta` files into a pak archive
+/// written to `output`. Both paths are relative to the directory of the executable, like the
+/// paths of [`AssetPlugin`](crate::AssetPlugin).
+///
+/// Call this with the [processed file path](crate::AssetPlugin::processed_file_path) of the
+/// default asset source once the [`AssetProcessor`](crate::processor::AssetProcessor) has
+/// finished to bundle its output, which is `imported_assets/Default` by default.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn pack_directory(path: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<(), PakError> {
+    let reader = super::file::FileAssetReader::new(&path);
+    let output = super::file::get_base_path().join(output);
+    bevy_tasks::block_on(async {
+        let mut pak = PakWriter::new();
+        pak.add_directory(&reader, Path::new("")).await?;
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::File::create(output)?;
+        pak.write(io::BufWriter::new(file)).await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::memory::{Dir, MemoryAssetReader};
+    use alloc::format;
+    use bevy_tasks::block_on;
+
+    fn read(reader: &PakAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            AssetReader::read(reader, Path::new(path))
+                .await?
+                .read_to_end(&mut bytes)
+                .await?;
+            Ok(String::from_utf8(bytes).unwrap())
+        })
+    }
+
+    fn pak() -> PakAssetReader {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        dir.insert_meta_text(Path::new("a.txt"), "meta a");
+        dir.insert_asset_text(Path::new("textures/b.png"), "b");
+        dir.insert_asset_text(Path::new("textures/ui/c.png"), "c");
+        dir.insert_meta_text(Path::new("textures/ui/c.png"), "meta c");
+
+        let reader = MemoryAssetReader { root: dir };
+        let mut pak = PakWriter::new();
+        block_on(pak.add_directory(&reader, Path::new(""))).unwrap();
+        assert_eq!(pak.len(), 3);
+        let mut bytes = Vec::new();
+        block_on(pak.write(&mut bytes)).unwrap();
+        PakAssetReader::from_bytes(bytes).unwrap()
+    }
+
+    #[test]
+    fn read_assets() {
+        let pak = pak();
+        assert_eq!(read(&pak, "a.txt"), Ok("a".into()));
+        assert_eq!(read(&pak, "textures/ui/c.png"), Ok("c".into()));
+        assert_eq!(
+            read(&pak, "missing.txt"),
+            Err(AssetReaderError::NotFound("missing.txt".into()))
+        );
+
+        let meta = block_on(AssetReader::read_meta_bytes(
+            &pak,
+            Path::new("textures/ui/c.png"),
+        ));
+        assert_eq!(meta.unwrap(), b"meta c");
+        let meta = block_on(AssetReader::read_meta_bytes(
+            &pak,
+            Path::new("textures/b.png"),
+        ));
+        assert!(matches!(meta, Err(AssetReaderError::NotFound(_))));
+    }
+
+    #[test]
+    fn read_directories() {
+        let pak = pak();
+        let children = |path: &str| {
+            block_on(async {
+                let stream = AssetReader::read_directory(&pak, Path::new(path))
+                    .await
+                    .unwrap();
+                stream.collect::<Vec<_>>().await
+            })
+        };
+        assert_eq!(
+            children(""),
+            vec![PathBuf::from("a.txt"), PathBuf::from("textures")]
+        );
+        assert_eq!(
+            children("textures"),
+            vec![
+                PathBuf::from("textures/b.png"),
+                PathBuf::from("textures/ui")
+            ]
+        );
+        assert_eq!(
+            block_on(AssetReader::is_directory(&pak, Path::new("textures/ui"))),
+            Ok(true)
+        );
+        assert_eq!(
+            block_on(AssetReader::is_directory(&pak, Path::new("a.txt"))),
+            Ok(false)
+        );
+        assert_eq!(
+            pak.paths().collect::<Vec<_>>(),
+            vec![
+                Path::new("a.txt"),
+                Path::new("textures/b.png"),
+                Path::new("textures/ui/c.png")
+            ]
+        );
+    }
+
+    #[test]
+    fn pack_and_open() {
+        let dir = std::env::temp_dir().join(format!("bevy_pak_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("processed/models")).unwrap();
+        std::fs::write(dir.join("processed/models/d.glb"), "d").unwrap();
+        std::fs::write(dir.join("processed/models/d.glb.meta"), "meta d").unwrap();
+
+        pack_directory(dir.join("processed"), dir.join("assets.pak")).unwrap();
+        let pak = PakAssetReader::open(dir.join("assets.pak")).unwrap();
+        assert_eq!(read(&pak, "models/d.glb"), Ok("d".into()));
+        let meta = block_on(AssetReader::read_meta_bytes(
+            &pak,
+            Path::new("models/d.glb"),
+        ));
+        assert_eq!(meta.unwrap(), b"meta d");
+        assert_eq!(pak.paths().count(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_archive() {
+        assert!(matches!(
+            PakAssetReader::from_bytes(b"not a pak".to_vec()),
+            Err(PakError::InvalidMagic)
+        ));
+        let mut bytes = Vec::new();
+        block_on(PakWriter::new().write(&mut bytes)).unwrap();
+        bytes[8] = 2;
+        assert!(matches!(
+            PakAssetReader::from_bytes(bytes),
+            Err(PakError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn corrupted_lengths() {
+        let mut pak = PakWriter::new();
+        pak.add_asset("a.txt", b"a".to_vec());
+        let mut bytes = Vec::new();
+        block_on(pak.write(&mut bytes)).unwrap();
+        assert!(PakAssetReader::from_bytes(bytes.clone()).is_ok());
+
+        // A path length that is longer than the archive.
+        let mut huge_path = bytes.clone();
+        huge_path[17..21].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            PakAssetReader::from_bytes(huge_path),
+            Err(PakError::InvalidIndex)
+        ));
+
+        // An entry count that can't fit in the archive.
+        let mut huge_count = bytes.clone();
+        huge_count[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            PakAssetReader::from_bytes(huge_count),
+            Err(PakError::InvalidIndex)
+        ));
+
+        // A data length that reaches past the end of the archive.
+        let len_start = 16 + 1 + 4 + "a.txt".len() + 8;
+        let mut huge_len = bytes;
+        huge_len[len_start..len_start + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            PakAssetReader::from_bytes(huge_len),
+            Err(PakError::InvalidIndex)
+        ));
+    }
+
+    #[test]
+    fn tampered_data() {
+        let mut pak = PakWriter::new();
+        pak.add_asset("a.txt", b"a".to_vec());
+        let mut bytes = Vec::new();
+        block_on(pak.write(&mut bytes)).unwrap();
+
+        let last = bytes.len() - 1;
+        bytes[last] = b'b';
+        let pak = PakAssetReader::from_bytes(bytes).unwrap();
+        assert!(matches!(
+            read(&pak, "a.txt"),
+            Err(AssetReaderError::Io(err)) if err.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn changed_while_writing() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        let reader = MemoryAssetReader { root: dir.clone() };
+        let mut pak = PakWriter::new();
+        block_on(pak.add_directory(&reader, Path::new(""))).unwrap();
+
+        dir.insert_asset_text(Path::new("a.txt"), "changed");
+        let mut bytes = Vec::new();
+        assert!(matches!(
+            block_on(pak.write(&mut bytes)),
+            Err(PakError::AssetChanged(path)) if path == Path::new("a.txt")
+        ));
+    }
+}
@@ -0,0 +1,530 @@
+//! Loading assets from web servers over HTTP(S) on native platforms.
+//!
+//! Adding [`WebAssetPlugin`] *before* [`AssetPlugin`](crate::AssetPlugin) registers the `http`
+//! and `https` [asset sources](crate::io::AssetSource) (when the `http` and `https` features are
+//! enabled respectively), so that assets can be loaded from a URL:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{io::web::WebAssetPlugin, AssetPlugin, AssetServer};
+//! # #[derive(bevy_asset::Asset, bevy_reflect::TypePath)]
+//! # struct Image;
+//! let mut app = App::new();
+//! app.add_plugins((
+//!     WebAssetPlugin {
+//!         cache_path: Some(".web-asset-cache".into()),
+//!         ..Default::default()
+//!     },
+//!     AssetPlugin::default(),
+//! ));
+//! # let asset_server: AssetServer = panic!();
+//! let handle = asset_server.load::<Image>("https://example.com/textures/player.png");
+//! ```
+
+use crate::{
+    io::{
+        get_meta_path, AssetReader, AssetReaderError, AssetSource, EmptyPathStream, PathStream,
+        Reader, VecReader,
+    },
+    AssetApp,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use async_lock::Semaphore;
+use bevy_app::{App, Plugin};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use tracing::{error, warn};
+use ureq::{
+    http::{header, Response},
+    Agent, Body,
+};
+
+/// Registers the `http` and `https` [asset sources](crate::io::AssetSource), which load assets
+/// from web servers using a [`WebAssetReader`].
+///
+/// This plugin must be added before [`AssetPlugin`](crate::AssetPlugin), since asset sources are
+/// built when it is added.
+#[derive(Clone, Debug)]
+pub struct WebAssetPlugin {
+    /// The directory in which downloaded assets are cached, or `None` to disable caching.
+    ///
+    /// Cached assets are revalidated with the server using their `ETag` and `Last-Modified`
+    /// headers, so they are only downloaded again if they changed.
+    pub cache_path: Option<PathBuf>,
+    /// Whether to load cached assets when the server can't be reached, or responds with a
+    /// server error.
+    pub offline_fallback: bool,
+    /// Whether to download the `.meta` files of assets.
+    ///
+    /// This is disabled by default, since it sends a second request for every asset, and most
+    /// servers don't host meta files. When disabled, assets are loaded with their default meta.
+    pub fetch_meta: bool,
+    /// The maximum number of requests sent at the same time.
+    pub max_concurrent_requests: usize,
+    /// The maximum duration of a single request, including downloading the asset.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for WebAssetPlugin {
+    fn default() -> Self {
+        Self {
+            cache_path: None,
+            offline_fallback: true,
+            fetch_meta: false,
+            max_concurrent_requests: 8,
+            timeout: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl Plugin for WebAssetPlugin {
+    fn build(&self, app: &mut App) {
+        let reader = WebAssetReader::new(WebScheme::Http, self);
+        #[cfg(feature = "http")]
+        {
+            let reader = reader.with_scheme(WebScheme::Http);
+            app.register_asset_source(
+                "http",
+                AssetSource::build().with_reader(move || Box::new(reader.clone())),
+            );
+        }
+        #[cfg(feature = "https")]
+        {
+            let reader = reader.with_scheme(WebScheme::Https);
+            app.register_asset_source(
+                "https",
+                AssetSource::build().with_reader(move || Box::new(reader.clone())),
+            );
+        }
+    }
+}
+
+/// The URL scheme used by a [`WebAssetReader`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WebScheme {
+    /// Unencrypted `http://` requests.
+    Http,
+    /// Encrypted `https://` requests. Requires the `https` feature.
+    Https,
+}
+
+impl WebScheme {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Https => "https",
+        }
+    }
+}
+
+/// An [`AssetReader`] that downloads assets from web servers.
+///
+/// The asset path is used as the URL without its scheme, so the path `example.com/player.png`
+/// is loaded from `https://example.com/player.png` by a reader using [`WebScheme::Https`].
+/// Directories can't be read.
+///
+/// Clones of a reader share their cache and their limit on concurrent requests.
+#[derive(Clone)]
+pub struct WebAssetReader {
+    scheme: WebScheme,
+    client: Arc<WebClient>,
+}
+
+struct WebClient {
+    agent: Agent,
+    requests: Semaphore,
+    cache: Option<WebAssetCache>,
+    offline_fallback: bool,
+    fetch_meta: bool,
+}
+
+impl WebAssetReader {
+    /// Creates a new [`WebAssetReader`] for the given `scheme`, configured by `settings`.
+    pub fn new(scheme: WebScheme, settings: &WebAssetPlugin) -> Self {
+        let agent = Agent::config_builder()
+            // Status codes are handled when reading, since 304 and 404 aren't failures.
+            .http_status_as_error(false)
+            .timeout_global(settings.timeout)
+            .build()
+            .new_agent();
+        Self {
+            scheme,
+            client: Arc::new(WebClient {
+                agent,
+                requests: Semaphore::new(settings.max_concurrent_requests.max(1)),
+                cache: settings
+                    .cache_path
+                    .clone()
+                    .map(|path| WebAssetCache { path }),
+                offline_fallback: settings.offline_fallback,
+                fetch_meta: settings.fetch_meta,
+            }),
+        }
+    }
+
+    /// Returns a reader for the given `scheme` that shares the cache and request limit of this
+    /// reader.
+    pub fn with_scheme(&self, scheme: WebScheme) -> Self {
+        Self {
+            scheme,
+            client: self.client.clone(),
+        }
+    }
+
+    fn make_url(&self, path: &Path) -> Result<String, AssetReaderError> {
+        let path = path.to_str().ok_or_else(|| {
+            AssetReaderError::Io(
+                io::Error::other(format!("non-utf8 path: {}", path.display())).into(),
+            )
+        })?;
+        Ok(format!(
+            "{}://{}",
+            self.scheme.as_str(),
+            path.replace('\\', "/")
+        ))
+    }
+
+    async fn get(&self, path: &Path) -> Result<VecReader, AssetReaderError> {
+        let url = self.make_url(path)?;
+        let client = &self.client;
+        let cached = match &client.cache {
+            Some(cache) => cache.load(&url).await,
+            None => None,
+        };
+
+        let mut request = client.agent.get(&url);
+        if let Some((entry, _)) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = {
+            let _permit = client.requests.acquire().await;
+            blocking::unblock(move || {
+                let mut response = request.call()?;
+                let body = if response.status().is_success() {
+                    response
+                        .body_mut()
+                        .with_config()
+                        .limit(u64::MAX)
+                        .read_to_vec()?
+                } else {
+                    Vec::new()
+                };
+                Ok::<_, ureq::Error>((response, body))
+            })
+            .await
+        };
+
+        let (response, body) = match response {
+            Ok(response) => response,
+            Err(err) => {
+                return match cached {
+                    Some((_, data)) if client.offline_fallback => {
+                        warn!("Failed to download {url}, loading it from the cache instead: {err}");
+                        Ok(VecReader::new(data))
+                    }
+                    _ => Err(AssetReaderError::Io(
+                        io::Error::other(format!("failed to download {url}: {err}")).into(),
+                    )),
+                };
+            }
+        };
+
+        match (response.status().as_u16(), cached) {
+            (200..=299, _) => {
+                if let Some(cache) = &client.cache {
+                    let entry = CacheEntry::new(url.clone(), &response);
+                    if let Err(err) = cache.save(&entry, &body).await {
+                        error!("Failed to cache {url}: {err}");
+                    }
+                }
+                Ok(VecReader::new(body))
+            }
+            (304, Some((_, data))) => Ok(VecReader::new(data)),
+            (404, _) => Err(AssetReaderError::NotFound(path.to_owned())),
+            (status @ 500..=599, Some((_, data))) if client.offline_fallback => {
+                warn!(
+                    "Failed to download {url} (status {status}), loading it from the cache instead"
+                );
+                Ok(VecReader::new(data))
+            }
+            (status, _) => Err(AssetReaderError::HttpError(status)),
+        }
+    }
+}
+
+impl AssetReader for WebAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.get(path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let meta_path = get_meta_path(path);
+        if !self.client.fetch_meta {
+            return Err(AssetReaderError::NotFound(meta_path));
+        }
+        self.get(&meta_path).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        error!("Reading directories is not supported with the WebAssetReader");
+        Ok(Box::new(EmptyPathStream))
+    }
+
+    async fn is_directory<'a>(&'a self, _path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(false)
+    }
+}
+
+/// The validators of a downloaded asset, stored next to it in the cache.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl CacheEntry {
+    fn new(url: String, response: &Response<Body>) -> Self {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string)
+        };
+        Self {
+            url,
+            etag: header(header::ETAG),
+            last_modified: header(header::LAST_MODIFIED),
+        }
+    }
+}
+
+/// An on-disk cache of downloaded assets, keyed by the hash of their URL.
+struct WebAssetCache {
+    path: PathBuf,
+}
+
+impl WebAssetCache {
+    fn entry_path(&self, url: &str) -> PathBuf {
+        self.path
+            .join(blake3::hash(url.as_bytes()).to_hex().as_str())
+    }
+
+    /// Returns the cache entry and data of `url`, if it was cached.
+    async fn load(&self, url: &str) -> Option<(CacheEntry, Vec<u8>)> {
+        let path = self.entry_path(url);
+        let entry = async_fs::read_to_string(path.with_extension("ron"))
+            .await
+            .ok()?;
+        let entry: CacheEntry = ron::from_str(&entry).ok()?;
+        // Guard against hash collisions.
+        if entry.url != url {
+            return None;
+        }
+        let data = async_fs::read(&path).await.ok()?;
+        Some((entry, data))
+    }
+
+    async fn save(&self, entry: &CacheEntry, data: &[u8]) -> io::Result<()> {
+        let path = self.entry_path(&entry.url);
+        let entry = ron::to_string(entry).map_err(io::Error::other)?;
+        async_fs::create_dir_all(&self.path).await?;
+        async_fs::write(&path, data).await?;
+        async_fs::write(path.with_extension("ron"), entry).await
+    }
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use futures_lite::{future::block_on, AsyncReadExt};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `responses.len()` requests on a local port, answering each with the response
+    /// returned by the matching function, and returns the requests it received.
+    fn serve(responses: Vec<fn(&str) -> String>) -> (u16, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for respond in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                stream.write_all(respond(&request).as_bytes()).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+        (port, handle)
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n{headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn read(reader: &WebAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(text)
+        })
+    }
+
+    #[test]
+    fn download_and_cache() {
+        let cache_path = std::env::temp_dir().join(format!(
+            "bevy_web_asset_cache_{}_{:?}",
+            std::process::id(),
+            thread::current().id()
+        ));
+        let settings = WebAssetPlugin {
+            cache_path: Some(cache_path.clone()),
+            ..Default::default()
+        };
+        let reader = WebAssetReader::new(WebScheme::Http, &settings);
+
+        let (port, server) = serve(vec![
+            |_| response("200 OK", "ETag: \"v1\"\r\n", "hello"),
+            |request| {
+                assert!(request.to_lowercase().contains("if-none-match: \"v1\"\r\n"));
+                response("304 Not Modified", "", "")
+            },
+            |_| response("404 Not Found", "", ""),
+            |_| response("503 Service Unavailable", "", ""),
+        ]);
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("hello".into())
+        );
+        // The second request is revalidated, and loaded from the cache.
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("hello".into())
+        );
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/b.txt")),
+            Err(AssetReaderError::NotFound(
+                format!("127.0.0.1:{port}/b.txt").into()
+            ))
+        );
+        // Server errors fall back to the cache as well.
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("hello".into())
+        );
+        assert_eq!(server.join().unwrap().len(), 4);
+
+        // The server is gone, so the cached asset is used instead.
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("hello".into())
+        );
+        let reader = WebAssetReader::new(
+            WebScheme::Http,
+            &WebAssetPlugin {
+                offline_fallback: false,
+                ..settings
+            },
+        );
+        assert!(matches!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Err(AssetReaderError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(cache_path).unwrap();
+    }
+
+    #[test]
+    fn fetch_meta() {
+        let read_meta = |reader: &WebAssetReader, path: &str| {
+            block_on(async {
+                let mut reader = reader.read_meta(Path::new(path)).await?;
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                Ok::<_, AssetReaderError>(text)
+            })
+        };
+
+        // No request is sent, so this doesn't fail to connect.
+        let reader = WebAssetReader::new(WebScheme::Http, &WebAssetPlugin::default());
+        assert_eq!(
+            read_meta(&reader, "127.0.0.1:1/a.txt"),
+            Err(AssetReaderError::NotFound("127.0.0.1:1/a.txt.meta".into()))
+        );
+
+        let settings = WebAssetPlugin {
+            fetch_meta: true,
+            ..Default::default()
+        };
+        let reader = WebAssetReader::new(WebScheme::Http, &settings);
+        let (port, server) = serve(vec![|request| {
+            assert!(request.starts_with("GET /a.txt.meta "));
+            response("200 OK", "", "meta")
+        }]);
+        assert_eq!(
+            read_meta(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("meta".into())
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn without_cache() {
+        let reader = WebAssetReader::new(WebScheme::Http, &WebAssetPlugin::default());
+        let (port, server) = serve(vec![
+            |_| response("200 OK", "ETag: \"v1\"\r\n", "hello"),
+            |request| {
+                assert!(!request.to_lowercase().contains("if-none-match"));
+                response("500 Internal Server Error", "", "")
+            },
+        ]);
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Ok("hello".into())
+        );
+        assert_eq!(
+            read(&reader, &format!("127.0.0.1:{port}/a.txt")),
+            Err(AssetReaderError::HttpError(500))
+        );
+        server.join().unwrap();
+    }
+}
@@ -184,6 +184,7 @@ mod loader_builders;
 mod path;
 mod reflect;
 mod render_asset;
+mod retention;
 mod server;
 
 pub use assets::*;
@@ -201,6 +202,7 @@ pub use loader_builders::{
 pub use path::*;
 pub use reflect::*;
 pub use render_asset::*;
+pub use retention::*;
 pub use server::*;
 
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy assets.
@@ -543,6 +545,13 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Sets the [`AssetRetentionPolicy`] of the [`Assets`] collection of the given [`Asset`] type.
+    /// If the asset type isn't initialized yet, the policy is applied once it is initialized with
+    /// [`AssetApp::init_asset`].
+    fn set_asset_retention_policy<A: Asset>(
+        &mut self,
+        policy: AssetRetentionPolicy<A>,
+    ) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -593,7 +602,13 @@ impl AssetApp for App {
     }
 
     fn init_asset<A: Asset>(&mut self) -> &mut Self {
-        let assets = Assets::<A>::default();
+        let mut assets = Assets::<A>::default();
+        if let Some(pending) = self
+            .world_mut()
+            .remove_resource::<PendingRetentionPolicy<A>>()
+        {
+            assets.set_retention_policy(pending.0);
+        }
         self.world()
             .resource::<AssetServer>()
             .register_asset(&assets);
@@ -613,6 +628,7 @@ impl AssetApp for App {
             .allow_ambiguous_resource::<Assets<A>>()
             .add_event::<AssetEvent<A>>()
             .add_event::<AssetLoadFailedEvent<A>>()
+            .add_event::<AssetEvictedEvent<A>>()
             .register_type::<Handle<A>>()
             .add_systems(
                 PostUpdate,
@@ -646,6 +662,19 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn set_asset_retention_policy<A: Asset>(
+        &mut self,
+        policy: AssetRetentionPolicy<A>,
+    ) -> &mut Self {
+        match self.world_mut().get_resource_mut::<Assets<A>>() {
+            Some(mut assets) => assets.set_retention_policy(policy),
+            None => {
+                self.insert_resource(PendingRetentionPolicy(policy));
+            }
+        }
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -665,12 +694,13 @@ mod tests {
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
-            memory::{Dir, MemoryAssetReader},
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, LoadState, UnapprovedPathMode,
+        Asset, AssetApp, AssetEvent, AssetEvictedEvent, AssetEvictionReason, AssetId,
+        AssetLoadError, AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetRetentionPolicy,
+        AssetServer, AssetSize, Assets, LoadState, UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -703,6 +733,12 @@ mod tests {
         pub sub_texts: Vec<Handle<SubText>>,
     }
 
+    impl AssetSize for CoolText {
+        fn asset_size(&self) -> usize {
+            self.text.len()
+        }
+    }
+
     #[derive(Asset, TypePath, Debug)]
     pub struct SubText {
         text: String,
@@ -1193,6 +1229,101 @@ mod tests {
         assert_eq!(events.0, expected_events);
     }
 
+    #[test]
+    fn dependency_graph() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let cool_text = |text: &str, dependencies: &[&str]| {
+            let dependencies = dependencies
+                .iter()
+                .map(|dependency| format!("\"{dependency}\","))
+                .collect::<String>();
+            format!(
+                "(text: \"{text}\", dependencies: [{dependencies}], embedded_dependencies: [], sub_texts: [])"
+            )
+        };
+        dir.insert_asset_text(
+            Path::new("a.cool.ron"),
+            &cool_text("a", &["b.cool.ron", "c.cool.ron"]),
+        );
+        dir.insert_asset_text(Path::new("b.cool.ron"), &cool_text("b", &[]));
+        dir.insert_asset_text(Path::new("c.cool.ron"), &cool_text("c", &["d.cool.ron"]));
+        dir.insert_asset_text(Path::new("d.cool.ron"), &cool_text("d", &[]));
+        dir.insert_asset_text(Path::new("e.cool.ron"), &cool_text("e", &["d.cool.ron"]));
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let e: Handle<CoolText> = asset_server.load("e.cool.ron");
+        app.update();
+
+        // Dependencies are unknown until the asset has loaded.
+        let graph = asset_server.get_dependency_graph(&a).unwrap();
+        assert_eq!(graph.root(), a.id().untyped());
+        assert_eq!(graph.recursive_dependencies().count(), 0);
+        assert!(graph.get(&a).unwrap().load_state.is_loading());
+
+        for path in [
+            "a.cool.ron",
+            "b.cool.ron",
+            "c.cool.ron",
+            "d.cool.ron",
+            "e.cool.ron",
+        ] {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded_with_dependencies(&a)
+                && asset_server.is_loaded_with_dependencies(&e))
+            .then_some(())
+        });
+
+        let id = |path: &str| asset_server.get_path_id(path).unwrap();
+        let (a_id, b_id, c_id, d_id, e_id) = (
+            id("a.cool.ron"),
+            id("b.cool.ron"),
+            id("c.cool.ron"),
+            id("d.cool.ron"),
+            id("e.cool.ron"),
+        );
+
+        let graph = asset_server.get_dependency_graph(a_id).unwrap();
+        let mut dependencies = graph.recursive_dependencies().collect::<Vec<_>>();
+        dependencies.sort();
+        let mut expected = vec![b_id, c_id, d_id];
+        expected.sort();
+        assert_eq!(dependencies, expected);
+        assert!(!graph.contains(e_id));
+
+        let root = graph.get(a_id).unwrap();
+        assert_eq!(root.path, Some(AssetPath::from("a.cool.ron")));
+        assert!(root.load_state.is_loaded());
+        assert!(root.recursive_dependency_load_state.is_loaded());
+        assert_eq!(root.dependencies.len(), 2);
+        assert!(root.dependents.is_empty());
+
+        // Dependents include assets outside of the graph.
+        let mut d_dependents = graph.get(d_id).unwrap().dependents.clone();
+        d_dependents.sort();
+        let mut expected = vec![c_id, e_id];
+        expected.sort();
+        assert_eq!(d_dependents, expected);
+
+        assert_eq!(graph.path_to(a_id), Some(vec![a_id]));
+        assert_eq!(graph.path_to(d_id), Some(vec![a_id, c_id, d_id]));
+        assert_eq!(graph.path_to(e_id), None);
+
+        assert!(asset_server
+            .get_dependency_graph(AssetId::<CoolText>::invalid())
+            .is_none());
+    }
+
     #[test]
     fn failure_load_states() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -1465,6 +1596,213 @@ mod tests {
         );
     }
 
+    #[derive(Resource, Default)]
+    struct StoredEvictions(Vec<(AssetId<CoolText>, AssetEvictionReason)>);
+
+    fn store_evictions(
+        mut reader: EventReader<AssetEvictedEvent<CoolText>>,
+        mut storage: ResMut<StoredEvictions>,
+    ) {
+        storage
+            .0
+            .extend(reader.read().map(|event| (event.id, event.reason)));
+    }
+
+    fn retention_app(policy: AssetRetentionPolicy<CoolText>) -> App {
+        let (mut app, _) = test_app(Dir::default());
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<StoredEvents>()
+            .init_resource::<StoredEvictions>()
+            .set_asset_retention_policy(policy)
+            .add_systems(Update, (store_asset_events, store_evictions));
+        app
+    }
+
+    fn add_text(app: &mut App, text: &str) -> Handle<CoolText> {
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .add(CoolText {
+                text: text.to_string(),
+                ..Default::default()
+            })
+    }
+
+    #[test]
+    fn retain_unused_assets() {
+        let mut app = retention_app(
+            AssetRetentionPolicy::default().with_grace_period(Duration::from_secs(3600)),
+        );
+        let id = add_text(&mut app, "hello").id();
+        app.update();
+
+        // The asset outlives its handle.
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(texts.contains(id));
+        assert!(texts.is_retained(id));
+
+        // Retained assets can be given new strong handles.
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .get_strong_handle(id)
+            .unwrap();
+        app.update();
+        drop(handle);
+        app.update();
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(texts.contains(id));
+        assert!(texts.is_retained(id));
+        assert_eq!(
+            app.world().resource::<StoredEvents>().0,
+            [AssetEvent::Added { id }]
+        );
+
+        // The asset is evicted once its grace period elapses.
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_retention_policy(
+                AssetRetentionPolicy::default().with_grace_period(Duration::from_nanos(1)),
+            );
+        app.update();
+        app.update();
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(!texts.contains(id));
+        assert!(!texts.is_retained(id));
+        assert_eq!(
+            app.world().resource::<StoredEvents>().0,
+            [
+                AssetEvent::Added { id },
+                AssetEvent::Unused { id },
+                AssetEvent::Removed { id }
+            ]
+        );
+        assert_eq!(
+            app.world().resource::<StoredEvictions>().0,
+            [(id, AssetEvictionReason::GracePeriodElapsed)]
+        );
+    }
+
+    #[test]
+    fn set_retention_policy_before_init_asset() {
+        let (mut app, _) = test_app(Dir::default());
+        app.set_asset_retention_policy(
+            AssetRetentionPolicy::<CoolText>::default().with_grace_period(Duration::MAX),
+        )
+        .init_asset::<CoolText>();
+        let id = add_text(&mut app, "hello").id();
+        app.update();
+        assert!(app.world().resource::<Assets<CoolText>>().is_retained(id));
+    }
+
+    #[test]
+    fn evict_unused_assets_over_budget() {
+        let mut app = retention_app(
+            AssetRetentionPolicy::default()
+                .with_grace_period(Duration::MAX)
+                .with_memory_budget(8),
+        );
+        let a = add_text(&mut app, "aaaa");
+        let b = add_text(&mut app, "bbbb");
+        let c = add_text(&mut app, "cccc");
+        let (a_id, b_id, c_id) = (a.id(), b.id(), c.id());
+        app.update();
+        assert_eq!(
+            app.world().resource::<Assets<CoolText>>().memory_usage(),
+            12
+        );
+
+        // Assets in use can exceed the budget, but unused ones are evicted.
+        drop(a);
+        app.update();
+        drop(b);
+        app.update();
+        drop(c);
+        app.update();
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(!texts.contains(a_id));
+        assert!(texts.is_retained(b_id));
+        assert!(texts.is_retained(c_id));
+        assert_eq!(texts.memory_usage(), 8);
+
+        // The least recently used assets are evicted first.
+        let _d = add_text(&mut app, "dddd");
+        app.update();
+        app.update();
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(!texts.contains(b_id));
+        assert!(texts.is_retained(c_id));
+        assert_eq!(
+            app.world().resource::<StoredEvictions>().0,
+            [
+                (a_id, AssetEvictionReason::MemoryBudgetExceeded),
+                (b_id, AssetEvictionReason::MemoryBudgetExceeded),
+            ]
+        );
+    }
+
+    #[test]
+    fn evict_when_assets_grow_over_budget() {
+        let mut app = retention_app(
+            AssetRetentionPolicy::default()
+                .with_grace_period(Duration::MAX)
+                .with_memory_budget(8),
+        );
+        let a = add_text(&mut app, "aaaa");
+        let b_id = add_text(&mut app, "bbbb").id();
+        app.update();
+        assert!(app.world().resource::<Assets<CoolText>>().is_retained(b_id));
+
+        // Growing an asset in use pushes the collection over its budget.
+        let mut texts = app.world_mut().resource_mut::<Assets<CoolText>>();
+        texts.get_mut(&a).unwrap().text.push_str("aaaa");
+        app.update();
+        let texts = app.world().resource::<Assets<CoolText>>();
+        assert!(!texts.contains(b_id));
+        assert_eq!(texts.memory_usage(), 8);
+    }
+
+    #[test]
+    fn reload_retained_assets() {
+        let dir = Dir::default();
+        let path = "dep.cool.ron";
+        dir.insert_asset_text(Path::new(path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .set_asset_retention_policy(
+                AssetRetentionPolicy::<CoolText>::default()
+                    .with_grace_period(Duration::from_secs(3600)),
+            );
+        gate_opener.open(path);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(path);
+        let id = handle.id();
+        run_app_until(&mut app, |world| get::<CoolText>(world, id).map(|_| ()));
+        drop(handle);
+        app.update();
+        assert!(app.world().resource::<Assets<CoolText>>().is_retained(id));
+
+        // Loading a retained asset returns it without loading it again.
+        let handle: Handle<CoolText> = asset_server.load(path);
+        assert_eq!(handle.id(), id);
+        assert!(asset_server.is_loaded(id));
+        drop(handle);
+        app.update();
+
+        // Evicted assets are forgotten by the asset server.
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_retention_policy(
+                AssetRetentionPolicy::default().with_grace_period(Duration::from_nanos(1)),
+            );
+        app.update();
+        assert!(get::<CoolText>(app.world(), id).is_none());
+        assert!(asset_server.get_load_state(id).is_none());
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -1993,4 +2331,295 @@ mod tests {
 
         app.world_mut().run_schedule(Update);
     }
+
+    /// Runs [`AssetProcessor::process_assets`](crate::processor::AssetProcessor::process_assets).
+    /// Processors share the transaction log in `imported_assets/log`, so only one of them
+    /// processes at a time.
+    #[cfg(feature = "multi_threaded")]
+    fn process_assets(processor: &crate::processor::AssetProcessor) {
+        static PROCESSING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        let _guard = PROCESSING
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        bevy_tasks::IoTaskPool::get_or_init(Default::default);
+        processor.process_assets();
+    }
+
+    #[cfg(feature = "multi_threaded")]
+    #[test]
+    fn processor_write_pak() {
+        use crate::{
+            io::pak::{PakAssetReader, PakError},
+            processor::AssetProcessor,
+        };
+
+        let processed_dir = Dir::default();
+        processed_dir.insert_asset_text(Path::new("a.cool.ron"), "a");
+        processed_dir.insert_meta_text(Path::new("a.cool.ron"), "meta a");
+
+        let mut builders = crate::io::AssetSourceBuilders::default();
+        builders.insert(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(|| Box::new(MemoryAssetReader::default()))
+                .with_processed_reader(move || {
+                    Box::new(MemoryAssetReader {
+                        root: processed_dir.clone(),
+                    })
+                }),
+        );
+        let processor = AssetProcessor::new(&mut builders);
+        process_assets(&processor);
+
+        let output =
+            std::env::temp_dir().join(format!("bevy_write_pak_{}.pak", std::process::id()));
+        let count = bevy_tasks::block_on(processor.write_pak(AssetSourceId::Default, &output));
+        assert_eq!(count.unwrap(), 1);
+        let pak = PakAssetReader::open(&output).unwrap();
+        assert_eq!(
+            pak.paths().collect::<Vec<_>>(),
+            vec![Path::new("a.cool.ron")]
+        );
+        let meta = bevy_tasks::block_on(pak.read_meta_bytes(Path::new("a.cool.ron")));
+        assert_eq!(meta.unwrap(), b"meta a");
+        std::fs::remove_file(output).unwrap();
+
+        let missing = bevy_tasks::block_on(processor.write_pak("missing", "missing.pak"));
+        assert!(matches!(missing, Err(PakError::MissingAssetSource(_))));
+    }
+
+    #[derive(Asset, TypePath)]
+    struct VersionedText(#[expect(dead_code, reason = "The text is never read.")] Vec<u8>);
+
+    struct VersionedTextLoader;
+
+    impl AssetLoader for VersionedTextLoader {
+        type Asset = VersionedText;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        const VERSION: u32 = 1;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(VersionedText(bytes))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["versioned"]
+        }
+    }
+
+    /// The source and processed directories of assets loaded with the [`VersionedTextLoader`].
+    #[derive(Default)]
+    struct VersionedDirs {
+        source: Dir,
+        processed: Dir,
+    }
+
+    impl VersionedDirs {
+        /// Stores `text` as the source and processed version of the asset at `path`, along with the
+        /// [`ProcessedInfo`](crate::meta::ProcessedInfo) of processing `hash_text` with the given
+        /// loader version. Returns the full hash of the processed asset.
+        fn process(
+            &self,
+            path: &str,
+            text: &str,
+            hash_text: &str,
+            loader_version: Option<u32>,
+            process_dependencies: Vec<crate::meta::ProcessDependencyInfo>,
+        ) -> crate::meta::AssetHash {
+            use crate::meta::{
+                get_asset_hash, get_full_asset_hash, AssetAction, AssetMeta, AssetMetaDyn,
+                LoaderVersion, ProcessedInfo,
+            };
+
+            let new_meta = || {
+                AssetMeta::<VersionedTextLoader, ()>::new(AssetAction::Load {
+                    loader: core::any::type_name::<VersionedTextLoader>().to_string(),
+                    settings: (),
+                })
+            };
+            self.source.insert_asset_text(Path::new(path), text);
+            self.processed.insert_asset_text(Path::new(path), text);
+            let hash = get_asset_hash(&AssetMetaDyn::serialize(&new_meta()), hash_text.as_bytes());
+            let mut processed_info = ProcessedInfo {
+                hash,
+                full_hash: hash,
+                process_dependencies,
+                processor_version: None,
+                loader_versions: loader_version
+                    .map(|version| LoaderVersion {
+                        loader: core::any::type_name::<VersionedTextLoader>().to_string(),
+                        version,
+                    })
+                    .into_iter()
+                    .collect(),
+            };
+            processed_info.full_hash = get_full_asset_hash(&processed_info);
+            let full_hash = processed_info.full_hash;
+            let mut meta = new_meta();
+            meta.processed_info = Some(processed_info);
+            self.processed.insert_meta_text(
+                Path::new(path),
+                core::str::from_utf8(&AssetMetaDyn::serialize(&meta)).unwrap(),
+            );
+            full_hash
+        }
+
+        fn processor(&self) -> crate::processor::AssetProcessor {
+            let source = self.source.clone();
+            let processed = self.processed.clone();
+            let processed_writer = self.processed.clone();
+            let mut builders = crate::io::AssetSourceBuilders::default();
+            builders.insert(
+                AssetSourceId::Default,
+                AssetSource::build()
+                    .with_reader(move || {
+                        Box::new(MemoryAssetReader {
+                            root: source.clone(),
+                        })
+                    })
+                    .with_processed_reader(move || {
+                        Box::new(MemoryAssetReader {
+                            root: processed.clone(),
+                        })
+                    })
+                    .with_processed_writer(move |_| {
+                        Some(Box::new(MemoryAssetWriter {
+                            root: processed_writer.clone(),
+                        }))
+                    }),
+            );
+            let processor = crate::processor::AssetProcessor::new(&mut builders);
+            processor.server().register_loader(VersionedTextLoader);
+            processor
+        }
+    }
+
+    #[test]
+    fn processor_dry_run() {
+        use crate::processor::ReprocessReason;
+
+        let dirs = VersionedDirs::default();
+        dirs.source
+            .insert_asset_text(Path::new("new.versioned"), "new");
+        dirs.process(
+            "unchanged.versioned",
+            "unchanged",
+            "unchanged",
+            Some(1),
+            Vec::new(),
+        );
+        dirs.process(
+            "changed.versioned",
+            "changed",
+            "original",
+            Some(1),
+            Vec::new(),
+        );
+        dirs.process(
+            "outdated.versioned",
+            "outdated",
+            "outdated",
+            None,
+            Vec::new(),
+        );
+
+        let processor = dirs.processor();
+        let report = bevy_tasks::block_on(processor.dry_run()).unwrap();
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            report.assets,
+            vec![
+                (
+                    AssetPath::from("changed.versioned"),
+                    ReprocessReason::SourceChanged
+                ),
+                (
+                    AssetPath::from("new.versioned"),
+                    ReprocessReason::NotProcessed
+                ),
+                (
+                    AssetPath::from("outdated.versioned"),
+                    ReprocessReason::LoaderVersionChanged {
+                        loader: core::any::type_name::<VersionedTextLoader>().to_string(),
+                        previous: 0,
+                        current: 1,
+                    }
+                ),
+            ]
+        );
+
+        // A dry run never writes processed assets.
+        assert!(dirs
+            .processed
+            .get_asset(Path::new("new.versioned"))
+            .is_none());
+    }
+
+    #[cfg(feature = "multi_threaded")]
+    #[test]
+    fn processor_reprocesses_dependents_on_version_bump() {
+        use crate::{meta::ProcessDependencyInfo, processor::ReprocessReason};
+
+        let dirs = VersionedDirs::default();
+        // `dep.versioned` was processed before its loader had a version, and `main.versioned`
+        // depends on it.
+        let dep_hash = dirs.process("dep.versioned", "dep", "dep", None, Vec::new());
+        dirs.process(
+            "main.versioned",
+            "main",
+            "main",
+            Some(1),
+            vec![ProcessDependencyInfo {
+                full_hash: dep_hash,
+                path: AssetPath::from("dep.versioned"),
+            }],
+        );
+        dirs.processed
+            .insert_asset_text(Path::new("main.versioned"), "outdated main");
+
+        let processor = dirs.processor();
+        let report = bevy_tasks::block_on(processor.dry_run()).unwrap();
+        assert_eq!(
+            report.assets,
+            vec![
+                (
+                    AssetPath::from("dep.versioned"),
+                    ReprocessReason::LoaderVersionChanged {
+                        loader: core::any::type_name::<VersionedTextLoader>().to_string(),
+                        previous: 0,
+                        current: 1,
+                    }
+                ),
+                (
+                    AssetPath::from("main.versioned"),
+                    ReprocessReason::DependencyChanged(AssetPath::from("dep.versioned"))
+                ),
+            ]
+        );
+
+        // Processing agrees with the dry run: bumping the loader version of the dependency
+        // changes its full hash, so its dependent is reprocessed too.
+        process_assets(&processor);
+        let reader = MemoryAssetReader {
+            root: dirs.processed.clone(),
+        };
+        let main = bevy_tasks::block_on(async {
+            let mut bytes = Vec::new();
+            let mut main = reader.read(Path::new("main.versioned")).await.unwrap();
+            main.read_to_end(&mut bytes).await.unwrap();
+            bytes
+        });
+        assert_eq!(main, b"main");
+        let report = bevy_tasks::block_on(processor.dry_run()).unwrap();
+        assert!(report.assets.is_empty());
+    }
 }
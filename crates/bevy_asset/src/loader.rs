@@ -48,6 +48,14 @@ pub trait AssetLoader: Send + Sync + 'static {
     fn extensions(&self) -> &[&str] {
         &[]
     }
+
+    /// The version of this [`AssetLoader`]. Increment it whenever the loader changes in a way that
+    /// produces different assets from the same bytes and settings (such as adding a field to
+    /// [`AssetLoader::Settings`] with a non-trivial default), so that the [`AssetProcessor`]
+    /// reprocesses the assets that were read with the previous version.
+    ///
+    /// [`AssetProcessor`]: crate::processor::AssetProcessor
+    const VERSION: u32 = 0;
 }
 
 /// Provides type-erased access to an [`AssetLoader`].
@@ -71,6 +79,8 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
     /// Returns the type name of the [`AssetLoader`].
     fn type_name(&self) -> &'static str;
+    /// Returns the [`AssetLoader::VERSION`] of the [`AssetLoader`].
+    fn version(&self) -> u32;
     /// Returns the [`TypeId`] of the [`AssetLoader`].
     fn type_id(&self) -> TypeId;
     /// Returns the type name of the top-level [`Asset`] loaded by the [`AssetLoader`].
@@ -126,6 +136,10 @@ where
         core::any::type_name::<L>()
     }
 
+    fn version(&self) -> u32 {
+        L::VERSION
+    }
+
     fn type_id(&self) -> TypeId {
         TypeId::of::<L>()
     }
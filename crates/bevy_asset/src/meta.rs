@@ -79,10 +79,27 @@ pub enum AssetAction<LoaderSettings, ProcessSettings> {
 pub struct ProcessedInfo {
     /// A hash of the asset bytes and the asset .meta data
     pub hash: AssetHash,
-    /// A hash of the asset bytes, the asset .meta data, and the `full_hash` of every `process_dependency`
+    /// A hash of the asset bytes, the asset .meta data, the processor and loader versions, and the `full_hash` of every
+    /// `process_dependency`
     pub full_hash: AssetHash,
     /// Information about the "process dependencies" used to process this asset.
     pub process_dependencies: Vec<ProcessDependencyInfo>,
+    /// The [`Process::VERSION`] of the processor used to process this asset, if it was processed by one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processor_version: Option<u32>,
+    /// The [`AssetLoader::VERSION`] of every loader used to read the asset source while processing it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub loader_versions: Vec<LoaderVersion>,
+}
+
+/// The version of an [`AssetLoader`] used to process an asset. This is used to determine whether the asset must be
+/// reprocessed because the loader changed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LoaderVersion {
+    /// The type name of the [`AssetLoader`].
+    pub loader: String,
+    /// The [`AssetLoader::VERSION`] of the loader.
+    pub version: u32,
 }
 
 /// Information about a dependency used to process an asset. This is used to determine whether an asset's "process dependency"
@@ -249,14 +266,23 @@ pub(crate) fn get_asset_hash(meta_bytes: &[u8], asset_bytes: &[u8]) -> AssetHash
 }
 
 /// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
-pub(crate) fn get_full_asset_hash(
-    asset_hash: AssetHash,
-    dependency_hashes: impl Iterator<Item = AssetHash>,
-) -> AssetHash {
+///
+/// The full hash of an asset covers the versions of the processor and loaders that produced it, so that the assets
+/// that depend on it are reprocessed along with it when one of them changes. This didn't require a bump: versions are
+/// only hashed when present, and metas written before they were recorded deserialize without them, so the full hash
+/// of those metas is unchanged and they aren't reprocessed just because of this.
+pub(crate) fn get_full_asset_hash(processed_info: &ProcessedInfo) -> AssetHash {
     let mut hasher = blake3::Hasher::new();
-    hasher.update(&asset_hash);
-    for hash in dependency_hashes {
-        hasher.update(&hash);
+    hasher.update(&processed_info.hash);
+    if let Some(version) = processed_info.processor_version {
+        hasher.update(&version.to_le_bytes());
+    }
+    for LoaderVersion { loader, version } in &processed_info.loader_versions {
+        hasher.update(loader.as_bytes());
+        hasher.update(&version.to_le_bytes());
+    }
+    for dependency in &processed_info.process_dependencies {
+        hasher.update(&dependency.full_hash);
     }
     *hasher.finalize().as_bytes()
 }
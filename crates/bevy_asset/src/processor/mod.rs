@@ -36,6 +36,16 @@
 //! - [`Process`]: a flexible low-level API for processing assets in arbitrary ways.
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
+//!
+//! # Reprocessing
+//!
+//! Processed assets are only reprocessed when they may have changed: the processor stores a [`ProcessedInfo`] in the meta
+//! of each processed asset, containing hashes of the source asset and its "process dependencies", as well as the
+//! [`Process::VERSION`] and [`AssetLoader::VERSION`](crate::AssetLoader::VERSION) it was processed with. Bump these versions
+//! whenever a change to a processor or loader affects its output, and the assets it produced will be reprocessed on the
+//! next run.
+//!
+//! Use [`AssetProcessor::dry_run`] to list the assets that would be reprocessed, and why, without processing them.
 
 mod log;
 mod process;
@@ -44,19 +54,29 @@ pub use log::*;
 pub use process::*;
 
 use crate::{
+    io::pak::{PakError, PakWriter},
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
         AssetSources, AssetWriterError, ErasedAssetReader, ErasedAssetWriter,
         MissingAssetSourceError,
     },
+    loader::ErasedAssetLoader,
     meta::{
         get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
-        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
+        AssetMetaDyn, AssetMetaMinimal, LoaderVersion, ProcessedInfo, ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
     MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
 };
-use alloc::{borrow::ToOwned, boxed::Box, collections::VecDeque, sync::Arc, vec, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::VecDeque,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use bevy_ecs::prelude::*;
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_tasks::IoTaskPool;
@@ -69,7 +89,6 @@ use tracing::{debug, error, trace, warn};
 
 #[cfg(feature = "trace")]
 use {
-    alloc::string::ToString,
     bevy_tasks::ConditionalSendFuture,
     tracing::{info_span, instrument::Instrument},
 };
@@ -621,40 +640,6 @@ impl AssetProcessor {
         self.validate_transaction_log_and_recover().await;
         let mut asset_infos = self.data.asset_infos.write().await;
 
-        /// Retrieves asset paths recursively. If `clean_empty_folders_writer` is Some, it will be used to clean up empty
-        /// folders when they are discovered.
-        async fn get_asset_paths(
-            reader: &dyn ErasedAssetReader,
-            clean_empty_folders_writer: Option<&dyn ErasedAssetWriter>,
-            path: PathBuf,
-            paths: &mut Vec<PathBuf>,
-        ) -> Result<bool, AssetReaderError> {
-            if reader.is_directory(&path).await? {
-                let mut path_stream = reader.read_directory(&path).await?;
-                let mut contains_files = false;
-
-                while let Some(child_path) = path_stream.next().await {
-                    contains_files |= Box::pin(get_asset_paths(
-                        reader,
-                        clean_empty_folders_writer,
-                        child_path,
-                        paths,
-                    ))
-                    .await?;
-                }
-                if !contains_files && path.parent().is_some() {
-                    if let Some(writer) = clean_empty_folders_writer {
-                        // it is ok for this to fail as it is just a cleanup job.
-                        let _ = writer.remove_empty_directory(&path).await;
-                    }
-                }
-                Ok(contains_files)
-            } else {
-                paths.push(path);
-                Ok(true)
-            }
-        }
-
         for source in self.sources().iter_processed() {
             let Ok(processed_reader) = source.processed_reader() else {
                 continue;
@@ -798,94 +783,39 @@ impl AssetProcessor {
         // TODO: The extension check was removed now that AssetPath is the input. is that ok?
         // TODO: check if already processing to protect against duplicate hot-reload events
         debug!("Processing {}", asset_path);
-        let server = &self.server;
         let path = asset_path.path();
-        let reader = source.reader();
 
-        let reader_err = |err| ProcessError::AssetReaderError {
-            path: asset_path.clone(),
-            err,
-        };
         let writer_err = |err| ProcessError::AssetWriterError {
             path: asset_path.clone(),
             err,
         };
 
-        // Note: we get the asset source reader first because we don't want to create meta files for assets that don't have source files
-        let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
-
-        let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
-            Ok(meta_bytes) => {
-                let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
-                    ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
-                })?;
-                let (meta, processor) = match minimal.asset {
-                    AssetActionMinimal::Load { loader } => {
-                        let loader = server.get_asset_loader_with_type_name(&loader).await?;
-                        let meta = loader.deserialize_meta(&meta_bytes)?;
-                        (meta, None)
-                    }
-                    AssetActionMinimal::Process { processor } => {
-                        let processor = self
-                            .get_processor(&processor)
-                            .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
-                        let meta = processor.deserialize_meta(&meta_bytes)?;
-                        (meta, Some(processor))
-                    }
-                    AssetActionMinimal::Ignore => {
-                        return Ok(ProcessResult::Ignored);
-                    }
-                };
-                (meta, meta_bytes, processor)
-            }
-            Err(AssetReaderError::NotFound(_path)) => {
-                let (meta, processor) = if let Some(processor) = asset_path
-                    .get_full_extension()
-                    .and_then(|ext| self.get_default_processor(&ext))
-                {
-                    let meta = processor.default_meta();
-                    (meta, Some(processor))
-                } else {
-                    match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
-                        Err(MissingAssetLoaderForExtensionError { .. }) => {
-                            let meta: Box<dyn AssetMetaDyn> =
-                                Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
-                            (meta, None)
-                        }
-                    }
-                };
-                let meta_bytes = meta.serialize();
-                (meta, meta_bytes, processor)
-            }
-            Err(err) => {
-                return Err(ProcessError::ReadAssetMetaError {
-                    path: asset_path.clone(),
-                    err,
-                })
-            }
+        let Some(SourceAsset {
+            meta: mut source_meta,
+            processor,
+            loader,
+            bytes: asset_bytes,
+            hash: new_hash,
+        }) = self.read_source_asset(source, asset_path).await?
+        else {
+            return Ok(ProcessResult::Ignored);
         };
 
         let processed_writer = source.processed_writer()?;
 
-        let mut asset_bytes = Vec::new();
-        byte_reader
-            .read_to_end(&mut asset_bytes)
-            .await
-            .map_err(|e| ProcessError::AssetReaderError {
-                path: asset_path.clone(),
-                err: AssetReaderError::Io(e.into()),
-            })?;
-
-        // PERF: in theory these hashes could be streamed if we want to avoid allocating the whole asset.
-        // The downside is that reading assets would need to happen twice (once for the hash and once for the asset loader)
-        // Hard to say which is worse
-        let new_hash = get_asset_hash(&meta_bytes, &asset_bytes);
         let mut new_processed_info = ProcessedInfo {
             hash: new_hash,
             full_hash: new_hash,
             process_dependencies: Vec::new(),
+            processor_version: processor.as_ref().map(|processor| processor.version()),
+            loader_versions: Vec::new(),
         };
+        if let Some(loader) = &loader {
+            new_processed_info.loader_versions.push(LoaderVersion {
+                loader: loader.type_name().to_owned(),
+                version: loader.version(),
+            });
+        }
 
         {
             let infos = self.data.asset_infos.read().await;
@@ -893,24 +823,27 @@ impl AssetProcessor {
                 .get(asset_path)
                 .and_then(|i| i.processed_info.as_ref())
             {
-                if current_processed_info.hash == new_hash {
-                    let mut dependency_changed = false;
-                    for current_dep_info in &current_processed_info.process_dependencies {
-                        let live_hash = infos
-                            .get(&current_dep_info.path)
-                            .and_then(|i| i.processed_info.as_ref())
-                            .map(|i| i.full_hash);
-                        if live_hash != Some(current_dep_info.full_hash) {
-                            dependency_changed = true;
-                            break;
-                        }
-                    }
-                    if !dependency_changed {
-                        return Ok(ProcessResult::SkippedNotChanged);
-                    }
+                let reason = self
+                    .reprocess_reason(
+                        new_hash,
+                        processor.as_deref(),
+                        loader.as_deref(),
+                        current_processed_info,
+                        |path| {
+                            infos
+                                .get(path)
+                                .and_then(|i| i.processed_info.as_ref())
+                                .map(|i| i.full_hash)
+                        },
+                    )
+                    .await;
+                match reason {
+                    None => return Ok(ProcessResult::SkippedNotChanged),
+                    Some(reason) => debug!("Reprocessing {asset_path}: {reason}"),
                 }
             }
         }
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -941,14 +874,7 @@ impl AssetProcessor {
                     err: AssetWriterError::Io(e),
                 })?;
 
-            let full_hash = get_full_asset_hash(
-                new_hash,
-                new_processed_info
-                    .process_dependencies
-                    .iter()
-                    .map(|i| i.full_hash),
-            );
-            new_processed_info.full_hash = full_hash;
+            new_processed_info.full_hash = get_full_asset_hash(&new_processed_info);
             *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
             let meta_bytes = processed_meta.serialize();
             processed_writer
@@ -960,6 +886,7 @@ impl AssetProcessor {
                 .write_bytes(path, &asset_bytes)
                 .await
                 .map_err(writer_err)?;
+            new_processed_info.full_hash = get_full_asset_hash(&new_processed_info);
             *source_meta.processed_info_mut() = Some(new_processed_info.clone());
             let meta_bytes = source_meta.serialize();
             processed_writer
@@ -972,6 +899,275 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Reads the source bytes and meta of the asset at `asset_path`, and resolves how it should be processed.
+    /// Returns `None` if the asset is ignored.
+    async fn read_source_asset(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+    ) -> Result<Option<SourceAsset>, ProcessError> {
+        let server = &self.server;
+        let path = asset_path.path();
+        let reader = source.reader();
+
+        let reader_err = |err| ProcessError::AssetReaderError {
+            path: asset_path.clone(),
+            err,
+        };
+
+        // Note: we get the asset source reader first because we don't want to create meta files for assets that don't have source files
+        let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
+
+        let (meta, meta_bytes, processor, loader) = match reader.read_meta_bytes(path).await {
+            Ok(meta_bytes) => {
+                let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
+                    ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
+                })?;
+                let (meta, processor, loader) = match minimal.asset {
+                    AssetActionMinimal::Load { loader } => {
+                        let loader = server.get_asset_loader_with_type_name(&loader).await?;
+                        let meta = loader.deserialize_meta(&meta_bytes)?;
+                        (meta, None, Some(loader))
+                    }
+                    AssetActionMinimal::Process { processor } => {
+                        let processor = self
+                            .get_processor(&processor)
+                            .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
+                        let meta = processor.deserialize_meta(&meta_bytes)?;
+                        (meta, Some(processor), None)
+                    }
+                    AssetActionMinimal::Ignore => {
+                        return Ok(None);
+                    }
+                };
+                (meta, meta_bytes, processor, loader)
+            }
+            Err(AssetReaderError::NotFound(_path)) => {
+                let (meta, processor, loader) = if let Some(processor) = asset_path
+                    .get_full_extension()
+                    .and_then(|ext| self.get_default_processor(&ext))
+                {
+                    let meta = processor.default_meta();
+                    (meta, Some(processor), None)
+                } else {
+                    match server.get_path_asset_loader(asset_path.clone()).await {
+                        Ok(loader) => (loader.default_meta(), None, Some(loader)),
+                        Err(MissingAssetLoaderForExtensionError { .. }) => {
+                            let meta: Box<dyn AssetMetaDyn> =
+                                Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
+                            (meta, None, None)
+                        }
+                    }
+                };
+                let meta_bytes = meta.serialize();
+                (meta, meta_bytes, processor, loader)
+            }
+            Err(err) => {
+                return Err(ProcessError::ReadAssetMetaError {
+                    path: asset_path.clone(),
+                    err,
+                })
+            }
+        };
+
+        let mut bytes = Vec::new();
+        byte_reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| ProcessError::AssetReaderError {
+                path: asset_path.clone(),
+                err: AssetReaderError::Io(e.into()),
+            })?;
+
+        // PERF: in theory these hashes could be streamed if we want to avoid allocating the whole asset.
+        // The downside is that reading assets would need to happen twice (once for the hash and once for the asset loader)
+        // Hard to say which is worse
+        let hash = get_asset_hash(&meta_bytes, &bytes);
+
+        Ok(Some(SourceAsset {
+            meta,
+            processor,
+            loader,
+            bytes,
+            hash,
+        }))
+    }
+
+    /// Compares the [`ProcessedInfo`] of the current processed version of an asset with its source, and returns why the
+    /// asset must be reprocessed, if it must be. `dependency_hash` returns the current `full_hash` of a processed asset.
+    async fn reprocess_reason(
+        &self,
+        hash: AssetHash,
+        processor: Option<&dyn ErasedProcessor>,
+        loader: Option<&dyn ErasedAssetLoader>,
+        processed_info: &ProcessedInfo,
+        dependency_hash: impl Fn(&AssetPath<'static>) -> Option<AssetHash>,
+    ) -> Option<ReprocessReason> {
+        if processed_info.hash != hash {
+            return Some(ReprocessReason::SourceChanged);
+        }
+
+        // Assets processed before versions were recorded are treated as processed with version 0.
+        if let Some(processor) = processor {
+            let previous = processed_info.processor_version.unwrap_or(0);
+            if previous != processor.version() {
+                return Some(ReprocessReason::ProcessorVersionChanged {
+                    previous,
+                    current: processor.version(),
+                });
+            }
+        }
+        if let Some(loader) = loader {
+            let previous = processed_info
+                .loader_versions
+                .iter()
+                .find(|version| version.loader == loader.type_name())
+                .map_or(0, |version| version.version);
+            if previous != loader.version() {
+                return Some(ReprocessReason::LoaderVersionChanged {
+                    loader: loader.type_name().to_owned(),
+                    previous,
+                    current: loader.version(),
+                });
+            }
+        }
+        for version in &processed_info.loader_versions {
+            // If the loader is missing, reprocessing would fail anyway.
+            if let Ok(loader) = self
+                .server
+                .get_asset_loader_with_type_name(&version.loader)
+                .await
+                && loader.version() != version.version
+            {
+                return Some(ReprocessReason::LoaderVersionChanged {
+                    loader: version.loader.clone(),
+                    previous: version.version,
+                    current: loader.version(),
+                });
+            }
+        }
+
+        processed_info
+            .process_dependencies
+            .iter()
+            .find(|dependency| dependency_hash(&dependency.path) != Some(dependency.full_hash))
+            .map(|dependency| ReprocessReason::DependencyChanged(dependency.path.clone()))
+    }
+
+    /// Waits until this processor has finished processing, then bundles the processed assets of the
+    /// [`AssetSource`] `id` into a [pak archive](crate::io::pak) written to `output`, relative to
+    /// the directory of the executable. Returns the number of bundled assets.
+    ///
+    /// This is meant to run as a build step: spawn it on the [`IoTaskPool`] once the processor
+    /// has started, and ship the archive instead of the processed asset directory.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn write_pak<'a>(
+        &self,
+        id: impl Into<AssetSourceId<'a>>,
+        output: impl AsRef<Path>,
+    ) -> Result<usize, PakError> {
+        self.data.wait_until_finished().await;
+        let reader = self.get_source(id)?.processed_reader()?;
+        let mut pak = PakWriter::new();
+        pak.add_directory(reader, Path::new("")).await?;
+        let output = crate::io::file::get_base_path().join(output);
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        pak.write(std::io::BufWriter::new(std::fs::File::create(output)?))
+            .await?;
+        Ok(pak.len())
+    }
+
+    /// Reports which assets would be processed by the next run of this processor, and why, without processing them or
+    /// modifying any files. This compares every asset in the processed [`AssetSource`]s with the [`ProcessedInfo`]
+    /// stored alongside its processed version, the same way processing does.
+    ///
+    /// Unlike processing, this does not recover from failures recorded in the [`ProcessorTransactionLog`]. The report
+    /// may be outdated if assets are being processed while it is produced.
+    pub async fn dry_run(&self) -> Result<ReprocessReport, InitializeError> {
+        let mut source_paths = Vec::new();
+        let mut processed_infos = HashMap::<AssetPath<'static>, ProcessedInfo>::default();
+        for source in self.sources().iter_processed() {
+            let Ok(processed_reader) = source.processed_reader() else {
+                continue;
+            };
+            let mut paths = Vec::new();
+            get_asset_paths(source.reader(), None, PathBuf::from(""), &mut paths)
+                .await
+                .map_err(InitializeError::FailedToReadSourcePaths)?;
+            for path in paths {
+                let asset_path = AssetPath::from(path).with_source(source.id());
+                if let Ok(meta_bytes) = processed_reader.read_meta_bytes(asset_path.path()).await
+                    && let Ok(ProcessedInfoMinimal {
+                        processed_info: Some(processed_info),
+                    }) = ron::de::from_bytes(&meta_bytes)
+                {
+                    processed_infos.insert(asset_path.clone(), processed_info);
+                }
+                source_paths.push((source, asset_path));
+            }
+        }
+
+        let mut report = ReprocessReport::default();
+        for (source, asset_path) in source_paths {
+            let source_asset = match self.read_source_asset(source, &asset_path).await {
+                Ok(Some(source_asset)) => source_asset,
+                Ok(None) => continue,
+                Err(err) => {
+                    report.errors.push((asset_path, err));
+                    continue;
+                }
+            };
+            let reason = match processed_infos.get(&asset_path) {
+                None => Some(ReprocessReason::NotProcessed),
+                Some(processed_info) => {
+                    self.reprocess_reason(
+                        source_asset.hash,
+                        source_asset.processor.as_deref(),
+                        source_asset.loader.as_deref(),
+                        processed_info,
+                        |path| processed_infos.get(path).map(|info| info.full_hash),
+                    )
+                    .await
+                }
+            };
+            if let Some(reason) = reason {
+                report.assets.push((asset_path, reason));
+            }
+        }
+
+        // Assets are also reprocessed when one of their "process dependencies" is.
+        let mut dependents = HashMap::<&AssetPath<'static>, Vec<&AssetPath<'static>>>::default();
+        for (path, processed_info) in &processed_infos {
+            for dependency in &processed_info.process_dependencies {
+                dependents.entry(&dependency.path).or_default().push(path);
+            }
+        }
+        let mut queued: HashSet<AssetPath<'static>> =
+            report.assets.iter().map(|(path, _)| path.clone()).collect();
+        let mut queue: VecDeque<AssetPath<'static>> = queued.iter().cloned().collect();
+        while let Some(dependency) = queue.pop_front() {
+            for dependent in dependents.get(&dependency).into_iter().flatten() {
+                if queued.insert((*dependent).clone()) {
+                    queue.push_back((*dependent).clone());
+                    report.assets.push((
+                        (*dependent).clone(),
+                        ReprocessReason::DependencyChanged(dependency.clone()),
+                    ));
+                }
+            }
+        }
+
+        report
+            .assets
+            .sort_by_cached_key(|(path, _)| path.to_string());
+        report
+            .errors
+            .sort_by_cached_key(|(path, _)| path.to_string());
+        Ok(report)
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -1166,6 +1362,113 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         );
         self.0.process(context, meta, writer).instrument(span)
     }
+
+    const VERSION: u32 = T::VERSION;
+}
+
+/// Retrieves asset paths recursively. If `clean_empty_folders_writer` is Some, it will be used to clean up empty
+/// folders when they are discovered.
+async fn get_asset_paths(
+    reader: &dyn ErasedAssetReader,
+    clean_empty_folders_writer: Option<&dyn ErasedAssetWriter>,
+    path: PathBuf,
+    paths: &mut Vec<PathBuf>,
+) -> Result<bool, AssetReaderError> {
+    if reader.is_directory(&path).await? {
+        let mut path_stream = reader.read_directory(&path).await?;
+        let mut contains_files = false;
+
+        while let Some(child_path) = path_stream.next().await {
+            contains_files |= Box::pin(get_asset_paths(
+                reader,
+                clean_empty_folders_writer,
+                child_path,
+                paths,
+            ))
+            .await?;
+        }
+        if !contains_files && path.parent().is_some() {
+            if let Some(writer) = clean_empty_folders_writer {
+                // it is ok for this to fail as it is just a cleanup job.
+                let _ = writer.remove_empty_directory(&path).await;
+            }
+        }
+        Ok(contains_files)
+    } else {
+        paths.push(path);
+        Ok(true)
+    }
+}
+
+/// The source of an asset, read by [`AssetProcessor::read_source_asset`].
+struct SourceAsset {
+    meta: Box<dyn AssetMetaDyn>,
+    /// The processor of the asset, if its action is [`AssetAction::Process`].
+    processor: Option<Arc<dyn ErasedProcessor>>,
+    /// The loader of the asset, if its action is [`AssetAction::Load`].
+    loader: Option<Arc<dyn ErasedAssetLoader>>,
+    bytes: Vec<u8>,
+    hash: AssetHash,
+}
+
+/// The reason an asset must be (re)processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReprocessReason {
+    /// The asset has never been processed.
+    NotProcessed,
+    /// The source asset or its meta file changed.
+    SourceChanged,
+    /// One of the "process dependencies" of the asset changed, or is being reprocessed.
+    DependencyChanged(AssetPath<'static>),
+    /// The [`Process::VERSION`] of the processor of the asset changed.
+    ProcessorVersionChanged {
+        /// The version the asset was processed with.
+        previous: u32,
+        /// The current version of the processor.
+        current: u32,
+    },
+    /// The [`AssetLoader::VERSION`] of a loader used to process the asset changed.
+    ///
+    /// [`AssetLoader::VERSION`]: crate::AssetLoader::VERSION
+    LoaderVersionChanged {
+        /// The type name of the loader.
+        loader: String,
+        /// The version the asset was processed with.
+        previous: u32,
+        /// The current version of the loader.
+        current: u32,
+    },
+}
+
+impl core::fmt::Display for ReprocessReason {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotProcessed => write!(f, "the asset has not been processed"),
+            Self::SourceChanged => write!(f, "the source asset changed"),
+            Self::DependencyChanged(path) => write!(f, "the process dependency {path} changed"),
+            Self::ProcessorVersionChanged { previous, current } => write!(
+                f,
+                "the processor version changed from {previous} to {current}"
+            ),
+            Self::LoaderVersionChanged {
+                loader,
+                previous,
+                current,
+            } => write!(
+                f,
+                "the version of {loader} changed from {previous} to {current}"
+            ),
+        }
+    }
+}
+
+/// The assets that would be processed by an [`AssetProcessor`], returned by [`AssetProcessor::dry_run`].
+#[derive(Debug, Default)]
+pub struct ReprocessReport {
+    /// The assets that would be processed, sorted by path, along with the reason they would be processed.
+    pub assets: Vec<(AssetPath<'static>, ReprocessReason)>,
+    /// The assets whose source could not be read, sorted by path. These would fail to process.
+    pub errors: Vec<(AssetPath<'static>, ProcessError)>,
 }
 
 /// The (successful) result of processing an asset
@@ -1349,6 +1652,8 @@ impl ProcessorAssetInfos {
                         hash: AssetHash::default(),
                         full_hash: AssetHash::default(),
                         process_dependencies: vec![],
+                        processor_version: None,
+                        loader_versions: vec![],
                     });
                     self.add_dependent(dependency.path(), asset_path.to_owned());
                 }
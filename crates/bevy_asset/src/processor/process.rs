@@ -3,7 +3,10 @@ use crate::{
         AssetReaderError, AssetWriterError, MissingAssetWriterError,
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, SliceReader, Writer,
     },
-    meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
+    meta::{
+        AssetAction, AssetMeta, AssetMetaDyn, LoaderVersion, ProcessDependencyInfo, ProcessedInfo,
+        Settings,
+    },
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
@@ -40,6 +43,14 @@ pub trait Process: Send + Sync + Sized + 'static {
     ) -> impl ConditionalSendFuture<
         Output = Result<<Self::OutputLoader as AssetLoader>::Settings, ProcessError>,
     >;
+
+    /// The version of this [`Process`] implementation. Increment it whenever the processor changes in a way that
+    /// produces different output from the same input and settings (such as adding a field to [`Process::Settings`]
+    /// with a non-trivial default), so that the [`AssetProcessor`] reprocesses the assets that were processed with
+    /// the previous version.
+    ///
+    /// The [`AssetLoader::VERSION`] of loaders used through [`ProcessContext::load_source_asset`] is tracked separately.
+    const VERSION: u32 = 0;
 }
 
 /// A flexible [`Process`] implementation that loads the source [`Asset`] using the `L` [`AssetLoader`], then transforms
@@ -221,6 +232,8 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    fn version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -255,6 +268,10 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -313,6 +330,17 @@ impl<'a> ProcessContext<'a> {
         let loaded_asset = server
             .load_with_meta_loader_and_reader(self.path, &meta, &*loader, &mut reader, false, true)
             .await?;
+        let loader_version = LoaderVersion {
+            loader: loader_name.to_owned(),
+            version: L::VERSION,
+        };
+        if !self
+            .new_processed_info
+            .loader_versions
+            .contains(&loader_version)
+        {
+            self.new_processed_info.loader_versions.push(loader_version);
+        }
         for (path, full_hash) in &loaded_asset.loader_dependencies {
             self.new_processed_info
                 .process_dependencies
@@ -0,0 +1,166 @@
+use crate::Asset;
+use bevy_ecs::resource::Resource;
+use bevy_platform_support::time::Instant;
+use core::{fmt::Debug, time::Duration};
+
+/// Reports the approximate amount of memory used by an [`Asset`], in bytes.
+///
+/// This is used to enforce the memory budget of an [`AssetRetentionPolicy`]. It doesn't need to
+/// be exact, but it should account for the largest allocations owned by the asset, such as pixel
+/// or vertex data.
+pub trait AssetSize {
+    /// Returns the approximate number of bytes of memory used by this asset.
+    fn asset_size(&self) -> usize;
+}
+
+/// Controls how long the assets in an [`Assets`] collection stay resident after their last strong
+/// [`Handle`] is dropped.
+///
+/// By default, an asset is removed as soon as its last strong [`Handle`] is dropped. A retention
+/// policy can instead keep unused assets cached, so that loading them again is free:
+/// - The [grace period](Self::with_grace_period) is how long an unused asset is kept before it is
+///   evicted.
+/// - The [memory budget](Self::with_memory_budget) caps the memory used by all assets of the type.
+///   When it is exceeded, unused assets are evicted before their grace period has elapsed,
+///   starting with the least recently used ones. Assets with live strong handles are never
+///   evicted, so the budget can still be exceeded by assets that are in use.
+///
+/// While an unused asset is retained, [`AssetServer::load`] returns a new strong handle to it
+/// instead of loading it again, and [`Assets::get_strong_handle`] can be used to keep it alive.
+/// [`AssetEvent::Unused`] and [`AssetEvent::Removed`] are only sent once the asset is evicted,
+/// along with an [`AssetEvictedEvent`].
+///
+/// Set the policy of an asset type with [`Assets::set_retention_policy`] or
+/// [`AssetApp::set_asset_retention_policy`].
+///
+/// ```
+/// # use bevy_asset::{AssetRetentionPolicy, AssetSize, Asset};
+/// # use bevy_reflect::TypePath;
+/// # use core::time::Duration;
+/// #[derive(Asset, TypePath)]
+/// struct Texture(Vec<u8>);
+///
+/// impl AssetSize for Texture {
+///     fn asset_size(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// // Keep unused textures for up to a minute, as long as all textures fit in 512 MiB.
+/// let policy = AssetRetentionPolicy::<Texture>::default()
+///     .with_grace_period(Duration::from_secs(60))
+///     .with_memory_budget(512 * 1024 * 1024);
+/// ```
+///
+/// [`Assets`]: crate::Assets
+/// [`Handle`]: crate::Handle
+/// [`AssetServer::load`]: crate::AssetServer::load
+/// [`Assets::get_strong_handle`]: crate::Assets::get_strong_handle
+/// [`AssetEvent::Unused`]: crate::AssetEvent::Unused
+/// [`AssetEvent::Removed`]: crate::AssetEvent::Removed
+/// [`AssetEvictedEvent`]: crate::AssetEvictedEvent
+/// [`Assets::set_retention_policy`]: crate::Assets::set_retention_policy
+/// [`AssetApp::set_asset_retention_policy`]: crate::AssetApp::set_asset_retention_policy
+pub struct AssetRetentionPolicy<A: Asset> {
+    grace_period: Duration,
+    memory_budget: Option<MemoryBudget<A>>,
+}
+
+struct MemoryBudget<A> {
+    bytes: usize,
+    size: fn(&A) -> usize,
+}
+
+impl<A: Asset> AssetRetentionPolicy<A> {
+    /// Keeps unused assets for `grace_period` after their last strong handle is dropped.
+    ///
+    /// Use [`Duration::MAX`] to keep unused assets until the memory budget is exceeded.
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Evicts unused assets, least recently used first, while all assets of this type use more than
+    /// `bytes` of memory, as reported by [`AssetSize`].
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self
+    where
+        A: AssetSize,
+    {
+        self.memory_budget = Some(MemoryBudget {
+            bytes,
+            size: A::asset_size,
+        });
+        self
+    }
+
+    /// Returns how long unused assets are kept after their last strong handle is dropped.
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
+
+    /// Returns the memory budget of this asset type in bytes, if any.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget.as_ref().map(|budget| budget.bytes)
+    }
+
+    /// Returns `true` if unused assets are kept after their last strong handle is dropped.
+    pub(crate) fn retains(&self) -> bool {
+        !self.grace_period.is_zero()
+    }
+
+    /// Returns the memory budget in bytes, and the function measuring the size of an asset.
+    pub(crate) fn budget(&self) -> Option<(usize, fn(&A) -> usize)> {
+        self.memory_budget
+            .as_ref()
+            .map(|budget| (budget.bytes, budget.size))
+    }
+}
+
+impl<A: Asset> Default for AssetRetentionPolicy<A> {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::ZERO,
+            memory_budget: None,
+        }
+    }
+}
+
+impl<A: Asset> Clone for AssetRetentionPolicy<A> {
+    fn clone(&self) -> Self {
+        Self {
+            grace_period: self.grace_period,
+            memory_budget: self.memory_budget.as_ref().map(|budget| MemoryBudget {
+                bytes: budget.bytes,
+                size: budget.size,
+            }),
+        }
+    }
+}
+
+impl<A: Asset> Debug for AssetRetentionPolicy<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AssetRetentionPolicy")
+            .field("grace_period", &self.grace_period)
+            .field("memory_budget", &self.memory_budget())
+            .finish()
+    }
+}
+
+/// An unused asset that is kept alive by an [`AssetRetentionPolicy`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetainedAsset {
+    /// When the last strong handle of the asset was dropped.
+    pub(crate) unused_since: Instant,
+    /// The number of handle drops that were deferred while the asset was retained. These are
+    /// processed when the asset is evicted, which doesn't remove the asset if it has been given a
+    /// new strong handle in the meantime.
+    pub(crate) deferred_drops: u32,
+    /// Whether the asset is managed by the [`AssetServer`](crate::AssetServer).
+    pub(crate) asset_server_managed: bool,
+}
+
+/// An [`AssetRetentionPolicy`] set with
+/// [`AssetApp::set_asset_retention_policy`](crate::AssetApp::set_asset_retention_policy) before
+/// its asset type was initialized. It is applied by [`AssetApp::init_asset`](crate::AssetApp::init_asset).
+#[derive(Resource)]
+pub(crate) struct PendingRetentionPolicy<A: Asset>(pub(crate) AssetRetentionPolicy<A>);
@@ -0,0 +1,153 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+
+use super::info::AssetInfos;
+
+/// A snapshot of the dependency graph of an asset managed by the [`AssetServer`], returned by
+/// [`AssetServer::get_dependency_graph`].
+///
+/// The graph contains the root asset and all of its recursive dependencies. Each node records the
+/// direct dependencies and dependents of its asset, along with its load states and path. The
+/// snapshot is not updated as assets load; fetch a new one to observe later changes.
+///
+/// [`AssetServer`]: crate::AssetServer
+/// [`AssetServer::get_dependency_graph`]: crate::AssetServer::get_dependency_graph
+#[derive(Debug, Clone)]
+pub struct AssetDependencyGraph {
+    root: UntypedAssetId,
+    nodes: HashMap<UntypedAssetId, AssetDependencyNode>,
+    /// The dependent through which each node was first reached from the root.
+    parents: HashMap<UntypedAssetId, UntypedAssetId>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Debug, Clone)]
+pub struct AssetDependencyNode {
+    /// The id of the asset.
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`LoadState`] of the asset.
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset.
+    pub dependency_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The direct dependencies of the asset. This is empty until the asset has loaded.
+    pub dependencies: Vec<UntypedAssetId>,
+    /// The assets managed by the [`AssetServer`](crate::AssetServer) that directly depend on
+    /// this asset. This may include assets outside of the graph.
+    pub dependents: Vec<UntypedAssetId>,
+    /// The paths of the assets read by the [`AssetLoader`](crate::AssetLoader) of this asset.
+    /// This is only populated when the [`AssetServer`](crate::AssetServer) is watching for
+    /// changes.
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
+
+impl AssetDependencyGraph {
+    /// Builds the graph of `root` from `infos`. Returns `None` if `root` is not managed by the
+    /// asset server.
+    pub(crate) fn new(infos: &AssetInfos, root: UntypedAssetId) -> Option<Self> {
+        infos.get(root)?;
+
+        let mut dependents = HashMap::<_, Vec<_>>::default();
+        for (id, info) in infos.iter() {
+            for dependency in &info.dependencies {
+                dependents.entry(*dependency).or_default().push(id);
+            }
+        }
+
+        let mut nodes = HashMap::default();
+        let mut parents = HashMap::default();
+        let mut queue = VecDeque::from([root]);
+        while let Some(id) = queue.pop_front() {
+            if nodes.contains_key(&id) {
+                continue;
+            }
+            let node = match infos.get(id) {
+                Some(info) => AssetDependencyNode {
+                    id,
+                    path: info.path.clone(),
+                    load_state: info.load_state.clone(),
+                    dependency_load_state: info.dep_load_state.clone(),
+                    recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                    dependencies: info.dependencies.iter().copied().collect(),
+                    dependents: dependents.remove(&id).unwrap_or_default(),
+                    loader_dependencies: info.loader_dependencies.keys().cloned().collect(),
+                },
+                // The dependency was dropped, or was never managed by the asset server.
+                None => AssetDependencyNode {
+                    id,
+                    path: None,
+                    load_state: LoadState::NotLoaded,
+                    dependency_load_state: DependencyLoadState::NotLoaded,
+                    recursive_dependency_load_state: RecursiveDependencyLoadState::NotLoaded,
+                    dependencies: Vec::new(),
+                    dependents: dependents.remove(&id).unwrap_or_default(),
+                    loader_dependencies: Vec::new(),
+                },
+            };
+            for dependency in &node.dependencies {
+                if *dependency != root && !parents.contains_key(dependency) {
+                    parents.insert(*dependency, id);
+                    queue.push_back(*dependency);
+                }
+            }
+            nodes.insert(id, node);
+        }
+
+        Some(Self {
+            root,
+            nodes,
+            parents,
+        })
+    }
+
+    /// Returns the id of the asset this graph was built for.
+    pub fn root(&self) -> UntypedAssetId {
+        self.root
+    }
+
+    /// Returns the node of the asset with the given `id`, if it is in the graph.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Returns `true` if the asset with the given `id` is in the graph.
+    pub fn contains(&self, id: impl Into<UntypedAssetId>) -> bool {
+        self.nodes.contains_key(&id.into())
+    }
+
+    /// Iterates over every node in the graph, including the root, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &AssetDependencyNode> {
+        self.nodes.values()
+    }
+
+    /// Iterates over the ids of every recursive dependency of the root, in no particular order.
+    pub fn recursive_dependencies(&self) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.nodes.keys().copied().filter(|id| *id != self.root)
+    }
+
+    /// Returns a chain of dependencies leading from the root to the asset with the given `id`,
+    /// explaining why it is part of the graph. The chain starts with the root and ends with
+    /// `id`.
+    ///
+    /// Returns `None` if the asset is not in the graph.
+    pub fn path_to(&self, id: impl Into<UntypedAssetId>) -> Option<Vec<UntypedAssetId>> {
+        let id = id.into();
+        if !self.nodes.contains_key(&id) {
+            return None;
+        }
+        let mut chain = Vec::from([id]);
+        let mut current = id;
+        while let Some(parent) = self.parents.get(&current) {
+            chain.push(*parent);
+            current = *parent;
+        }
+        chain.reverse();
+        Some(chain)
+    }
+}
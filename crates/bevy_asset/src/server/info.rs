@@ -27,6 +27,11 @@ pub(crate) struct AssetInfo {
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
     pub(crate) rec_dep_load_state: RecursiveDependencyLoadState,
+    /// The direct dependencies of this asset, set using the value from [`LoadedAsset`] once the
+    /// asset has loaded.
+    ///
+    /// [`LoadedAsset`]: crate::loader::LoadedAsset
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
     loading_dependencies: HashSet<UntypedAssetId>,
     failed_dependencies: HashSet<UntypedAssetId>,
     loading_rec_dependencies: HashSet<UntypedAssetId>,
@@ -39,7 +44,7 @@ pub(crate) struct AssetInfo {
     /// save memory.
     ///
     /// [`LoadedAsset`]: crate::loader::LoadedAsset
-    loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
+    pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
@@ -55,6 +60,7 @@ impl AssetInfo {
             load_state: LoadState::NotLoaded,
             dep_load_state: DependencyLoadState::NotLoaded,
             rec_dep_load_state: RecursiveDependencyLoadState::NotLoaded,
+            dependencies: HashSet::default(),
             loading_dependencies: HashSet::default(),
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
@@ -283,6 +289,10 @@ impl AssetInfos {
         self.infos.get(&id)
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, &AssetInfo)> {
+        self.infos.iter().map(|(id, info)| (*id, info))
+    }
+
     pub(crate) fn contains_key(&self, id: UntypedAssetId) -> bool {
         self.infos.contains_key(&id)
     }
@@ -392,6 +402,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -488,6 +499,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
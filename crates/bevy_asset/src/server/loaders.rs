@@ -338,6 +338,8 @@ impl<T: AssetLoader> AssetLoader for InstrumentedAssetLoader<T> {
     fn extensions(&self) -> &[&str] {
         self.0.extensions()
     }
+
+    const VERSION: u32 = T::VERSION;
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+mod graph;
 mod info;
 mod loaders;
 
@@ -32,6 +33,7 @@ use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+pub use graph::*;
 use info::*;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
@@ -1110,6 +1112,17 @@ impl AssetServer {
             .map(|i| i.rec_dep_load_state.clone())
     }
 
+    /// Retrieves a snapshot of the dependency graph of the given asset `id`, containing the asset
+    /// and all of its recursive dependencies along with their paths, load states and dependents.
+    ///
+    /// Returns `None` if the asset is not managed by this [`AssetServer`].
+    pub fn get_dependency_graph(
+        &self,
+        id: impl Into<UntypedAssetId>,
+    ) -> Option<AssetDependencyGraph> {
+        AssetDependencyGraph::new(&self.data.infos.read(), id.into())
+    }
+
     /// Retrieves the main [`LoadState`] of a given asset `id`.
     ///
     /// This is the same as [`AssetServer::get_load_state`] except the result is unwrapped. If
@@ -123,7 +123,11 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert))
+        if attrs.relationship.as_ref().is_some_and(|r| r.many) {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::ManyRelationship>::on_insert))
+        } else {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert))
+        }
     } else {
         attrs
             .on_insert
@@ -140,8 +144,12 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace))
-    } else if attrs.relationship_target.is_some() {
+        if attrs.relationship.as_ref().is_some_and(|r| r.many) {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::ManyRelationship>::on_replace))
+        } else {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace))
+        }
+    } else if let Some(relationship_target) = &attrs.relationship_target {
         if attrs.on_replace.is_some() {
             return syn::Error::new(
                 ast.span(),
@@ -151,16 +159,21 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace))
+        if relationship_target.many {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::ManyRelationshipTarget>::on_replace))
+        } else {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace))
+        }
     } else {
         attrs
             .on_replace
             .map(|path| path.to_token_stream(&bevy_ecs_path))
     };
 
-    let on_despawn_path = if attrs
+    let on_despawn_path = if let Some(relationship_target) = attrs
         .relationship_target
-        .is_some_and(|target| target.linked_spawn)
+        .as_ref()
+        .filter(|target| target.linked_spawn)
     {
         if attrs.on_despawn.is_some() {
             return syn::Error::new(
@@ -171,7 +184,11 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_despawn))
+        if relationship_target.many {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::ManyRelationshipTarget>::on_despawn))
+        } else {
+            Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_despawn))
+        }
     } else {
         attrs
             .on_despawn
@@ -249,7 +266,9 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
-    let clone_behavior = if relationship_target.is_some() {
+    let clone_behavior = if attrs.relationship_target.as_ref().is_some_and(|target| target.many) {
+        quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Custom(#bevy_ecs_path::relationship::clone_many_relationship_target::<Self>))
+    } else if relationship_target.is_some() {
         quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Custom(#bevy_ecs_path::relationship::clone_relationship_target::<Self>))
     } else {
         quote!(
@@ -473,11 +492,13 @@ struct Require {
 
 struct Relationship {
     relationship_target: Type,
+    many: bool,
 }
 
 struct RelationshipTarget {
     relationship: Type,
     linked_spawn: bool,
+    many: bool,
 }
 
 // values for `storage` attribute
@@ -654,14 +675,35 @@ mod kw {
     syn::custom_keyword!(relationship_target);
     syn::custom_keyword!(relationship);
     syn::custom_keyword!(linked_spawn);
+    syn::custom_keyword!(many);
 }
 
 impl Parse for Relationship {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        input.parse::<kw::relationship_target>()?;
-        input.parse::<Token![=]>()?;
+        let mut relationship_target: Option<Type> = None;
+        let mut many: bool = false;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::many) {
+                input.parse::<kw::many>()?;
+                many = true;
+            } else if lookahead.peek(kw::relationship_target) {
+                input.parse::<kw::relationship_target>()?;
+                input.parse::<Token![=]>()?;
+                relationship_target = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
         Ok(Relationship {
-            relationship_target: input.parse::<Type>()?,
+            relationship_target: relationship_target.ok_or_else(|| {
+                syn::Error::new(input.span(), "Missing `relationship_target = X` attribute")
+            })?,
+            many,
         })
     }
 }
@@ -670,12 +712,16 @@ impl Parse for RelationshipTarget {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
         let mut relationship: Option<Type> = None;
         let mut linked_spawn: bool = false;
+        let mut many: bool = false;
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
             if lookahead.peek(kw::linked_spawn) {
                 input.parse::<kw::linked_spawn>()?;
                 linked_spawn = true;
+            } else if lookahead.peek(kw::many) {
+                input.parse::<kw::many>()?;
+                many = true;
             } else if lookahead.peek(kw::relationship) {
                 input.parse::<kw::relationship>()?;
                 input.parse::<Token![=]>()?;
@@ -692,6 +738,7 @@ impl Parse for RelationshipTarget {
                 syn::Error::new(input.span(), "Missing `relationship = X` attribute")
             })?,
             linked_spawn,
+            many,
         })
     }
 }
@@ -727,6 +774,45 @@ fn derive_relationship(
 
     let relationship_target = &relationship.relationship_target;
 
+    if relationship.many {
+        let collection = &field.ty;
+        return Ok(Some(quote! {
+            impl #impl_generics #bevy_ecs_path::relationship::ManyRelationship for #struct_name #type_generics #where_clause {
+                type RelationshipTarget = #relationship_target;
+                type Collection = #collection;
+
+                #[inline(always)]
+                fn collection(&self) -> &Self::Collection {
+                    &self.#relationship_member
+                }
+
+                #[inline]
+                fn from_collection(collection: Self::Collection) -> Self {
+                    Self {
+                        #(#members: core::default::Default::default(),)*
+                        #relationship_member: collection
+                    }
+                }
+            }
+
+            impl #impl_generics #bevy_ecs_path::relationship::RelationshipSource for #struct_name #type_generics #where_clause {
+                type Target = #relationship_target;
+
+                #[inline]
+                fn from_target(target: #bevy_ecs_path::entity::Entity) -> Self {
+                    let mut collection = <#collection as #bevy_ecs_path::relationship::RelationshipSourceCollection>::with_capacity(1);
+                    #bevy_ecs_path::relationship::RelationshipSourceCollection::add(&mut collection, target);
+                    <Self as #bevy_ecs_path::relationship::ManyRelationship>::from_collection(collection)
+                }
+
+                #[inline]
+                fn target_with_capacity(capacity: usize) -> Self::Target {
+                    <#relationship_target as #bevy_ecs_path::relationship::ManyRelationshipTarget>::with_capacity(capacity)
+                }
+            }
+        }));
+    }
+
     Ok(Some(quote! {
         impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
             type RelationshipTarget = #relationship_target;
@@ -783,6 +869,39 @@ fn derive_relationship_target(
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
     let linked_spawn = relationship_target.linked_spawn;
+
+    if relationship_target.many {
+        return Ok(Some(quote! {
+            impl #impl_generics #bevy_ecs_path::relationship::ManyRelationshipTarget for #struct_name #type_generics #where_clause {
+                const LINKED_SPAWN: bool = #linked_spawn;
+                type Relationship = #relationship;
+                type Collection = #collection;
+
+                #[inline]
+                fn collection(&self) -> &Self::Collection {
+                    &self.#relationship_member
+                }
+
+                #[inline]
+                fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                    &mut self.#relationship_member
+                }
+
+                #[inline]
+                fn from_collection_risky(collection: Self::Collection) -> Self {
+                    Self {
+                        #(#members: core::default::Default::default(),)*
+                        #relationship_member: collection
+                    }
+                }
+            }
+
+            impl #impl_generics #bevy_ecs_path::spawn::SpawnRelated for #struct_name #type_generics #where_clause {
+                type Relationship = #relationship;
+            }
+        }));
+    }
+
     Ok(Some(quote! {
         impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
             const LINKED_SPAWN: bool = #linked_spawn;
@@ -20,7 +20,10 @@ use crate::{
     query::DebugCheckedUnwrap,
     relationship::RelationshipHookMode,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
-    world::{unsafe_world_cell::UnsafeWorldCell, EntityWorldMut, ON_ADD, ON_INSERT, ON_REPLACE},
+    world::{
+        unsafe_world_cell::UnsafeWorldCell, EntityWorldMut, WorldChange, ON_ADD, ON_INSERT,
+        ON_REPLACE,
+    },
 };
 use alloc::{boxed::Box, vec, vec::Vec};
 use bevy_platform_support::collections::{HashMap, HashSet};
@@ -1293,6 +1296,19 @@ impl<'w> BundleInserter<'w> {
         // SAFETY: We have no outstanding mutable references to world as they were dropped
         let mut deferred_world = unsafe { self.world.into_deferred() };
 
+        let inserted = |component| WorldChange::Inserted { entity, component };
+        let components = self.world.components();
+        match insert_mode {
+            InsertMode::Replace => {
+                components.record_changed_entity(entity, archetype_after_insert.iter_inserted());
+                deferred_world.record_changes(archetype_after_insert.iter_inserted().map(inserted));
+            }
+            InsertMode::Keep => {
+                components.record_changed_entity(entity, archetype_after_insert.iter_added());
+                deferred_world.record_changes(archetype_after_insert.iter_added().map(inserted));
+            }
+        }
+
         // SAFETY: All components in the bundle are guaranteed to exist in the World
         // as they must be initialized before creating the BundleInfo.
         unsafe {
@@ -1463,6 +1479,16 @@ impl<'w> BundleSpawner<'w> {
         let mut deferred_world = unsafe { self.world.into_deferred() };
         // SAFETY: `DeferredWorld` cannot provide mutable access to `Archetypes`.
         let archetype = self.archetype.as_ref();
+        self.world
+            .components()
+            .record_changed_entity(entity, bundle_info.iter_contributed_components());
+        deferred_world.record_changes(
+            core::iter::once(WorldChange::Spawned(entity)).chain(
+                bundle_info
+                    .iter_contributed_components()
+                    .map(|component| WorldChange::Inserted { entity, component }),
+            ),
+        );
         // SAFETY: All components in the bundle are guaranteed to exist in the World
         // as they must be initialized before creating the BundleInfo.
         unsafe {
@@ -2,8 +2,10 @@
 
 use crate::{
     component::{Tick, TickCells},
+    entity::Entity,
     ptr::PtrMut,
     resource::Resource,
+    world::ChangedEntities,
 };
 use alloc::borrow::ToOwned;
 use bevy_ptr::{Ptr, UnsafeCellDeref};
@@ -372,14 +374,14 @@ macro_rules! change_detection_mut_impl {
             #[inline]
             #[track_caller]
             fn set_changed(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
+                self.ticks.set_changed(self.ticks.this_run);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
             #[inline]
             #[track_caller]
             fn set_last_changed(&mut self, last_changed: Tick) {
-                *self.ticks.changed = last_changed;
+                self.ticks.set_changed(last_changed);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
@@ -431,6 +433,7 @@ macro_rules! impl_methods {
                         changed: self.ticks.changed,
                         last_run: self.ticks.last_run,
                         this_run: self.ticks.this_run,
+                        changed_entities: self.ticks.changed_entities,
                     },
                     changed_by: self.changed_by.as_deref_mut(),
                 }
@@ -552,6 +555,9 @@ pub(crate) struct TicksMut<'w> {
     pub(crate) changed: &'w mut Tick,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
+    /// The log to record the entity in when the value is changed, if its component tracks its
+    /// changed entities.
+    pub(crate) changed_entities: Option<(&'w ChangedEntities, Entity)>,
 }
 
 impl<'w> TicksMut<'w> {
@@ -570,8 +576,31 @@ impl<'w> TicksMut<'w> {
             changed: unsafe { cells.changed.deref_mut() },
             last_run,
             this_run,
+            changed_entities: None,
         }
     }
+
+    /// Records `entity` in `changed_entities` when the value is changed, if it is `Some`.
+    #[inline]
+    pub(crate) fn with_changed_entities(
+        mut self,
+        changed_entities: Option<&'w ChangedEntities>,
+        entity: Entity,
+    ) -> Self {
+        self.changed_entities = changed_entities.map(|log| (log, entity));
+        self
+    }
+
+    #[inline]
+    fn set_changed(&mut self, tick: Tick) {
+        if let Some((log, entity)) = self.changed_entities {
+            // Values that already changed this tick have been recorded.
+            if *self.changed != tick {
+                log.push(entity);
+            }
+        }
+        *self.changed = tick;
+    }
 }
 
 impl<'w> From<TicksMut<'w>> for Ticks<'w> {
@@ -954,6 +983,7 @@ impl<'w, T: ?Sized> Mut<'w, T> {
                 changed: last_changed,
                 last_run,
                 this_run,
+                changed_entities: None,
             },
             changed_by: caller,
         }
@@ -1044,6 +1074,7 @@ impl<'w> MutUntyped<'w> {
                 changed: self.ticks.changed,
                 last_run: self.ticks.last_run,
                 this_run: self.ticks.this_run,
+                changed_entities: self.ticks.changed_entities,
             },
             changed_by: self.changed_by.as_deref_mut(),
         }
@@ -1147,14 +1178,14 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[inline]
     #[track_caller]
     fn set_changed(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
+        self.ticks.set_changed(self.ticks.this_run);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
     #[inline]
     #[track_caller]
     fn set_last_changed(&mut self, last_changed: Tick) {
-        *self.ticks.changed = last_changed;
+        self.ticks.set_changed(last_changed);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
@@ -1601,6 +1632,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            changed_entities: None,
         };
         let mut res = R {};
         let mut caller = MaybeLocation::caller();
@@ -1651,6 +1683,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            changed_entities: None,
         };
         let mut res = R {};
         let mut caller = MaybeLocation::caller();
@@ -1684,6 +1717,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run,
             this_run,
+            changed_entities: None,
         };
 
         let mut outer = Outer(0);
@@ -1772,6 +1806,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run,
             this_run,
+            changed_entities: None,
         };
 
         let mut value: i32 = 5;
@@ -1809,6 +1844,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            changed_entities: None,
         };
         let mut c = C {};
         let mut caller = MaybeLocation::caller();
@@ -10,7 +10,7 @@ use crate::{
     resource::Resource,
     storage::{SparseSetIndex, SparseSets, Table, TableRow},
     system::{Local, SystemParam},
-    world::{DeferredWorld, FromWorld, World},
+    world::{ChangedEntities, DeferredWorld, FromWorld, World},
 };
 use alloc::boxed::Box;
 use alloc::{borrow::Cow, format, vec::Vec};
@@ -885,6 +885,7 @@ pub struct ComponentInfo {
     hooks: ComponentHooks,
     required_components: RequiredComponents,
     required_by: HashSet<ComponentId>,
+    changed_entities: Option<Arc<ChangedEntities>>,
 }
 
 impl ComponentInfo {
@@ -958,6 +959,7 @@ impl ComponentInfo {
             hooks: Default::default(),
             required_components: Default::default(),
             required_by: Default::default(),
+            changed_entities: None,
         }
     }
 
@@ -1069,9 +1071,11 @@ pub struct ComponentDescriptor {
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
     mutable: bool,
     clone_behavior: ComponentCloneBehavior,
+    // Registers the Rust component type this descriptor was created for, if any.
+    register: Option<fn(&mut ComponentsRegistrator) -> ComponentId>,
 }
 
-// We need to ignore the `drop` field in our `Debug` impl
+// We need to ignore the `drop` and `register` fields in our `Debug` impl
 impl Debug for ComponentDescriptor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ComponentDescriptor")
@@ -1097,6 +1101,10 @@ impl ComponentDescriptor {
         }
     }
 
+    fn register_component<T: Component>(components: &mut ComponentsRegistrator) -> ComponentId {
+        components.register_component::<T>()
+    }
+
     /// Create a new `ComponentDescriptor` for the type `T`.
     pub fn new<T: Component>() -> Self {
         Self {
@@ -1108,6 +1116,7 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: T::Mutability::MUTABLE,
             clone_behavior: T::clone_behavior(),
+            register: Some(Self::register_component::<T>),
         }
     }
 
@@ -1133,6 +1142,7 @@ impl ComponentDescriptor {
             drop,
             mutable,
             clone_behavior,
+            register: None,
         }
     }
 
@@ -1151,6 +1161,7 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
             clone_behavior: ComponentCloneBehavior::Default,
+            register: None,
         }
     }
 
@@ -1164,6 +1175,7 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
             clone_behavior: ComponentCloneBehavior::Default,
+            register: None,
         }
     }
 
@@ -1794,6 +1806,19 @@ impl<'w> ComponentsRegistrator<'w> {
         info.required_components = required_components;
     }
 
+    /// Registers the component described by `info`, which may come from another [`World`], and
+    /// returns its id in this instance.
+    ///
+    /// This is how component ids are mapped between worlds. Components are matched by their Rust
+    /// type, and are registered with [`register_component`](Self::register_component) if they
+    /// aren't registered yet. Returns `None` if the component was registered with a
+    /// [`ComponentDescriptor`] that doesn't correspond to a Rust [`Component`] type.
+    ///
+    /// [`World`]: crate::world::World
+    pub fn register_component_from_info(&mut self, info: &ComponentInfo) -> Option<ComponentId> {
+        info.descriptor.register.map(|register| register(self))
+    }
+
     /// Registers a component described by `descriptor`.
     ///
     /// # Note
@@ -1960,6 +1985,7 @@ pub struct Components {
     resource_indices: TypeIdMap<ComponentId>,
     // This is kept internal and local to verify that no deadlocks can occor.
     queued: bevy_platform_support::sync::RwLock<QueuedComponents>,
+    tracked_changed_entities: Vec<ComponentId>,
 }
 
 impl Components {
@@ -2113,6 +2139,54 @@ impl Components {
         }
     }
 
+    /// Gets the log of the entities whose value of the component with this [`ComponentId`] changed,
+    /// if it tracks them.
+    #[inline]
+    pub(crate) fn changed_entities(&self, id: ComponentId) -> Option<&ChangedEntities> {
+        self.get_info(id)?.changed_entities.as_deref()
+    }
+
+    /// Returns the components that track their changed entities.
+    #[inline]
+    pub(crate) fn tracked_changed_entities(&self) -> &[ComponentId] {
+        &self.tracked_changed_entities
+    }
+
+    /// Records that the value of the given components changed on `entity`, for the components
+    /// that track their changed entities.
+    #[inline]
+    pub(crate) fn record_changed_entity(
+        &self,
+        entity: Entity,
+        components: impl Iterator<Item = ComponentId>,
+    ) {
+        if self.tracked_changed_entities.is_empty() {
+            return;
+        }
+        for id in components {
+            if let Some(log) = self.changed_entities(id) {
+                log.push(entity);
+            }
+        }
+    }
+
+    /// Starts tracking the changed entities of the component with this [`ComponentId`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component is not registered.
+    pub(crate) fn track_changed_entities(&mut self, id: ComponentId) {
+        let info = self
+            .components
+            .get_mut(id.0)
+            .and_then(Option::as_mut)
+            .expect("component is not registered");
+        if info.changed_entities.is_none() {
+            info.changed_entities = Some(Arc::new(ChangedEntities::default()));
+            self.tracked_changed_entities.push(id);
+        }
+    }
+
     #[inline]
     pub(crate) fn get_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ComponentHooks> {
         self.components
@@ -3109,7 +3183,8 @@ pub fn component_clone_via_reflect(source: &SourceComponent, ctx: &mut Component
         let source_component_cloned = source_component_reflect.to_dynamic();
         let component_layout = component_info.layout();
         let target = ctx.target();
-        let component_id = ctx.component_id();
+        // The deferred command runs on the world of the target entity.
+        let component_id = ctx.target_component_id();
         drop(registry);
         ctx.queue_deferred(move |world: &mut World, mapper: &mut dyn EntityMapper| {
             let mut component = reflect_from_world.from_world(world);
@@ -3122,7 +3197,7 @@ pub fn component_clone_via_reflect(source: &SourceComponent, ctx: &mut Component
                 reflect_component.map_entities(&mut *component, mapper);
             }
             // SAFETY:
-            // - component_id is from the same world as target entity
+            // - component_id is the id of the component in the world of the target entity
             // - component is a valid value represented by component_id
             unsafe {
                 let raw_component_ptr =
@@ -5,12 +5,11 @@ use bumpalo::Bump;
 use core::any::TypeId;
 
 use crate::{
-    bundle::Bundle,
+    bundle::{Bundle, InsertMode},
     component::{Component, ComponentCloneBehavior, ComponentCloneFn, ComponentId, ComponentInfo},
     entity::{hash_map::EntityHashMap, Entities, Entity, EntityMapper},
-    query::DebugCheckedUnwrap,
     relationship::RelationshipHookMode,
-    world::World,
+    world::{World, WorldId},
 };
 
 /// Provides read access to the source component (the component being cloned) in a [`ComponentCloneFn`].
@@ -72,6 +71,7 @@ impl<'a> SourceComponent<'a> {
 /// and allows component clone handler to get information about component being cloned.
 pub struct ComponentCloneCtx<'a, 'b> {
     component_id: ComponentId,
+    target_component_id: ComponentId,
     target_component_written: bool,
     bundle_scratch: &'a mut BundleScratch<'b>,
     bundle_scratch_allocator: &'b Bump,
@@ -95,8 +95,10 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
     /// Caller must ensure that:
     /// - `component_info` corresponds to the `component_id` in the same world,.
     /// - `source_component_ptr` points to a valid component of type represented by `component_id`.
+    /// - `target_component_id` is the id of the same component in the world of the `target` entity.
     unsafe fn new(
         component_id: ComponentId,
+        target_component_id: ComponentId,
         source: Entity,
         target: Entity,
         bundle_scratch_allocator: &'b Bump,
@@ -110,6 +112,7 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
     ) -> Self {
         Self {
             component_id,
+            target_component_id,
             source,
             target,
             bundle_scratch,
@@ -143,6 +146,15 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         self.component_id
     }
 
+    /// Returns the [`ComponentId`] of the component being cloned in the world of the target
+    /// entity.
+    ///
+    /// This is the same as [`component_id`](Self::component_id), unless the entity is cloned
+    /// into another [`World`].
+    pub fn target_component_id(&self) -> ComponentId {
+        self.target_component_id
+    }
+
     /// Returns the [`ComponentInfo`] of the component being cloned.
     pub fn component_info(&self) -> &ComponentInfo {
         self.component_info
@@ -183,8 +195,11 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         };
         // SAFETY: the TypeId of self.component_id has been checked to ensure it matches `C`
         unsafe {
-            self.bundle_scratch
-                .push(self.bundle_scratch_allocator, self.component_id, component);
+            self.bundle_scratch.push(
+                self.bundle_scratch_allocator,
+                self.target_component_id,
+                component,
+            );
         };
         self.target_component_written = true;
     }
@@ -205,7 +220,7 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         let target_ptr = self.bundle_scratch_allocator.alloc_layout(layout);
         core::ptr::copy_nonoverlapping(ptr.as_ptr(), target_ptr.as_ptr(), layout.size());
         self.bundle_scratch
-            .push_ptr(self.component_id, PtrMut::new(target_ptr));
+            .push_ptr(self.target_component_id, PtrMut::new(target_ptr));
         self.target_component_written = true;
     }
 
@@ -245,8 +260,10 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
                 target_component_data_ptr.as_ptr(),
                 component_layout.size(),
             );
-            self.bundle_scratch
-                .push_ptr(self.component_id, PtrMut::new(target_component_data_ptr));
+            self.bundle_scratch.push_ptr(
+                self.target_component_id,
+                PtrMut::new(target_component_data_ptr),
+            );
 
             if component_layout.size() > 0 {
                 // Ensure we don't attempt to deallocate zero-sized components
@@ -265,14 +282,15 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         self.type_registry
     }
 
-    /// Queues the `entity` to be cloned by the current [`EntityCloner`]
+    /// Queues the `entity` to be cloned by the current [`EntityCloner`], into a new entity
+    /// reserved in the world of the target entity.
     pub fn queue_entity_clone(&mut self, entity: Entity) {
         let target = self.entities.reserve_entity();
         self.mapper.set_mapped(entity, target);
         self.entity_cloner.clone_queue.push_back(entity);
     }
 
-    /// Queues a deferred clone operation, which will run with exclusive [`World`] access immediately after calling the clone handler for each component on an entity.
+    /// Queues a deferred clone operation, which will run with exclusive access to the [`World`] of the target entity immediately after calling the clone handler for each component on an entity.
     /// This exists, despite its similarity to [`Commands`](crate::system::Commands), to provide access to the entity mapper in the current context.
     pub fn queue_deferred(
         &mut self,
@@ -352,6 +370,13 @@ pub struct EntityCloner {
     default_clone_fn: ComponentCloneFn,
     clone_queue: VecDeque<Entity>,
     deferred_commands: VecDeque<Box<dyn FnOnce(&mut World, &mut dyn EntityMapper)>>,
+    // The ids of the components of the source world in the target world, when cloning between
+    // the worlds of `component_map_worlds`.
+    component_map: HashMap<ComponentId, Option<ComponentId>>,
+    component_map_worlds: Option<(WorldId, WorldId)>,
+    // The source and target ids of the components of the last cloned entity whose clone handler
+    // neither wrote a component nor queued a deferred command.
+    uncloned_components: Vec<(ComponentId, ComponentId)>,
 }
 
 impl Default for EntityCloner {
@@ -365,6 +390,9 @@ impl Default for EntityCloner {
             clone_behavior_overrides: Default::default(),
             clone_queue: Default::default(),
             deferred_commands: Default::default(),
+            component_map: Default::default(),
+            component_map_worlds: None,
+            uncloned_components: Vec::new(),
         }
     }
 }
@@ -418,6 +446,7 @@ impl<'a> BundleScratch<'a> {
         self,
         world: &mut World,
         entity: Entity,
+        insert_mode: InsertMode,
         relationship_hook_insert_mode: RelationshipHookMode,
     ) {
         // SAFETY:
@@ -427,6 +456,7 @@ impl<'a> BundleScratch<'a> {
             world.entity_mut(entity).insert_by_ids_internal(
                 &self.component_ids,
                 self.component_ptrs.into_iter().map(|ptr| ptr.promote()),
+                insert_mode,
                 relationship_hook_insert_mode,
             );
         }
@@ -450,6 +480,104 @@ impl EntityCloner {
         self.linked_cloning
     }
 
+    /// Runs the clone handlers of the allowed components of the `source` entity, and returns the
+    /// cloned components.
+    ///
+    /// `target_ids` maps the ids of the components of `source_world` to the world of the `target`
+    /// entity, whose entities are `target_entities`. It is `None` when cloning within a world.
+    fn clone_components<'b>(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target: Entity,
+        target_entities: &Entities,
+        target_ids: Option<&HashMap<ComponentId, Option<ComponentId>>>,
+        mapper: &mut dyn EntityMapper,
+        bundle_scratch_allocator: &'b Bump,
+    ) -> BundleScratch<'b> {
+        let source_entity = source_world
+            .get_entity(source)
+            .expect("Source entity must exist");
+
+        #[cfg(feature = "bevy_reflect")]
+        // We clone the registry, which prevents conflicts with the clone handlers accessing it.
+        let app_registry = source_world
+            .get_resource::<crate::reflect::AppTypeRegistry>()
+            .cloned();
+        #[cfg(not(feature = "bevy_reflect"))]
+        let app_registry = Option::<()>::None;
+
+        let archetype = source_entity.archetype();
+        let mut bundle_scratch = BundleScratch::with_capacity(archetype.component_count());
+        self.uncloned_components.clear();
+
+        for component in archetype.components() {
+            if !self.is_cloning_allowed(&component) {
+                continue;
+            }
+            let target_component = match target_ids {
+                None => component,
+                Some(target_ids) => match target_ids.get(&component) {
+                    Some(&Some(target_component)) => target_component,
+                    _ => continue,
+                },
+            };
+
+            let handler = match self.clone_behavior_overrides.get(&component) {
+                Some(clone_behavior) => clone_behavior.resolve(self.default_clone_fn),
+                None => source_world
+                    .components()
+                    .get_info(component)
+                    .map(|info| info.clone_behavior().resolve(self.default_clone_fn))
+                    .unwrap_or(self.default_clone_fn),
+            };
+
+            // SAFETY: This component exists because it is present on the archetype.
+            let info = unsafe { source_world.components().get_info_unchecked(component) };
+            let deferred_commands = self.deferred_commands.len();
+
+            let source_component = SourceComponent {
+                info,
+                // `component` is from `source_entity`'s archetype
+                ptr: source_entity.get_by_id(component).unwrap(),
+            };
+
+            // SAFETY:
+            // - `components` and `component` are from the same world
+            // - `source_component_ptr` is valid and points to the same type as represented by `component`
+            // - `target_component` is `component` or was registered from `info` in the target world
+            let mut ctx = unsafe {
+                ComponentCloneCtx::new(
+                    component,
+                    target_component,
+                    source,
+                    target,
+                    bundle_scratch_allocator,
+                    &mut bundle_scratch,
+                    target_entities,
+                    info,
+                    self,
+                    mapper,
+                    app_registry.as_ref(),
+                )
+            };
+
+            (handler)(&source_component, &mut ctx);
+            let written = ctx.target_component_written();
+            if !written && self.deferred_commands.len() == deferred_commands {
+                self.uncloned_components.push((component, target_component));
+            }
+        }
+
+        bundle_scratch
+    }
+
+    /// Returns the ids of the components of the last cloned entity that weren't cloned, in the
+    /// source world and in the target world.
+    pub(crate) fn uncloned_components(&self) -> &[(ComponentId, ComponentId)] {
+        &self.uncloned_components
+    }
+
     /// Clones and inserts components from the `source` entity into the entity mapped by `mapper` from `source` using the stored configuration.
     fn clone_entity_internal(
         &mut self,
@@ -461,74 +589,15 @@ impl EntityCloner {
         let target = mapper.get_mapped(source);
         // PERF: reusing allocated space across clones would be more efficient. Consider an allocation model similar to `Commands`.
         let bundle_scratch_allocator = Bump::new();
-        let mut bundle_scratch: BundleScratch;
-        {
-            let world = world.as_unsafe_world_cell();
-            let source_entity = world.get_entity(source).expect("Source entity must exist");
-
-            #[cfg(feature = "bevy_reflect")]
-            // SAFETY: we have unique access to `world`, nothing else accesses the registry at this moment, and we clone
-            // the registry, which prevents future conflicts.
-            let app_registry = unsafe {
-                world
-                    .get_resource::<crate::reflect::AppTypeRegistry>()
-                    .cloned()
-            };
-            #[cfg(not(feature = "bevy_reflect"))]
-            let app_registry = Option::<()>::None;
-
-            let archetype = source_entity.archetype();
-            bundle_scratch = BundleScratch::with_capacity(archetype.component_count());
-
-            for component in archetype.components() {
-                if !self.is_cloning_allowed(&component) {
-                    continue;
-                }
-
-                let handler = match self.clone_behavior_overrides.get(&component) {
-                    Some(clone_behavior) => clone_behavior.resolve(self.default_clone_fn),
-                    None => world
-                        .components()
-                        .get_info(component)
-                        .map(|info| info.clone_behavior().resolve(self.default_clone_fn))
-                        .unwrap_or(self.default_clone_fn),
-                };
-
-                // SAFETY: This component exists because it is present on the archetype.
-                let info = unsafe { world.components().get_info_unchecked(component) };
-
-                // SAFETY:
-                // - There are no other mutable references to source entity.
-                // - `component` is from `source_entity`'s archetype
-                let source_component_ptr =
-                    unsafe { source_entity.get_by_id(component).debug_checked_unwrap() };
-
-                let source_component = SourceComponent {
-                    info,
-                    ptr: source_component_ptr,
-                };
-
-                // SAFETY:
-                // - `components` and `component` are from the same world
-                // - `source_component_ptr` is valid and points to the same type as represented by `component`
-                let mut ctx = unsafe {
-                    ComponentCloneCtx::new(
-                        component,
-                        source,
-                        target,
-                        &bundle_scratch_allocator,
-                        &mut bundle_scratch,
-                        world.entities(),
-                        info,
-                        self,
-                        mapper,
-                        app_registry.as_ref(),
-                    )
-                };
-
-                (handler)(&source_component, &mut ctx);
-            }
-        }
+        let bundle_scratch = self.clone_components(
+            world,
+            source,
+            target,
+            &world.entities,
+            None,
+            mapper,
+            &bundle_scratch_allocator,
+        );
 
         world.flush();
 
@@ -549,10 +618,94 @@ impl EntityCloner {
         // SAFETY:
         // - All `component_ids` are from the same world as `target` entity
         // - All `component_data_ptrs` are valid types represented by `component_ids`
-        unsafe { bundle_scratch.write(world, target, relationship_hook_insert_mode) };
+        unsafe {
+            bundle_scratch.write(
+                world,
+                target,
+                InsertMode::Replace,
+                relationship_hook_insert_mode,
+            );
+        };
+        target
+    }
+
+    /// Clones and inserts components from the `source` entity of `source_world` into the entity
+    /// of `target_world` mapped by `mapper` from `source`, using the stored configuration.
+    fn clone_entity_into_world_internal(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        mapper: &mut dyn EntityMapper,
+        insert_mode: InsertMode,
+        relationship_hook_insert_mode: RelationshipHookMode,
+    ) -> Entity {
+        let target = mapper.get_mapped(source);
+        self.map_components(source_world, source, target_world);
+        let component_map = core::mem::take(&mut self.component_map);
+        let bundle_scratch_allocator = Bump::new();
+        let bundle_scratch = self.clone_components(
+            source_world,
+            source,
+            target,
+            &target_world.entities,
+            Some(&component_map),
+            mapper,
+            &bundle_scratch_allocator,
+        );
+        self.component_map = component_map;
+
+        target_world.flush();
+
+        for deferred in self.deferred_commands.drain(..) {
+            (deferred)(target_world, mapper);
+        }
+
+        if !target_world.entities.contains(target) {
+            panic!("Target entity does not exist");
+        }
+
+        // SAFETY:
+        // - All `component_ids` were mapped to `target_world` by `map_components`
+        // - All `component_data_ptrs` are valid types represented by `component_ids`
+        unsafe {
+            bundle_scratch.write(
+                target_world,
+                target,
+                insert_mode,
+                relationship_hook_insert_mode,
+            );
+        };
         target
     }
 
+    /// Registers the allowed components of the `source` entity in `target_world`, and records
+    /// their ids in the component map.
+    fn map_components(&mut self, source_world: &World, source: Entity, target_world: &mut World) {
+        let worlds = (source_world.id(), target_world.id());
+        if self.component_map_worlds != Some(worlds) {
+            self.component_map.clear();
+            self.component_map_worlds = Some(worlds);
+        }
+
+        let source_entity = source_world
+            .get_entity(source)
+            .expect("Source entity must exist");
+        let archetype = source_entity.archetype();
+        let mut registrator = target_world.components_registrator();
+        for component in archetype.components() {
+            if !self.is_cloning_allowed(&component) {
+                continue;
+            }
+            self.component_map.entry(component).or_insert_with(|| {
+                source_world
+                    .components()
+                    .get_info(component)
+                    .and_then(|info| registrator.register_component_from_info(info))
+            });
+        }
+    }
+
     /// Clones and inserts components from the `source` entity into `target` entity using the stored configuration.
     /// If this [`EntityCloner`] has [`EntityCloner::linked_cloning`], then it will recursively spawn entities as defined
     /// by [`RelationshipTarget`](crate::relationship::RelationshipTarget) components with
@@ -605,12 +758,155 @@ impl EntityCloner {
         target
     }
 
+    /// Clones and inserts components from the `source` entity of `source_world` into the `target`
+    /// entity of `target_world` using the stored configuration.
+    /// If this [`EntityCloner`] has [`EntityCloner::linked_cloning`], then it will recursively spawn entities into
+    /// `target_world` as defined by [`RelationshipTarget`](crate::relationship::RelationshipTarget) components with
+    /// [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN).
+    ///
+    /// Components are matched between the worlds by their Rust type, and are registered in
+    /// `target_world` if they aren't yet. Components without a Rust type are not cloned. The
+    /// filters and clone behavior overrides of the cloner refer to the component ids of
+    /// `source_world`, and [`EntityClonerBuilder::move_components`] has no effect.
+    ///
+    /// Entities referenced by the cloned components that are not cloned along with `source` don't
+    /// exist in `target_world`, and are mapped to [`Entity::PLACEHOLDER`]. Relationships to them
+    /// are removed.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity::EntityCloner;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut simulation = World::new();
+    /// let parent = simulation.spawn(Health(10)).id();
+    /// simulation.spawn((Health(5), ChildOf(parent)));
+    ///
+    /// let mut world = World::new();
+    /// let mut builder = EntityCloner::build(&mut simulation);
+    /// builder.linked_cloning(true);
+    /// let mut cloner = builder.finish();
+    /// let clone = cloner.spawn_clone_into_world(&simulation, parent, &mut world);
+    ///
+    /// assert_eq!(world.get::<Health>(clone), Some(&Health(10)));
+    /// let child = world.get::<Children>(clone).unwrap()[0];
+    /// assert_eq!(world.get::<Health>(child), Some(&Health(5)));
+    /// ```
+    #[track_caller]
+    pub fn clone_entity_into_world(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        target: Entity,
+    ) {
+        let mut map = EntityHashMap::<Entity>::new();
+        map.set_mapped(source, target);
+        self.clone_entity_into_world_mapped(
+            source_world,
+            source,
+            target_world,
+            &mut CrossWorldMapper(&mut map),
+        );
+    }
+
+    /// Clones and inserts components from the `source` entity of `source_world` into a newly
+    /// spawned entity of `target_world` using the stored configuration.
+    ///
+    /// See [`EntityCloner::clone_entity_into_world`] for how components are cloned between worlds.
+    #[track_caller]
+    pub fn spawn_clone_into_world(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+    ) -> Entity {
+        let target = target_world.spawn_empty().id();
+        self.clone_entity_into_world(source_world, source, target_world, target);
+        target
+    }
+
+    /// Clones the `source` entity of `source_world` into whatever entity of `target_world`
+    /// `mapper` chooses for it.
+    ///
+    /// See [`EntityCloner::clone_entity_into_world`] for how components are cloned between worlds.
+    /// Unlike with that method, entities that are not mapped by `mapper` are mapped to whatever it
+    /// returns for them.
+    #[track_caller]
+    pub fn clone_entity_into_world_mapped(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        mapper: &mut dyn EntityMapper,
+    ) -> Entity {
+        self.clone_entity_into_world_with(
+            source_world,
+            source,
+            target_world,
+            mapper,
+            InsertMode::Replace,
+        )
+    }
+
+    /// Clones the `source` entity of `source_world` into whatever entity of `target_world`
+    /// `mapper` chooses for it, inserting the cloned components with `insert_mode`.
+    pub(crate) fn clone_entity_into_world_with(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        mapper: &mut dyn EntityMapper,
+        insert_mode: InsertMode,
+    ) -> Entity {
+        // Same as in `clone_entity_mapped`
+        let target = self.clone_entity_into_world_internal(
+            source_world,
+            source,
+            target_world,
+            mapper,
+            insert_mode,
+            RelationshipHookMode::Run,
+        );
+        let child_hook_insert_mode = if self.linked_cloning {
+            RelationshipHookMode::RunIfNotLinked
+        } else {
+            RelationshipHookMode::Run
+        };
+        while let Some(queued) = self.clone_queue.pop_front() {
+            self.clone_entity_into_world_internal(
+                source_world,
+                queued,
+                target_world,
+                mapper,
+                insert_mode,
+                child_hook_insert_mode,
+            );
+        }
+        target
+    }
+
     fn is_cloning_allowed(&self, component: &ComponentId) -> bool {
         (self.filter_allows_components && self.filter.contains(component))
             || (!self.filter_allows_components && !self.filter.contains(component))
     }
 }
 
+/// An [`EntityMapper`] for cloning entities into another [`World`], which maps the entities that
+/// have not been mapped to [`Entity::PLACEHOLDER`], since they don't exist in the target world.
+pub(crate) struct CrossWorldMapper<'a>(pub &'a mut EntityHashMap<Entity>);
+
+impl EntityMapper for CrossWorldMapper<'_> {
+    fn get_mapped(&mut self, source: Entity) -> Entity {
+        self.0.get(&source).copied().unwrap_or(Entity::PLACEHOLDER)
+    }
+
+    fn set_mapped(&mut self, source: Entity, target: Entity) {
+        self.0.insert(source, target);
+    }
+}
+
 /// A builder for configuring [`EntityCloner`]. See [`EntityCloner`] for more information.
 pub struct EntityClonerBuilder<'w> {
     world: &'w mut World,
@@ -983,6 +1279,64 @@ mod tests {
             assert_eq!(world.get::<D>(e_clone), Some(world.get::<D>(e).unwrap()));
         }
 
+        #[test]
+        fn clone_entity_into_world_using_reflect_from_world() {
+            #[derive(PartialEq, Eq, Default, Debug)]
+            struct NotClone;
+
+            // Only clonable with `ReflectFromWorld`
+            #[derive(Component, Reflect, PartialEq, Eq, Default, Debug)]
+            #[reflect(FromWorld)]
+            #[reflect(from_reflect = false)]
+            struct A {
+                field: Vec<usize>,
+                #[reflect(ignore)]
+                ignored: NotClone,
+            }
+
+            #[derive(Component, PartialEq, Eq, Debug)]
+            struct B(u8);
+
+            let mut source_world = World::default();
+            source_world.init_resource::<AppTypeRegistry>();
+            source_world
+                .resource::<AppTypeRegistry>()
+                .write()
+                .register::<A>();
+            source_world.register_component::<B>();
+            let a_id = source_world.register_component::<A>();
+            let source = source_world
+                .spawn(A {
+                    field: vec![1, 2],
+                    ignored: NotClone,
+                })
+                .id();
+
+            // The components are registered in a different order, so `A` has a different id in
+            // each world.
+            let mut target_world = World::default();
+            target_world.register_component::<A>();
+            target_world.register_component::<B>();
+
+            let mut builder = EntityCloner::build(&mut source_world);
+            builder.override_clone_behavior_with_id(a_id, ComponentCloneBehavior::reflect());
+            builder
+                .finish()
+                .spawn_clone_into_world(&source_world, source, &mut target_world);
+
+            let mut query = target_world.query::<(&A, Option<&B>)>();
+            assert_eq!(
+                query.single(&target_world).unwrap(),
+                (
+                    &A {
+                        field: vec![1, 2],
+                        ignored: NotClone
+                    },
+                    None
+                )
+            );
+        }
+
         #[test]
         fn read_source_component_reflect_should_return_none_on_invalid_reflect_from_ptr() {
             #[derive(Component, Reflect)]
@@ -1367,6 +1721,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clone_entity_into_world() {
+        #[derive(Component, Clone, PartialEq, Debug)]
+        #[require(B)]
+        #[component(on_add = count_add)]
+        struct A(u32);
+
+        #[derive(Component, Clone, Default, PartialEq, Debug)]
+        #[component(storage = "SparseSet")]
+        struct B;
+
+        #[derive(Resource, Default)]
+        struct Adds(u32);
+
+        fn count_add(mut world: crate::world::DeferredWorld, _: crate::component::HookContext) {
+            if let Some(mut adds) = world.get_resource_mut::<Adds>() {
+                adds.0 += 1;
+            }
+        }
+
+        let mut source_world = World::new();
+        // Shift the component ids, so they differ between the worlds.
+        source_world.register_component::<ChildOf>();
+        let parent = source_world.spawn_empty().id();
+        let source = source_world.spawn((A(5), ChildOf(parent))).id();
+
+        let mut target_world = World::new();
+        target_world.init_resource::<Adds>();
+        let target = EntityCloner::default().spawn_clone_into_world(
+            &source_world,
+            source,
+            &mut target_world,
+        );
+
+        assert_eq!(target_world.get::<A>(target), Some(&A(5)));
+        assert_eq!(target_world.get::<B>(target), Some(&B));
+        // The parent wasn't cloned, so the relationship is removed.
+        assert!(target_world.get::<ChildOf>(target).is_none());
+        assert_eq!(target_world.resource::<Adds>().0, 1);
+
+        // Components are registered like they would be in the target world.
+        let a = target_world.component_id::<A>().unwrap();
+        let b = target_world.component_id::<B>().unwrap();
+        let info = target_world.components().get_info(a).unwrap();
+        assert!(info.required_components().iter_ids().any(|id| id == b));
+        assert_ne!(source_world.component_id::<A>(), Some(a));
+        assert_eq!(target_world.spawn(A(1)).get::<B>(), Some(&B));
+        assert_eq!(target_world.resource::<Adds>().0, 2);
+    }
+
+    #[test]
+    fn recursive_clone_into_world() {
+        let mut source_world = World::new();
+        let root = source_world.spawn_empty().id();
+        let child1 = source_world.spawn(ChildOf(root)).id();
+        source_world.spawn(ChildOf(child1));
+        source_world.spawn(ChildOf(root));
+
+        let mut target_world = World::new();
+        target_world.spawn_empty();
+        let mut builder = EntityCloner::build(&mut source_world);
+        builder.linked_cloning(true);
+        let mut cloner = builder.finish();
+        let clone_root = cloner.spawn_clone_into_world(&source_world, root, &mut target_world);
+
+        let root_children = target_world.get::<Children>(clone_root).unwrap().to_vec();
+        assert_eq!(root_children.len(), 2);
+        for &child in &root_children {
+            assert_eq!(
+                target_world.get::<ChildOf>(child),
+                Some(&ChildOf(clone_root))
+            );
+        }
+        let child1_children = target_world.get::<Children>(root_children[0]).unwrap();
+        assert_eq!(child1_children.len(), 1);
+        assert_eq!(target_world.entities().len(), 5);
+        assert_eq!(source_world.entities().len(), 4);
+    }
+
+    #[test]
+    fn clone_into_world_skips_dynamic_components() {
+        let mut source_world = World::new();
+        // SAFETY: the layout and drop fn match the component data
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                "DynamicComp",
+                StorageType::Table,
+                Layout::new::<u64>(),
+                None,
+                true,
+                ComponentCloneBehavior::Default,
+            )
+        };
+        let component_id = source_world.register_component_with_descriptor(descriptor);
+        let mut entity = source_world.spawn_empty();
+        OwningPtr::make(5u64, |ptr| {
+            // SAFETY: `ptr` points to a `u64`, as described by `component_id`
+            unsafe { entity.insert_by_id(component_id, ptr) };
+        });
+        let source = entity.id();
+
+        let mut target_world = World::new();
+        let target = EntityCloner::default().spawn_clone_into_world(
+            &source_world,
+            source,
+            &mut target_world,
+        );
+        assert_eq!(target_world.entity(target).archetype().component_count(), 0);
+    }
+
     #[test]
     fn clone_with_reflect_from_world() {
         #[derive(Component, Reflect, PartialEq, Eq, Debug)]
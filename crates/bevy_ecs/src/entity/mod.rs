@@ -82,9 +82,10 @@ use crate::{
     },
     storage::{SparseSetIndex, TableId, TableRow},
 };
-use alloc::vec::Vec;
+use alloc::{collections::VecDeque, vec::Vec};
 use bevy_platform_support::sync::atomic::Ordering;
-use core::{fmt, hash::Hash, mem, num::NonZero, panic::Location};
+use core::{fmt, hash::Hash, mem, num::NonZero, ops::Range, panic::Location};
+use fixedbitset::FixedBitSet;
 use log::warn;
 
 #[cfg(feature = "serialize")]
@@ -526,6 +527,56 @@ impl<'a> core::iter::FusedIterator for ReserveEntitiesIterator<'a> {}
 // SAFETY: Newly reserved entity values are unique.
 unsafe impl EntitySetIterator for ReserveEntitiesIterator<'_> {}
 
+/// Controls which index [`Entities`] gives to newly allocated entities.
+///
+/// The default mode recycles the indices of freed entities, so the ids handed out depend on
+/// the order in which entities were despawned. The other modes never reuse a freed index, making
+/// entity ids reproducible across worlds that spawned the same number of entities but despawned
+/// them differently, as needed for lockstep networking or replays.
+///
+/// Entities with a specific id can be spawned in any mode with [`World::spawn_at`].
+///
+/// [`World::spawn_at`]: crate::world::World::spawn_at
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EntityAllocationMode {
+    /// Reuse the index of the most recently freed entity, and only allocate a new index once
+    /// there are no freed indices left.
+    #[default]
+    Recycle,
+    /// Never reuse the index of a freed entity: every allocation takes the lowest index that
+    /// has never been used.
+    Sequential,
+    /// Like [`Sequential`](Self::Sequential), but only take indices from the ranges added with
+    /// [`Entities::add_allocation_range`], in the order they were added.
+    ///
+    /// Allocating an entity panics once all ranges are used up, and reserving entities panics
+    /// if they don't fit in the remainder of the current range. Indices of a range that are
+    /// skipped by [`World::spawn_at`] are still allocated, before the rest of the ranges.
+    Ranges,
+}
+
+/// An error that occurs when a specific [`Entity`] id can't be allocated.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocAtError {
+    /// The index of the entity is currently used by another entity, or reserved for one.
+    #[error("The entity index of {requested} is already used by {current}")]
+    InUse {
+        /// The entity that was requested.
+        requested: Entity,
+        /// The entity currently using the index.
+        current: Entity,
+    },
+    /// The generation of the entity is lower than the one of the freed index, so stale copies of
+    /// the requested id may still be around.
+    #[error("{requested} is older than the next generation of its index, {current}")]
+    StaleGeneration {
+        /// The entity that was requested.
+        requested: Entity,
+        /// The freed index, with its next generation.
+        current: Entity,
+    },
+}
+
 /// A [`World`]'s internal metadata store on all of its entities.
 ///
 /// Contains metadata on:
@@ -580,6 +631,21 @@ pub struct Entities {
     /// [`flush`]: Entities::flush
     pending: Vec<u32>,
     free_cursor: AtomicIdCursor,
+
+    mode: EntityAllocationMode,
+    /// Indices of freed entities that will never be handed out by [`alloc`] or [`reserve_entity`],
+    /// because the [allocation mode](EntityAllocationMode) doesn't recycle them.
+    /// In those modes, `pending` only holds the unused indices of allocation ranges that were
+    /// skipped by [`try_alloc_at`], lowest last, so they are still handed out.
+    ///
+    /// [`alloc`]: Entities::alloc
+    /// [`reserve_entity`]: Entities::reserve_entity
+    /// [`try_alloc_at`]: Entities::try_alloc_at
+    retired: FixedBitSet,
+    retired_count: u32,
+    /// The ranges that new indices are taken from in [`EntityAllocationMode::Ranges`].
+    /// The front range is the current one, and `meta.len()` is always within it.
+    ranges: VecDeque<Range<u32>>,
 }
 
 impl Entities {
@@ -588,6 +654,163 @@ impl Entities {
             meta: Vec::new(),
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
+            mode: EntityAllocationMode::Recycle,
+            retired: FixedBitSet::new(),
+            retired_count: 0,
+            ranges: VecDeque::new(),
+        }
+    }
+
+    /// Returns the [`EntityAllocationMode`] used to pick the index of new entities.
+    #[inline]
+    pub fn allocation_mode(&self) -> EntityAllocationMode {
+        self.mode
+    }
+
+    /// Changes the [`EntityAllocationMode`] used to pick the index of new entities.
+    ///
+    /// When switching back to [`EntityAllocationMode::Recycle`], the indices freed in the other
+    /// modes become available again, lowest first.
+    ///
+    /// Must not be called while reserved entities are awaiting `flush()`.
+    pub fn set_allocation_mode(&mut self, mode: EntityAllocationMode) {
+        self.verify_flushed();
+
+        if mode == EntityAllocationMode::Recycle && self.mode != EntityAllocationMode::Recycle {
+            self.pending
+                .extend(self.retired.ones().rev().map(|index| index as u32));
+            self.retired.clear();
+            self.retired_count = 0;
+        } else if mode == EntityAllocationMode::Sequential
+            || (mode == EntityAllocationMode::Ranges && self.mode == EntityAllocationMode::Recycle)
+        {
+            for index in mem::take(&mut self.pending) {
+                self.retire(index);
+            }
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+
+        self.mode = mode;
+        self.advance_allocation_range();
+    }
+
+    /// Adds a range of indices that new entities can be allocated from in
+    /// [`EntityAllocationMode::Ranges`].
+    ///
+    /// Ranges are used up in the order they were added, skipping indices that are already in
+    /// use. Indices between two ranges are never allocated, but still take up memory, so ranges
+    /// should not be too far apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` starts before the end of the previously added range.
+    pub fn add_allocation_range(&mut self, range: Range<u32>) {
+        if let Some(last) = self.ranges.back() {
+            assert!(
+                range.start >= last.end,
+                "entity allocation range {range:?} overlaps or precedes the previous range {last:?}"
+            );
+        }
+        if !range.is_empty() {
+            self.ranges.push_back(range);
+            self.advance_allocation_range();
+        }
+    }
+
+    /// Marks the freed `index` as never to be reused.
+    fn retire(&mut self, index: u32) {
+        self.retired.grow(index as usize + 1);
+        self.retired.insert(index as usize);
+        self.retired_count += 1;
+    }
+
+    /// Returns `true` if the index below `meta.len()` is not used by an entity.
+    fn is_free(&self, index: u32) -> bool {
+        self.retired.contains(index as usize) || self.pending.contains(&index)
+    }
+
+    /// Makes the freed `index` available for a specific allocation, returning `false` if it is
+    /// not free.
+    fn take_free(&mut self, index: u32) -> bool {
+        if let Some(position) = self.pending.iter().position(|item| *item == index) {
+            if self.mode == EntityAllocationMode::Recycle {
+                self.pending.swap_remove(position);
+            } else {
+                // Keep the unused range indices sorted.
+                self.pending.remove(position);
+            }
+            *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        } else {
+            if !self.retired.contains(index as usize) {
+                return false;
+            }
+            self.retired.remove(index as usize);
+            self.retired_count -= 1;
+        }
+        true
+    }
+
+    /// Grows `meta` to `len` entries, freeing all the new indices.
+    ///
+    /// In [`EntityAllocationMode::Ranges`], the new indices that are part of an allocation range
+    /// can still be allocated, and the others are retired.
+    fn free_up_to(&mut self, len: u32) {
+        let skipped = (self.meta.len() as u32)..len;
+        match self.mode {
+            EntityAllocationMode::Recycle => {
+                self.pending.extend(skipped);
+            }
+            EntityAllocationMode::Sequential => {
+                for index in skipped {
+                    self.retire(index);
+                }
+            }
+            EntityAllocationMode::Ranges => {
+                let mut unused = Vec::new();
+                for index in skipped {
+                    if self.ranges.iter().any(|range| range.contains(&index)) {
+                        unused.push(index);
+                    } else {
+                        self.retire(index);
+                    }
+                }
+                // The unused indices are handed out lowest first, and are all higher than the
+                // ones already pending.
+                self.pending.splice(0..0, unused.into_iter().rev());
+            }
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        self.meta.resize(len as usize, EntityMeta::EMPTY);
+    }
+
+    /// In [`EntityAllocationMode::Ranges`], drops the ranges that are used up and moves the end
+    /// of `meta` to the start of the current range, so new indices are taken from it.
+    fn advance_allocation_range(&mut self) {
+        if self.mode != EntityAllocationMode::Ranges {
+            return;
+        }
+        while let Some(range) = self.ranges.front() {
+            let len = self.meta.len() as u32;
+            if len >= range.end {
+                self.ranges.pop_front();
+            } else {
+                if len < range.start {
+                    self.free_up_to(range.start);
+                }
+                break;
+            }
+        }
+    }
+
+    /// Panics if new indices up to `end` (exclusive) can't be allocated in the current mode.
+    #[inline]
+    fn check_allocation_end(&self, end: u32) {
+        if self.mode == EntityAllocationMode::Ranges {
+            let range_end = self.ranges.front().map_or(0, |range| range.end);
+            assert!(
+                end <= range_end,
+                "the current entity allocation range is used up; add more with `Entities::add_allocation_range`"
+            );
         }
     }
 
@@ -633,6 +856,7 @@ impl Entities {
             let base = self.meta.len() as IdCursor;
 
             let new_id_end = u32::try_from(base - range_start).expect("too many entities");
+            self.check_allocation_end(new_id_end);
 
             // `new_id_end` is in range, so no need to check `start`.
             let new_id_start = (base - range_end.min(0)) as u32;
@@ -662,9 +886,9 @@ impl Entities {
             //
             // As `self.free_cursor` goes more and more negative, we return IDs farther
             // and farther beyond `meta.len()`.
-            Entity::from_raw(
-                u32::try_from(self.meta.len() as IdCursor - n).expect("too many entities"),
-            )
+            let index = u32::try_from(self.meta.len() as IdCursor - n).expect("too many entities");
+            self.check_allocation_end(index + 1);
+            Entity::from_raw(index)
         }
     }
 
@@ -685,11 +909,50 @@ impl Entities {
             Entity::from_raw_and_generation(index, self.meta[index as usize].generation)
         } else {
             let index = u32::try_from(self.meta.len()).expect("too many entities");
+            self.check_allocation_end(index + 1);
             self.meta.push(EntityMeta::EMPTY);
+            self.advance_allocation_range();
             Entity::from_raw(index)
         }
     }
 
+    /// Allocate a specific entity ID, if its index is free.
+    ///
+    /// Fails if the index is used by another entity, or if the generation of `entity` is lower
+    /// than the next generation of its index. Any index that isn't in use can be allocated,
+    /// regardless of the [`EntityAllocationMode`].
+    ///
+    /// In [`EntityAllocationMode::Recycle`] this takes time proportional to the number of freed
+    /// entities.
+    pub fn try_alloc_at(&mut self, entity: Entity) -> Result<(), AllocAtError> {
+        self.verify_flushed();
+
+        let index = entity.index();
+        if let Some(meta) = self.meta.get(index as usize) {
+            let current = Entity::from_raw_and_generation(index, meta.generation);
+            if !self.is_free(index) {
+                return Err(AllocAtError::InUse {
+                    requested: entity,
+                    current,
+                });
+            }
+            if entity.generation < meta.generation {
+                return Err(AllocAtError::StaleGeneration {
+                    requested: entity,
+                    current,
+                });
+            }
+            self.take_free(index);
+        } else {
+            self.free_up_to(index);
+            self.meta.push(EntityMeta::EMPTY);
+            self.advance_allocation_range();
+        }
+
+        self.meta[index as usize].generation = entity.generation;
+        Ok(())
+    }
+
     /// Allocate a specific entity ID, overwriting its generation.
     ///
     /// Returns the location of the entity currently using the given ID, if any. Location should be
@@ -702,17 +965,11 @@ impl Entities {
         self.verify_flushed();
 
         let loc = if entity.index() as usize >= self.meta.len() {
-            self.pending
-                .extend((self.meta.len() as u32)..entity.index());
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.meta
-                .resize(entity.index() as usize + 1, EntityMeta::EMPTY);
+            self.free_up_to(entity.index());
+            self.meta.push(EntityMeta::EMPTY);
+            self.advance_allocation_range();
             None
-        } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index()) {
-            self.pending.swap_remove(index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
+        } else if self.take_free(entity.index()) {
             None
         } else {
             Some(mem::replace(
@@ -744,17 +1001,11 @@ impl Entities {
         self.verify_flushed();
 
         let result = if entity.index() as usize >= self.meta.len() {
-            self.pending
-                .extend((self.meta.len() as u32)..entity.index());
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.meta
-                .resize(entity.index() as usize + 1, EntityMeta::EMPTY);
+            self.free_up_to(entity.index());
+            self.meta.push(EntityMeta::EMPTY);
+            self.advance_allocation_range();
             AllocAtWithoutReplacement::DidNotExist
-        } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index()) {
-            self.pending.swap_remove(index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
+        } else if self.take_free(entity.index()) {
             AllocAtWithoutReplacement::DidNotExist
         } else {
             let current_meta = &self.meta[entity.index() as usize];
@@ -793,7 +1044,11 @@ impl Entities {
 
         let loc = mem::replace(&mut meta.location, EntityMeta::EMPTY.location);
 
-        self.pending.push(entity.index());
+        if self.mode == EntityAllocationMode::Recycle {
+            self.pending.push(entity.index());
+        } else {
+            self.retire(entity.index());
+        }
 
         let new_free_cursor = self.pending.len() as IdCursor;
         *self.free_cursor.get_mut() = new_free_cursor;
@@ -834,6 +1089,9 @@ impl Entities {
         self.meta.clear();
         self.pending.clear();
         *self.free_cursor.get_mut() = 0;
+        self.retired.clear();
+        self.retired_count = 0;
+        self.advance_allocation_range();
     }
 
     /// Returns the location of an [`Entity`].
@@ -947,6 +1205,8 @@ impl Entities {
                 &mut meta.location,
             );
         }
+
+        self.advance_allocation_range();
     }
 
     /// Flushes all reserved entities to an "invalid" state. Attempting to retrieve them will return `None`
@@ -979,7 +1239,9 @@ impl Entities {
     /// [`World`]: crate::world::World
     #[inline]
     pub fn used_count(&self) -> usize {
-        (self.meta.len() as isize - self.free_cursor.load(Ordering::Relaxed) as isize) as usize
+        (self.meta.len() as isize
+            - self.free_cursor.load(Ordering::Relaxed) as isize
+            - self.retired_count as isize) as usize
     }
 
     /// The count of all entities in the [`World`] that have ever been allocated or reserved, including those that are freed.
@@ -994,8 +1256,8 @@ impl Entities {
     /// The count of currently allocated entities.
     #[inline]
     pub fn len(&self) -> u32 {
-        // `pending`, by definition, can't be bigger than `meta`.
-        (self.meta.len() - self.pending.len()) as u32
+        // `pending` and `retired`, by definition, can't be bigger than `meta`.
+        (self.meta.len() - self.pending.len()) as u32 - self.retired_count
     }
 
     /// Checks if any entity is currently active.
@@ -1037,6 +1299,69 @@ impl Entities {
         .map(Option::flatten)
     }
 
+    /// Copies the generation of every entity index, the alive/dead status of each index, the
+    /// order of the freelist and the allocation mode, so that they can later be restored with
+    /// [`restore_allocator_state`](Entities::restore_allocator_state).
+    ///
+    /// Must not be called while reserved entities are awaiting `flush()`.
+    pub(crate) fn allocator_state(&self) -> EntitiesAllocatorState {
+        debug_assert!(
+            self.free_cursor.load(Ordering::Relaxed) == self.pending.len() as IdCursor,
+            "flush() needs to be called before this operation is legal"
+        );
+        EntitiesAllocatorState {
+            generations: self.meta.iter().map(|meta| meta.generation).collect(),
+            alive: self
+                .meta
+                .iter()
+                .enumerate()
+                .filter(|(_, meta)| meta.location.archetype_id != ArchetypeId::INVALID)
+                .map(|(index, _)| index)
+                .collect(),
+            pending: self.pending.clone(),
+            mode: self.mode,
+            retired: self.retired.clone(),
+            ranges: self.ranges.clone(),
+        }
+    }
+
+    /// Restores the generations, the freelist and the allocation mode captured by [`allocator_state`](Entities::allocator_state).
+    ///
+    /// Entities that are alive in `state` keep their current location if they are still alive.
+    /// Every other index is left without a location, and entities that are alive in `state` but
+    /// not currently allocated must be given one before handing control to unknown code.
+    ///
+    /// Must not be called while reserved entities are awaiting `flush()`, or while an entity that
+    /// is not alive in `state` is still alive.
+    pub(crate) fn restore_allocator_state(&mut self, state: &EntitiesAllocatorState) {
+        self.verify_flushed();
+        debug_assert!(
+            self.meta.iter().enumerate().all(|(index, meta)| {
+                meta.location.archetype_id == ArchetypeId::INVALID
+                    || state.contains(Entity::from_raw_and_generation(
+                        index as u32,
+                        meta.generation,
+                    ))
+            }),
+            "entities that are not alive in the restored state must be freed first"
+        );
+
+        self.meta.resize(state.generations.len(), EntityMeta::EMPTY);
+        for (meta, &generation) in self.meta.iter_mut().zip(&state.generations) {
+            if meta.generation != generation {
+                meta.generation = generation;
+                meta.location = EntityLocation::INVALID;
+            }
+        }
+
+        self.pending.clone_from(&state.pending);
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        self.mode = state.mode;
+        self.retired.clone_from(&state.retired);
+        self.retired_count = self.retired.count_ones(..) as u32;
+        self.ranges.clone_from(&state.ranges);
+    }
+
     /// Constructs a message explaining why an entity does not exist, if known.
     pub(crate) fn entity_does_not_exist_error_details(
         &self,
@@ -1048,6 +1373,34 @@ impl Entities {
     }
 }
 
+/// A copy of the allocation state of [`Entities`].
+///
+/// Restoring it reproduces the exact same sequence of entity ids for future allocations.
+#[derive(Clone, Debug)]
+pub(crate) struct EntitiesAllocatorState {
+    generations: Vec<NonZero<u32>>,
+    alive: FixedBitSet,
+    pending: Vec<u32>,
+    mode: EntityAllocationMode,
+    retired: FixedBitSet,
+    ranges: VecDeque<Range<u32>>,
+}
+
+impl EntitiesAllocatorState {
+    /// Returns `true` if `entity` was alive when this state was captured.
+    pub(crate) fn contains(&self, entity: Entity) -> bool {
+        let index = entity.index() as usize;
+        self.alive.contains(index) && self.generations[index] == entity.generation
+    }
+
+    /// Iterates over all entities that were alive when this state was captured, in index order.
+    pub(crate) fn iter_alive(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.alive
+            .ones()
+            .map(|index| Entity::from_raw_and_generation(index as u32, self.generations[index]))
+    }
+}
+
 /// An error that occurs when a specified [`Entity`] does not exist.
 #[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[error("The entity with ID {entity} {details}")]
@@ -1230,6 +1583,140 @@ mod tests {
         assert!(next_entity.generation() > entity.generation() + GENERATIONS);
     }
 
+    #[test]
+    fn sequential_allocation_ignores_free_order() {
+        let allocate = |free_order: [usize; 3]| {
+            let mut entities = Entities::new();
+            entities.set_allocation_mode(EntityAllocationMode::Sequential);
+            let allocated = [entities.alloc(), entities.alloc(), entities.alloc()];
+            for i in free_order {
+                entities.free(allocated[i]);
+            }
+            assert_eq!(entities.len(), 0);
+            let reserved = entities.reserve_entity();
+            entities.flush_as_invalid();
+            [entities.alloc(), reserved]
+        };
+
+        let ids = allocate([0, 1, 2]);
+        assert_eq!(ids, allocate([2, 0, 1]));
+        assert_eq!(ids.map(Entity::index), [4, 3]);
+    }
+
+    #[test]
+    fn switch_allocation_mode() {
+        let mut entities = Entities::new();
+        let a = entities.alloc();
+        let b = entities.alloc();
+        entities.free(b);
+        entities.set_allocation_mode(EntityAllocationMode::Sequential);
+        entities.free(a);
+        assert_eq!(entities.alloc().index(), 2);
+
+        entities.set_allocation_mode(EntityAllocationMode::Recycle);
+        assert_eq!(entities.alloc().index(), 0);
+        assert_eq!(entities.alloc().index(), 1);
+        assert_eq!(entities.len(), 3);
+    }
+
+    #[test]
+    fn range_allocation() {
+        let mut entities = Entities::new();
+        entities.alloc();
+        entities.set_allocation_mode(EntityAllocationMode::Ranges);
+        entities.add_allocation_range(10..12);
+        entities.add_allocation_range(20..21);
+
+        let reserved = entities.reserve_entity();
+        entities.flush_as_invalid();
+        assert_eq!(reserved.index(), 10);
+        assert_eq!(entities.alloc().index(), 11);
+        assert_eq!(entities.alloc().index(), 20);
+        assert_eq!(entities.len(), 4);
+    }
+
+    #[test]
+    fn range_allocation_after_try_alloc_at() {
+        let mut entities = Entities::new();
+        entities.set_allocation_mode(EntityAllocationMode::Ranges);
+        entities.add_allocation_range(0..3);
+        entities.add_allocation_range(10..12);
+        entities.alloc();
+
+        // Spawning past the current range keeps its unused indices, but not the gap between
+        // the ranges.
+        assert_eq!(entities.try_alloc_at(Entity::from_raw(100)), Ok(()));
+        let reserved = entities.reserve_entity();
+        entities.flush_as_invalid();
+        assert_eq!(reserved.index(), 1);
+        assert_eq!(
+            [entities.alloc(), entities.alloc(), entities.alloc()].map(Entity::index),
+            [2, 10, 11]
+        );
+        assert!(entities.try_alloc_at(Entity::from_raw(5)).is_ok());
+        assert_eq!(entities.len(), 7);
+
+        // Switching to sequential allocation retires the unused range indices.
+        let mut entities = Entities::new();
+        entities.set_allocation_mode(EntityAllocationMode::Ranges);
+        entities.add_allocation_range(0..3);
+        assert_eq!(entities.try_alloc_at(Entity::from_raw(5)), Ok(()));
+        entities.set_allocation_mode(EntityAllocationMode::Sequential);
+        assert_eq!(entities.alloc().index(), 6);
+    }
+
+    #[test]
+    #[should_panic = "the current entity allocation range is used up"]
+    fn range_allocation_exhausted() {
+        let mut entities = Entities::new();
+        entities.set_allocation_mode(EntityAllocationMode::Ranges);
+        entities.add_allocation_range(0..1);
+        entities.alloc();
+        entities.alloc();
+    }
+
+    #[test]
+    fn try_alloc_at() {
+        for mode in [
+            EntityAllocationMode::Recycle,
+            EntityAllocationMode::Sequential,
+        ] {
+            let mut entities = Entities::new();
+            entities.set_allocation_mode(mode);
+            let a = entities.alloc();
+            let b = entities.alloc();
+            entities.free(b);
+
+            assert_eq!(
+                entities.try_alloc_at(a),
+                Err(AllocAtError::InUse {
+                    requested: a,
+                    current: a
+                })
+            );
+            assert!(matches!(
+                entities.try_alloc_at(b),
+                Err(AllocAtError::StaleGeneration { .. })
+            ));
+
+            let next_b = Entity::from_raw_and_generation(b.index(), NonZero::new(5).unwrap());
+            assert_eq!(entities.try_alloc_at(next_b), Ok(()));
+            assert!(entities.try_alloc_at(next_b).is_err());
+            assert_eq!(entities.try_alloc_at(Entity::from_raw(4)), Ok(()));
+            assert_eq!(entities.len(), 3);
+
+            // Skipped indices are free.
+            let skipped = Entity::from_raw(3);
+            assert_eq!(entities.try_alloc_at(skipped), Ok(()));
+            assert_eq!(entities.len(), 4);
+            let expected = match mode {
+                EntityAllocationMode::Recycle => 2,
+                _ => 5,
+            };
+            assert_eq!(entities.alloc().index(), expected);
+        }
+    }
+
     #[test]
     #[expect(
         clippy::nonminimal_bool,
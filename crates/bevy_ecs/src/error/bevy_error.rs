@@ -72,7 +72,7 @@ impl BevyError {
                         if line.contains("__rust_begin_short_backtrace") {
                             break;
                         }
-                        if line.contains("bevy_ecs::observer::Observers::invoke::{{closure}}") {
+                        if line.contains("bevy_ecs::observer::Observers::invoke") {
                             break;
                         }
                     }
@@ -1,13 +1,18 @@
-use alloc::vec::Vec;
+use alloc::{collections::VecDeque, vec::Vec};
 use bevy_ecs::{
     change_detection::MaybeLocation,
     event::{Event, EventCursor, EventId, EventInstance},
     resource::Resource,
 };
+use bevy_platform_support::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
+use log::{debug, warn};
 #[cfg(feature = "bevy_reflect")]
 use {
     bevy_ecs::reflect::ReflectResource,
@@ -15,7 +20,7 @@ use {
 };
 
 /// An event collection that represents the events that occurred within the last two
-/// [`Events::update`] calls.
+/// [`Events::update`] calls, or as long as its [`EventRetention`] policy says.
 /// Events can be written to using an [`EventWriter`]
 /// and are typically cheaply read using an [`EventReader`].
 ///
@@ -35,7 +40,11 @@ use {
 /// Events will persist across a single frame boundary and so ordering of event producers and
 /// consumers is not critical (although poorly-planned ordering may cause accumulating lag).
 /// If events are not handled by the end of the frame after they are updated, they will be
-/// dropped silently.
+/// dropped. [`Events::dropped_unread`] counts the events that were dropped before every
+/// [`EventReader`] read them.
+///
+/// Events that are read at a lower rate, for example in `FixedUpdate` or in systems with run
+/// conditions, can be kept around for longer with a different [`EventRetention`] policy.
 ///
 /// # Example
 /// ```
@@ -75,6 +84,9 @@ use {
 /// - [`EventReader`]s that read after two updates are guaranteed to drop all events that occurred
 ///   before those updates.
 ///
+/// [`EventRetention::Updates`] and [`EventRetention::UntilRead`] keep some of the events in the
+/// oldest buffer instead of clearing all of it.
+///
 /// The buffers in [`Events`] will grow indefinitely if [`update`](Events::update) is never called.
 ///
 /// An alternative call pattern would be to call [`update`](Events::update)
@@ -98,6 +110,15 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    retention: EventRetention,
+    /// The event counts at the most recent updates, used by [`EventRetention::Updates`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    updates: VecDeque<usize>,
+    /// The progress of every registered [`EventCursor`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    readers: Vec<Arc<AtomicUsize>>,
+    dropped_unread: usize,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -107,11 +128,86 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            retention: Default::default(),
+            updates: Default::default(),
+            readers: Default::default(),
+            dropped_unread: Default::default(),
         }
     }
 }
 
+/// How long an [`Events`] collection keeps the events sent to it.
+///
+/// The policy of an event type can be chosen when it is added to an app, with
+/// `App::add_event_with_retention`, or later with [`Events::set_retention`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventRetention {
+    /// Keep events for the given number of [`Events::update`] calls, so events sent since the
+    /// last update survive `n - 1` more updates. The number is clamped to at least 1.
+    ///
+    /// The default policy keeps events for 2 updates.
+    Updates(usize),
+    /// Keep events until every registered reader has read them.
+    ///
+    /// [`EventReader`](super::EventReader)s and [`EventMutator`](super::EventMutator)s are
+    /// registered automatically when their system is initialized, and unregistered when it is
+    /// dropped. Other [`EventCursor`]s can be registered with [`Events::register_cursor`].
+    /// If no reader is registered, events are dropped on the next update.
+    ///
+    /// A registered reader that never runs keeps all events alive, so this policy should only be
+    /// used for events that every reader eventually reads.
+    UntilRead,
+    /// Keep the given number of most recently sent events, regardless of updates.
+    RingBuffer(usize),
+}
+
+impl Default for EventRetention {
+    fn default() -> Self {
+        Self::Updates(2)
+    }
+}
+
 impl<E: Event> Events<E> {
+    /// Creates an empty [`Events`] collection with the given [`EventRetention`] policy.
+    pub fn with_retention(retention: EventRetention) -> Self {
+        Self {
+            retention,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the [`EventRetention`] policy of this collection.
+    pub fn retention(&self) -> EventRetention {
+        self.retention
+    }
+
+    /// Changes the [`EventRetention`] policy of this collection. It is applied on the next
+    /// [`update`](Events::update), or the next send for [`EventRetention::RingBuffer`].
+    pub fn set_retention(&mut self, retention: EventRetention) {
+        self.retention = retention;
+        self.updates.clear();
+    }
+
+    /// Registers `cursor`, so that the [`EventRetention`] policy of this collection knows which
+    /// events it has read.
+    ///
+    /// [`EventReader`](super::EventReader)s and [`EventMutator`](super::EventMutator)s are
+    /// registered automatically. The cursor is unregistered when it is dropped.
+    pub fn register_cursor(&mut self, cursor: &mut EventCursor<E>) {
+        let progress = Arc::new(AtomicUsize::new(cursor.last_event_count));
+        self.readers.push(progress.clone());
+        cursor.progress = Some(progress);
+    }
+
+    /// Returns the total number of events that were dropped before every registered
+    /// [`EventCursor`] read them.
+    ///
+    /// Dropping unread events is also logged, as a warning if a non-default
+    /// [`EventRetention`] policy was chosen.
+    pub fn dropped_unread(&self) -> usize {
+        self.dropped_unread
+    }
+
     /// Returns the index of the oldest event stored in the event buffer.
     pub fn oldest_event_count(&self) -> usize {
         self.events_a.start_event_count
@@ -138,6 +234,7 @@ impl<E: Event> Events<E> {
 
         self.events_b.push(event_instance);
         self.event_count += 1;
+        self.enforce_capacity();
 
         event_id
     }
@@ -185,15 +282,11 @@ impl<E: Event> Events<E> {
     /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
     /// called once per frame/update.
     ///
+    /// Events that the [`EventRetention`] policy keeps stay in the oldest buffer.
+    ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        self.events_b.clear();
-        self.events_b.start_event_count = self.event_count;
-        debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
-            self.events_b.start_event_count
-        );
+        drop(self.update_drain());
     }
 
     /// Swaps the event buffers and drains the oldest event buffer, returning an iterator
@@ -202,17 +295,119 @@ impl<E: Event> Events<E> {
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        let iter = self.events_b.events.drain(..);
+        let keep_from = self.retained_from();
+        self.record_dropped(keep_from);
+
+        let a_dropped = keep_from
+            .saturating_sub(self.events_a.start_event_count)
+            .min(self.events_a.len());
+        let iter = if a_dropped == self.events_a.len() {
+            // All of the oldest events are dropped, so the newer events become the oldest ones.
+            core::mem::swap(&mut self.events_a, &mut self.events_b);
+            let b_dropped = keep_from
+                .saturating_sub(self.events_a.start_event_count)
+                .min(self.events_a.len());
+            self.events_a.start_event_count += b_dropped;
+            self.events_b
+                .events
+                .drain(..)
+                .chain(self.events_a.events.drain(..b_dropped))
+        } else {
+            self.events_a.append(&mut self.events_b.events);
+            self.events_a.start_event_count += a_dropped;
+            self.events_a
+                .events
+                .drain(..a_dropped)
+                .chain(self.events_b.events.drain(..))
+        };
         self.events_b.start_event_count = self.event_count;
-        debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
-            self.events_b.start_event_count
-        );
 
         iter.map(|e| e.event)
     }
 
+    /// Returns the id of the oldest event that the [`EventRetention`] policy keeps in an update.
+    fn retained_from(&mut self) -> usize {
+        let oldest = self.oldest_event_count();
+        let keep_from = match self.retention {
+            EventRetention::Updates(updates) => {
+                let updates = updates.max(1);
+                self.updates.push_back(self.event_count);
+                while self.updates.len() > updates {
+                    self.updates.pop_front();
+                }
+                if self.updates.len() == updates {
+                    self.updates[0]
+                } else {
+                    oldest
+                }
+            }
+            EventRetention::UntilRead => self.read_by_all(),
+            EventRetention::RingBuffer(capacity) => self.event_count.saturating_sub(capacity),
+        };
+        keep_from.max(oldest)
+    }
+
+    /// Returns the number of events that every registered cursor has read, and unregisters the
+    /// cursors that were dropped.
+    fn read_by_all(&mut self) -> usize {
+        self.readers
+            .retain(|progress| Arc::strong_count(progress) > 1);
+        self.readers
+            .iter()
+            .map(|progress| progress.load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(self.event_count)
+    }
+
+    /// Counts the events before `keep_from` that some registered cursor has not read yet.
+    fn record_dropped(&mut self, keep_from: usize) {
+        let oldest = self.oldest_event_count();
+        if keep_from <= oldest {
+            return;
+        }
+        let read = self.read_by_all();
+        if read >= keep_from {
+            return;
+        }
+        let dropped = keep_from - read.max(oldest);
+        self.dropped_unread += dropped;
+        if self.retention == EventRetention::default() {
+            debug!(
+                "{dropped} events of type {} were dropped before they were read by every reader.",
+                core::any::type_name::<E>()
+            );
+        } else {
+            warn!(
+                "{dropped} events of type {} were dropped before they were read by every reader. Consider changing the retention policy {:?}.",
+                core::any::type_name::<E>(),
+                self.retention
+            );
+        }
+    }
+
+    /// Drops the oldest events if there are more than an [`EventRetention::RingBuffer`] holds.
+    #[inline]
+    fn enforce_capacity(&mut self) {
+        let EventRetention::RingBuffer(capacity) = self.retention else {
+            return;
+        };
+        if self.len() <= capacity {
+            return;
+        }
+        let keep_from = self.event_count - capacity;
+        self.record_dropped(keep_from);
+        for sequence in [&mut self.events_a, &mut self.events_b] {
+            let dropped = keep_from
+                .saturating_sub(sequence.start_event_count)
+                .min(sequence.len());
+            sequence.drain(..dropped);
+            sequence.start_event_count += dropped;
+        }
+        if self.events_a.is_empty() {
+            self.events_a.start_event_count = self.events_b.start_event_count;
+        }
+    }
+
     #[inline]
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
@@ -305,6 +500,8 @@ impl<E: Event> Extend<E> for Events<E> {
         });
 
         self.events_b.extend(events);
+        self.event_count = event_count;
+        self.enforce_capacity();
 
         if old_count != event_count {
             #[cfg(feature = "detailed_trace")]
@@ -314,8 +511,6 @@ impl<E: Event> Extend<E> for Events<E> {
                 event_count
             );
         }
-
-        self.event_count = event_count;
     }
 }
 
@@ -1,9 +1,20 @@
-use bevy_ecs::event::{
-    Event, EventIterator, EventIteratorWithId, EventMutIterator, EventMutIteratorWithId, Events,
-};
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::{EventMutParIter, EventParIter};
-use core::marker::PhantomData;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    event::{
+        Event, EventIterator, EventIteratorWithId, EventMutIterator, EventMutIteratorWithId, Events,
+    },
+    world::{FromWorld, World},
+};
+use bevy_platform_support::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
 /// Stores the state for an [`EventReader`] or [`EventMutator`].
 ///
@@ -52,6 +63,8 @@ use core::marker::PhantomData;
 #[derive(Debug)]
 pub struct EventCursor<E: Event> {
     pub(super) last_event_count: usize,
+    // Shared with the `Events` this cursor is registered with, see `Events::register_cursor`.
+    pub(super) progress: Option<Arc<AtomicUsize>>,
     pub(super) _marker: PhantomData<E>,
 }
 
@@ -59,21 +72,39 @@ impl<E: Event> Default for EventCursor<E> {
     fn default() -> Self {
         EventCursor {
             last_event_count: 0,
+            progress: None,
             _marker: Default::default(),
         }
     }
 }
 
 impl<E: Event> Clone for EventCursor<E> {
+    /// Clones the cursor. The clone is not registered with any [`Events`], even if this cursor is.
     fn clone(&self) -> Self {
         EventCursor {
             last_event_count: self.last_event_count,
+            progress: None,
             _marker: PhantomData,
         }
     }
 }
 
 impl<E: Event> EventCursor<E> {
+    /// Returns `true` if this cursor is registered with an [`Events`] collection, so its
+    /// [`EventRetention`](super::EventRetention) policy knows which events it has read.
+    pub fn is_registered(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Marks all events before `last_event_count` as read.
+    #[inline]
+    pub(super) fn set_last_event_count(&mut self, last_event_count: usize) {
+        self.last_event_count = last_event_count;
+        if let Some(progress) = &self.progress {
+            progress.store(last_event_count, Ordering::Relaxed);
+        }
+    }
+
     /// See [`EventReader::read`](super::EventReader::read)
     pub fn read<'a>(&'a mut self, events: &'a Events<E>) -> EventIterator<'a, E> {
         self.read_with_id(events).without_id()
@@ -135,6 +166,38 @@ impl<E: Event> EventCursor<E> {
 
     /// See [`EventReader::clear()`](super::EventReader::clear)
     pub fn clear(&mut self, events: &Events<E>) {
-        self.last_event_count = events.event_count;
+        self.set_last_event_count(events.event_count);
+    }
+}
+
+/// An [`EventCursor`] that registers itself with the [`Events`] resource when it is created
+/// from the [`World`], used by [`EventReader`](super::EventReader) and
+/// [`EventMutator`](super::EventMutator).
+#[derive(Debug)]
+pub(super) struct RegisteredEventCursor<E: Event>(EventCursor<E>);
+
+impl<E: Event> FromWorld for RegisteredEventCursor<E> {
+    fn from_world(world: &mut World) -> Self {
+        let mut cursor = EventCursor::default();
+        if let Some(mut events) = world.get_resource_mut::<Events<E>>() {
+            events
+                .bypass_change_detection()
+                .register_cursor(&mut cursor);
+        }
+        Self(cursor)
+    }
+}
+
+impl<E: Event> Deref for RegisteredEventCursor<E> {
+    type Target = EventCursor<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E: Event> DerefMut for RegisteredEventCursor<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
     }
 }
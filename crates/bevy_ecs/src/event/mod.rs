@@ -13,7 +13,7 @@ mod writer;
 pub(crate) use base::EventInstance;
 pub use base::{Event, EventId};
 pub use bevy_ecs_macros::Event;
-pub use collections::{Events, SendBatchIds};
+pub use collections::{EventRetention, Events, SendBatchIds};
 pub use event_cursor::EventCursor;
 #[cfg(feature = "multi_threaded")]
 pub use iterators::EventParIter;
@@ -618,4 +618,115 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    #[test]
+    fn test_retention_updates() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::Updates(3));
+        let mut reader = events.get_cursor();
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        assert_eq!(events.len(), 2);
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(reader.missed_events(&events), 1);
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 1 }]);
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_retention_until_read() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::UntilRead);
+        let mut fast = events.get_cursor();
+        let mut slow = events.get_cursor();
+        events.register_cursor(&mut fast);
+        events.register_cursor(&mut slow);
+        assert!(slow.is_registered());
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        assert_eq!(fast.read(&events).count(), 2);
+        for _ in 0..5 {
+            events.update();
+        }
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(slow.read(&events).next(), Some(&TestEvent { i: 0 }));
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(get_events(&events, &mut slow), vec![TestEvent { i: 1 }]);
+
+        // Dropping a cursor unregisters it.
+        events.send(TestEvent { i: 2 });
+        drop(slow);
+        assert_eq!(fast.read(&events).count(), 1);
+        events.update();
+        assert!(events.is_empty());
+        assert_eq!(events.dropped_unread(), 0);
+    }
+
+    #[test]
+    fn test_retention_ring_buffer() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::RingBuffer(3));
+        let mut reader = events.get_cursor();
+        events.register_cursor(&mut reader);
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send_batch([TestEvent { i: 1 }, TestEvent { i: 2 }]);
+        for _ in 0..5 {
+            events.update();
+        }
+        assert_eq!(events.len(), 3);
+
+        events.send(TestEvent { i: 3 });
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.oldest_event_count(), 1);
+        assert_eq!(events.dropped_unread(), 1);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }, TestEvent { i: 2 }, TestEvent { i: 3 }]
+        );
+    }
+
+    #[test]
+    fn test_dropped_unread() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_cursor();
+        events.register_cursor(&mut reader);
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        assert_eq!(reader.read(&events).count(), 2);
+        events.send(TestEvent { i: 2 });
+        events.update();
+        events.update();
+        assert_eq!(events.dropped_unread(), 1);
+    }
+
+    #[test]
+    fn test_event_reader_retention() {
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.insert_resource(Events::<TestEvent>::with_retention(
+            EventRetention::UntilRead,
+        ));
+
+        let mut reader =
+            IntoSystem::into_system(|mut events: EventReader<TestEvent>| events.read().count());
+        reader.initialize(&mut world);
+
+        world.send_event(TestEvent { i: 0 });
+        for _ in 0..3 {
+            world.resource_mut::<Events<TestEvent>>().update();
+        }
+        assert_eq!(reader.run((), &mut world), 1);
+        world.resource_mut::<Events<TestEvent>>().update();
+        assert!(world.resource::<Events<TestEvent>>().is_empty());
+    }
 }
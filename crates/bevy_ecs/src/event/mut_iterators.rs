@@ -67,7 +67,7 @@ impl<'a, E: Event> EventMutIteratorWithId<'a, E> {
 
         let unread_count = a.len() + b.len();
 
-        mutator.last_event_count = events.event_count - unread_count;
+        mutator.set_last_event_count(events.event_count - unread_count);
         // Iterate the oldest first, then the newer events
         let chain = a.iter_mut().chain(b.iter_mut());
 
@@ -95,7 +95,8 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventMutator::iter() -> {}", item.1);
-                self.mutator.last_event_count += 1;
+                self.mutator
+                    .set_last_event_count(self.mutator.last_event_count + 1);
                 self.unread -= 1;
                 Some(item)
             }
@@ -108,7 +109,8 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
     }
 
     fn count(self) -> usize {
-        self.mutator.last_event_count += self.unread;
+        self.mutator
+            .set_last_event_count(self.mutator.last_event_count + self.unread);
         self.unread
     }
 
@@ -117,17 +119,20 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
         Self: Sized,
     {
         let EventInstance { event_id, event } = self.chain.last()?;
-        self.mutator.last_event_count += self.unread;
+        self.mutator
+            .set_last_event_count(self.mutator.last_event_count + self.unread);
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.mutator.last_event_count += n + 1;
+            self.mutator
+                .set_last_event_count(self.mutator.last_event_count + n + 1);
             self.unread -= n + 1;
             Some((event, *event_id))
         } else {
-            self.mutator.last_event_count += self.unread;
+            self.mutator
+                .set_last_event_count(self.mutator.last_event_count + self.unread);
             self.unread = 0;
             None
         }
@@ -165,7 +170,7 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
         let b = events.events_b.get_mut(b_index..).unwrap_or_default();
 
         let unread_count = a.len() + b.len();
-        mutator.last_event_count = events.event_count - unread_count;
+        mutator.set_last_event_count(events.event_count - unread_count);
 
         Self {
             mutator,
@@ -246,7 +251,8 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.mutator.last_event_count += self.unread;
+            self.mutator
+                .set_last_event_count(self.mutator.last_event_count + self.unread);
             self.unread = 0;
         }
     }
@@ -1,7 +1,8 @@
+use super::event_cursor::RegisteredEventCursor;
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::EventMutParIter;
 use bevy_ecs::{
-    event::{Event, EventCursor, EventMutIterator, EventMutIteratorWithId, Events},
+    event::{Event, EventMutIterator, EventMutIteratorWithId, Events},
     system::{Local, ResMut, SystemParam},
 };
 
@@ -43,7 +44,7 @@ use bevy_ecs::{
 /// [`EventWriter`]: super::EventWriter
 #[derive(SystemParam, Debug)]
 pub struct EventMutator<'w, 's, E: Event> {
-    pub(super) reader: Local<'s, EventCursor<E>>,
+    pub(super) reader: Local<'s, RegisteredEventCursor<E>>,
     #[system_param(validation_message = "Event not initialized")]
     events: ResMut<'w, Events<E>>,
 }
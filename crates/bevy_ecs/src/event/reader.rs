@@ -1,7 +1,8 @@
+use super::event_cursor::RegisteredEventCursor;
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::EventParIter;
 use bevy_ecs::{
-    event::{Event, EventCursor, EventIterator, EventIteratorWithId, Events},
+    event::{Event, EventIterator, EventIteratorWithId, Events},
     system::{Local, Res, SystemParam},
 };
 
@@ -15,7 +16,7 @@ use bevy_ecs::{
 /// [`EventWriter<T>`]: super::EventWriter
 #[derive(SystemParam, Debug)]
 pub struct EventReader<'w, 's, E: Event> {
-    pub(super) reader: Local<'s, EventCursor<E>>,
+    pub(super) reader: Local<'s, RegisteredEventCursor<E>>,
     #[system_param(validation_message = "Event not initialized")]
     events: Res<'w, Events<E>>,
 }
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use bevy_ecs::{
     change_detection::{DetectChangesMut, MutUntyped},
     component::{ComponentId, Tick},
-    event::{Event, Events},
+    event::{Event, EventRetention, Events},
     resource::Resource,
     world::World,
 };
@@ -10,11 +10,12 @@ use bevy_ecs::{
 #[doc(hidden)]
 struct RegisteredEvent {
     component_id: ComponentId,
-    // Required to flush the secondary buffer and drop events even if left unchanged.
-    previously_updated: bool,
+    // Required to drop the remaining events even if left unchanged.
+    has_events: bool,
     // SAFETY: The component ID and the function must be used to fetch the Events<T> resource
     // of the same type initialized in `register_event`, or improper type casts will occur.
-    update: unsafe fn(MutUntyped),
+    // Returns whether any events are left after the update.
+    update: unsafe fn(MutUntyped) -> bool,
 }
 
 /// A registry of all of the [`Events`] in the [`World`], used by [`event_update_system`](crate::event::update::event_update_system)
@@ -52,30 +53,41 @@ impl EventRegistry {
         let mut registry = world.get_resource_or_init::<Self>();
         registry.event_updates.push(RegisteredEvent {
             component_id,
-            previously_updated: false,
+            has_events: false,
             update: |ptr| {
                 // SAFETY: The resource was initialized with the type Events<T>.
-                unsafe { ptr.with_type::<Events<T>>() }
-                    .bypass_change_detection()
-                    .update();
+                let mut events = unsafe { ptr.with_type::<Events<T>>() };
+                let events = events.bypass_change_detection();
+                events.update();
+                !events.is_empty()
             },
         });
     }
 
+    /// Registers an event type to be updated in a given [`World`], like [`register_event`](Self::register_event),
+    /// and sets the [`EventRetention`] policy of its [`Events`].
+    pub fn register_event_with_retention<T: Event>(world: &mut World, retention: EventRetention) {
+        if !world.contains_resource::<Events<T>>() {
+            Self::register_event::<T>(world);
+        }
+        world
+            .resource_mut::<Events<T>>()
+            .bypass_change_detection()
+            .set_retention(retention);
+    }
+
     /// Updates all of the registered events in the World.
     pub fn run_updates(&mut self, world: &mut World, last_change_tick: Tick) {
         for registered_event in &mut self.event_updates {
             // Bypass the type ID -> Component ID lookup with the cached component ID.
             if let Some(events) = world.get_resource_mut_by_id(registered_event.component_id) {
                 let has_changed = events.has_changed_since(last_change_tick);
-                if registered_event.previously_updated || has_changed {
+                if registered_event.has_events || has_changed {
                     // SAFETY: The update function pointer is called with the resource
                     // fetched from the same component ID.
-                    unsafe { (registered_event.update)(events) };
-                    // Always set to true if the events have changed, otherwise disable running on the second invocation
-                    // to wait for more changes.
-                    registered_event.previously_updated =
-                        has_changed || !registered_event.previously_updated;
+                    // Keep updating while events are left, so they are dropped when their retention
+                    // policy allows it, otherwise wait for more changes.
+                    registered_event.has_events = unsafe { (registered_event.update)(events) };
                 }
             }
         }
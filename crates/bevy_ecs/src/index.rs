@@ -0,0 +1,352 @@
+//! Indexes that find the entities with a given value of an immutable component.
+//!
+//! Finding the entities whose component has a specific value normally requires iterating over
+//! every entity with that component. A [`ComponentIndex`] keeps track of which entities have
+//! which value, so they can be looked up directly. Because the indexed components are
+//! [immutable](crate::component::Immutable), the index only needs to be updated when a component
+//! is inserted, replaced or removed, which is done by observers.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::index::ComponentIndex;
+//! #[derive(Component, PartialEq, Eq, Hash, Clone, Debug)]
+//! #[component(immutable)]
+//! struct GridCell(i32, i32);
+//!
+//! let mut world = World::new();
+//! world.register_component_index::<GridCell>();
+//!
+//! let entity = world.spawn(GridCell(3, 7)).id();
+//! world.spawn(GridCell(0, 0));
+//!
+//! let index = world.resource::<ComponentIndex<GridCell>>();
+//! assert_eq!(index.get(&GridCell(3, 7)), &[entity]);
+//! ```
+//!
+//! In systems, [`IndexedQuery`] combines the index with a [`Query`] for the matching entities.
+
+use core::{hash::Hash, iter::Copied, slice::Iter};
+
+use bevy_platform_support::collections::HashMap;
+use smallvec::SmallVec;
+
+use crate::{
+    component::{Component, Immutable},
+    entity::{Entity, UniqueEntityIter},
+    observer::Trigger,
+    query::{QueryData, QueryFilter, QueryManyUniqueIter, ROQueryItem},
+    resource::Resource,
+    system::{Query, Res, SystemParam},
+    world::{DeferredWorld, OnInsert, OnReplace, World},
+};
+
+/// A [`Component`] that can be indexed by a [`ComponentIndex`]: an immutable component that can be
+/// compared and hashed.
+///
+/// This trait is implemented automatically for all such components.
+pub trait IndexableComponent: Component<Mutability = Immutable> + Eq + Hash + Clone {}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> IndexableComponent for C {}
+
+/// A [`Resource`] that maps every value of the component `C` to the entities that have it.
+///
+/// The index is created with [`World::register_component_index`], and updated automatically
+/// whenever `C` is inserted, replaced or removed. Disabled entities are indexed as well.
+///
+/// See the [module-level documentation](crate::index) for an example.
+#[derive(Resource, Debug)]
+pub struct ComponentIndex<C: IndexableComponent> {
+    map: HashMap<C, SmallVec<[Entity; 1]>>,
+}
+
+impl<C: IndexableComponent> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+        }
+    }
+}
+
+impl<C: IndexableComponent> ComponentIndex<C> {
+    /// Returns the entities whose component is equal to `value`, in no particular order.
+    pub fn get(&self, value: &C) -> &[Entity] {
+        self.map.get(value).map_or(&[], |entities| entities)
+    }
+
+    /// Returns `true` if any entity has a component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.map.contains_key(value)
+    }
+
+    /// Returns the number of distinct values in the index.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if no entity has the component.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over every indexed value and the entities that have it.
+    /// Iteration order is undefined.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, &[Entity])> {
+        self.map
+            .iter()
+            .map(|(value, entities)| (value, entities.as_slice()))
+    }
+
+    fn insert(&mut self, value: C, entity: Entity) {
+        let entities = self.map.entry(value).or_default();
+        if !entities.contains(&entity) {
+            entities.push(entity);
+        }
+    }
+
+    /// Returns the entities whose component is equal to `value` as an [`EntitySet`](crate::entity::EntitySet).
+    fn get_unique(&self, value: &C) -> UniqueEntityIter<Copied<Iter<'_, Entity>>> {
+        // SAFETY: `insert` never adds an entity to the same value twice.
+        unsafe { UniqueEntityIter::from_iterator_unchecked(self.get(value).iter().copied()) }
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        let Some(entities) = self.map.get_mut(value) else {
+            return;
+        };
+        if let Some(index) = entities.iter().position(|&e| e == entity) {
+            entities.swap_remove(index);
+        }
+        if entities.is_empty() {
+            self.map.remove(value);
+        }
+    }
+}
+
+/// A [`SystemParam`] that looks up the entities of a [`Query`] by the value of their component
+/// `C`, using its [`ComponentIndex`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::index::IndexedQuery;
+/// # #[derive(Component, PartialEq, Eq, Hash, Clone)]
+/// # #[component(immutable)]
+/// # struct PlayerId(u32);
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// fn damage_player(mut players: IndexedQuery<PlayerId, &mut Health>) {
+///     for mut health in players.get_mut(&PlayerId(42)) {
+///         health.0 -= 1;
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(damage_player);
+/// ```
+///
+/// The index must have been created with [`World::register_component_index`], otherwise the
+/// system will not run.
+#[derive(SystemParam)]
+pub struct IndexedQuery<
+    'w,
+    's,
+    C: IndexableComponent,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static = (),
+> {
+    #[system_param(validation_message = "Component index not registered")]
+    index: Res<'w, ComponentIndex<C>>,
+    query: Query<'w, 's, D, F>,
+}
+
+/// An iterator over the query items of the entities with a given component value.
+///
+/// See [`IndexedQuery::get`] and [`IndexedQuery::get_mut`].
+pub type IndexedQueryIter<'w, 's, D, F> =
+    QueryManyUniqueIter<'w, 's, D, F, UniqueEntityIter<Copied<Iter<'w, Entity>>>>;
+
+impl<'w, 's, C: IndexableComponent, D: QueryData, F: QueryFilter> IndexedQuery<'w, 's, C, D, F> {
+    /// Returns the [`ComponentIndex`] of `C`.
+    pub fn index(&self) -> &ComponentIndex<C> {
+        &self.index
+    }
+
+    /// Returns the underlying [`Query`].
+    pub fn query(&self) -> &Query<'w, 's, D, F> {
+        &self.query
+    }
+
+    /// Returns the underlying [`Query`] mutably.
+    pub fn query_mut(&mut self) -> &mut Query<'w, 's, D, F> {
+        &mut self.query
+    }
+
+    /// Returns the entities whose component is equal to `value`, in no particular order.
+    pub fn entities(&self, value: &C) -> &[Entity] {
+        self.index.get(value)
+    }
+
+    /// Returns an iterator over the read-only query items of the entities whose component is
+    /// equal to `value`. Entities that don't match the query are skipped.
+    pub fn get(&self, value: &C) -> IndexedQueryIter<'_, 's, D::ReadOnly, F> {
+        self.query.iter_many_unique(self.index.get_unique(value))
+    }
+
+    /// Returns an iterator over the query items of the entities whose component is equal to
+    /// `value`. Entities that don't match the query are skipped.
+    pub fn get_mut(&mut self, value: &C) -> IndexedQueryIter<'_, 's, D, F> {
+        self.query
+            .iter_many_unique_mut(self.index.get_unique(value))
+    }
+
+    /// Returns the read-only query item of the only entity whose component is equal to `value`,
+    /// or [`None`] if there is not exactly one such entity matching the query.
+    pub fn single(&self, value: &C) -> Option<ROQueryItem<'_, D>> {
+        let mut items = self.get(value);
+        let item = items.next()?;
+        items.next().is_none().then_some(item)
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for the component `C`, indexing all entities that already
+    /// have it, and keeps it up to date with observers.
+    ///
+    /// Does nothing if the index already exists.
+    ///
+    /// See the [`index` module-level documentation](crate::index) for an example.
+    pub fn register_component_index<C: IndexableComponent>(&mut self) {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return;
+        }
+
+        let mut index = ComponentIndex::<C>::default();
+        if let Some(component_id) = self.component_id::<C>() {
+            for archetype in self.archetypes().iter() {
+                if !archetype.contains(component_id) {
+                    continue;
+                }
+                for archetype_entity in archetype.entities() {
+                    let entity = archetype_entity.id();
+                    if let Some(value) = self.get::<C>(entity) {
+                        index.insert(value.clone(), entity);
+                    }
+                }
+            }
+        }
+        self.insert_resource(index);
+
+        self.add_observer(index_on_insert::<C>);
+        self.add_observer(index_on_replace::<C>);
+        self.flush();
+    }
+}
+
+fn index_on_insert<C: IndexableComponent>(trigger: Trigger<OnInsert, C>, mut world: DeferredWorld) {
+    let entity = trigger.target();
+    let Some(value) = world.get::<C>(entity).cloned() else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.insert(value, entity);
+    }
+}
+
+fn index_on_replace<C: IndexableComponent>(
+    trigger: Trigger<OnReplace, C>,
+    mut world: DeferredWorld,
+) {
+    let entity = trigger.target();
+    let Some(value) = world.get::<C>(entity).cloned() else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.remove(&value, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentIndex, IndexedQuery};
+    use crate::{entity_disabling::Disabled, prelude::*, system::RunSystemOnce};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, PartialEq, Eq, Hash, Clone, Debug)]
+    #[component(immutable)]
+    struct GridCell(i32, i32);
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    fn cell(world: &World, value: GridCell) -> Vec<Entity> {
+        let mut entities = world
+            .resource::<ComponentIndex<GridCell>>()
+            .get(&value)
+            .to_vec();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_spawn_despawn() {
+        let mut world = World::new();
+        let existing = world.spawn(GridCell(0, 0)).id();
+        world.register_component_index::<GridCell>();
+
+        let a = world.spawn(GridCell(3, 7)).id();
+        let b = world.spawn(GridCell(3, 7)).id();
+        assert_eq!(cell(&world, GridCell(0, 0)), vec![existing]);
+        assert_eq!(cell(&world, GridCell(3, 7)), vec![a, b]);
+        assert_eq!(world.resource::<ComponentIndex<GridCell>>().len(), 2);
+
+        world.despawn(a);
+        assert_eq!(cell(&world, GridCell(3, 7)), vec![b]);
+        world.entity_mut(b).remove::<GridCell>();
+        assert!(cell(&world, GridCell(3, 7)).is_empty());
+        assert!(!world
+            .resource::<ComponentIndex<GridCell>>()
+            .contains(&GridCell(3, 7)));
+    }
+
+    #[test]
+    fn index_replace() {
+        let mut world = World::new();
+        world.register_component_index::<GridCell>();
+
+        let entity = world.spawn(GridCell(1, 1)).id();
+        world.entity_mut(entity).insert(GridCell(2, 2));
+        assert!(cell(&world, GridCell(1, 1)).is_empty());
+        assert_eq!(cell(&world, GridCell(2, 2)), vec![entity]);
+
+        // Inserting the same value again keeps a single entry.
+        world.entity_mut(entity).insert(GridCell(2, 2));
+        assert_eq!(cell(&world, GridCell(2, 2)), vec![entity]);
+
+        // Registering twice doesn't index entities twice.
+        world.register_component_index::<GridCell>();
+        world.entity_mut(entity).insert(GridCell(2, 2));
+        assert_eq!(cell(&world, GridCell(2, 2)), vec![entity]);
+    }
+
+    #[test]
+    fn indexed_query() {
+        let mut world = World::new();
+        world.register_component_index::<GridCell>();
+
+        let target = world.spawn((GridCell(3, 7), Health(10))).id();
+        world.spawn((GridCell(3, 7), Health(10), Disabled));
+        world.spawn(GridCell(3, 7));
+        let other = world.spawn((GridCell(0, 0), Health(10))).id();
+
+        world
+            .run_system_once(|mut query: IndexedQuery<GridCell, &mut Health>| {
+                assert_eq!(query.entities(&GridCell(3, 7)).len(), 3);
+                for mut health in query.get_mut(&GridCell(3, 7)) {
+                    health.0 -= 1;
+                }
+                assert_eq!(query.single(&GridCell(3, 7)).unwrap().0, 9);
+                assert!(query.single(&GridCell(1, 1)).is_none());
+            })
+            .unwrap();
+
+        assert_eq!(world.get::<Health>(target).unwrap().0, 9);
+        assert_eq!(world.get::<Health>(other).unwrap().0, 10);
+    }
+}
@@ -40,10 +40,12 @@ pub mod error;
 pub mod event;
 pub mod hierarchy;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod name;
 pub mod observer;
+pub mod prefab;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
@@ -79,6 +81,7 @@ pub mod prelude {
         hierarchy::{ChildOf, ChildSpawner, ChildSpawnerCommands, Children},
         name::{Name, NameOrEntity},
         observer::{Observer, Trigger},
+        prefab::{InstanceOf, Instances, Prefab},
         query::{Added, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
         related,
         relationship::RelationshipTarget,
@@ -97,13 +100,13 @@ pub mod prelude {
         },
         world::{
             EntityMut, EntityRef, EntityWorldMut, FilteredResources, FilteredResourcesMut,
-            FromWorld, OnAdd, OnInsert, OnRemove, OnReplace, World,
+            FromWorld, OnAdd, OnInsert, OnRemove, OnReplace, OnResourceChange, World,
         },
     };
 
     #[doc(hidden)]
     #[cfg(feature = "std")]
-    pub use crate::system::ParallelCommands;
+    pub use crate::{system::ParallelCommands, world::AsyncWorld};
 
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
@@ -4,7 +4,7 @@ use crate::{
     },
     entity::{ComponentCloneCtx, Entity, EntityClonerBuilder, EntityMapper, SourceComponent},
     observer::ObserverState,
-    world::World,
+    world::{EntityWorldMut, World},
 };
 use alloc::vec::Vec;
 
@@ -63,51 +63,66 @@ impl EntityClonerBuilder<'_> {
     }
 }
 
-fn component_clone_observed_by(_source: &SourceComponent, ctx: &mut ComponentCloneCtx) {
+fn component_clone_observed_by(source: &SourceComponent, ctx: &mut ComponentCloneCtx) {
     let target = ctx.target();
-    let source = ctx.source();
-
-    ctx.queue_deferred(move |world: &mut World, _mapper: &mut dyn EntityMapper| {
-        let observed_by = world
-            .get::<ObservedBy>(source)
-            .map(|observed_by| observed_by.0.clone())
-            .expect("Source entity must have ObservedBy");
-
-        world
-            .entity_mut(target)
-            .insert(ObservedBy(observed_by.clone()));
-
-        for observer in &observed_by {
-            let mut observer_state = world
-                .get_mut::<ObserverState>(*observer)
-                .expect("Source observer entity must have ObserverState");
+    let observed_by = source
+        .read::<ObservedBy>()
+        .map(|observed_by| observed_by.0.clone())
+        .expect("Source component must be ObservedBy");
+
+    ctx.queue_deferred(move |world: &mut World, mapper: &mut dyn EntityMapper| {
+        let mut target_observed_by = Vec::with_capacity(observed_by.len());
+        for observer in observed_by {
+            // When cloning into another world, only observers that exist in that world can watch
+            // the target.
+            let observer = mapper.get_mapped(observer);
+            let Some(mut observer_state) = world
+                .get_entity_mut(observer)
+                .ok()
+                .and_then(EntityWorldMut::into_mut::<ObserverState>)
+            else {
+                continue;
+            };
             observer_state.descriptor.entities.push(target);
+            let runner = observer_state.runner;
             let event_types = observer_state.descriptor.events.clone();
             let components = observer_state.descriptor.components.clone();
             for event_type in event_types {
                 let observers = world.observers.get_observers(event_type);
                 if components.is_empty() {
-                    if let Some(map) = observers.entity_observers.get(&source).cloned() {
-                        observers.entity_observers.insert(target, map);
-                    }
+                    observers
+                        .entity_observers
+                        .entry(target)
+                        .or_default()
+                        .insert(observer, runner);
                 } else {
                     for component in &components {
                         let Some(observers) = observers.component_observers.get_mut(component)
                         else {
                             continue;
                         };
-                        if let Some(map) = observers.entity_map.get(&source).cloned() {
-                            observers.entity_map.insert(target, map);
-                        }
+                        observers
+                            .entity_map
+                            .entry(target)
+                            .or_default()
+                            .insert(observer, runner);
                     }
                 }
             }
+            target_observed_by.push(observer);
+        }
+
+        if !target_observed_by.is_empty() {
+            world
+                .entity_mut(target)
+                .insert(ObservedBy(target_observed_by));
         }
     });
 }
 
 #[cfg(test)]
 mod tests {
+    use super::ObservedBy;
     use crate::{
         entity::EntityCloner, event::Event, observer::Trigger, resource::Resource, system::ResMut,
         world::World,
@@ -141,4 +156,36 @@ mod tests {
 
         assert_eq!(world.resource::<Num>().0, 3);
     }
+
+    #[test]
+    fn clone_entity_with_observer_into_world() {
+        let mut world = World::default();
+        let e = world
+            .spawn_empty()
+            .observe(|_: Trigger<E>, mut res: ResMut<Num>| res.0 += 1)
+            .id();
+        world.flush();
+
+        // The observer of `other` has the same id as the observer of `e`.
+        let mut target_world = World::default();
+        target_world.init_resource::<Num>();
+        let other = target_world
+            .spawn_empty()
+            .observe(|_: Trigger<E>, mut res: ResMut<Num>| res.0 += 10)
+            .id();
+        target_world.flush();
+
+        let mut builder = EntityCloner::build(&mut world);
+        builder.add_observers(true);
+        let e_clone = builder
+            .finish()
+            .spawn_clone_into_world(&world, e, &mut target_world);
+
+        // Observers can't watch entities of another world.
+        assert!(target_world.get::<ObservedBy>(e_clone).is_none());
+        target_world.trigger_targets(E, e_clone);
+        assert_eq!(target_world.resource::<Num>().0, 0);
+        target_world.trigger_targets(E, other);
+        assert_eq!(target_world.resource::<Num>().0, 10);
+    }
 }
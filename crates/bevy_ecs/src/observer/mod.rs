@@ -1,6 +1,7 @@
 //! Types for creating and storing [`Observer`]s
 
 mod entity_observer;
+mod ordering;
 mod runner;
 
 pub use entity_observer::ObservedBy;
@@ -13,6 +14,8 @@ use crate::{
     component::ComponentId,
     entity::EntityHashMap,
     prelude::*,
+    schedule::InternedSystemSet,
+    storage::ResourceData,
     system::IntoObserverSystem,
     world::{DeferredWorld, *},
 };
@@ -24,6 +27,7 @@ use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
+use ordering::ObserverOrdering;
 use smallvec::SmallVec;
 
 /// Type containing triggered [`Event`] information for a given run of an [`Observer`]. This contains the
@@ -70,6 +74,10 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     /// Returns the [`Entity`] that was targeted by the `event` that triggered this observer. It may
     /// be [`Entity::PLACEHOLDER`].
     ///
+    /// Events of resources, which observers watch with [`Observer::with_resource`], have no target
+    /// entity, so this always returns [`Entity::PLACEHOLDER`] for them. Use the observed resource
+    /// as a system parameter to access it instead.
+    ///
     /// Observable events can target specific entities. When those events fire, they will trigger
     /// any observers on the targeted entities. In this case, the `target()` and `observer()` are
     /// the same, because the observer that was triggered is attached to the entity that was
@@ -145,6 +153,39 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     pub fn caller(&self) -> MaybeLocation {
         self.trigger.caller
     }
+
+    /// Returns the [`PropagationPhase`] the event is in.
+    ///
+    /// This is [`PropagationPhase::Capture`] while the event travels down its propagation path
+    /// towards the original target, [`PropagationPhase::Target`] at the original target and
+    /// [`PropagationPhase::Bubble`] while it [propagates](Trigger::propagate) back up.
+    pub fn phase(&self) -> PropagationPhase {
+        self.trigger.phase
+    }
+}
+
+/// The stage of propagation an [`Event`] is in when it runs an [`Observer`].
+///
+/// Events that target entities run in up to three phases, like DOM events:
+/// + **Capture**: the event travels from the root of its [`Traversal`] path down to the parent of
+///   the target, running the observers created with [`Observer::capturing`]. Calling
+///   [`Trigger::propagate`] with `false` in this phase stops the event before it reaches the target.
+/// + **Target**: the event runs all observers of the target.
+/// + **Bubble**: the event [propagates](Trigger::propagate) up the [`Traversal`] path, running
+///   the observers that are not capturing.
+///
+/// The capture phase only happens if at least one capturing observer watches the event.
+///
+/// [`Traversal`]: crate::traversal::Traversal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropagationPhase {
+    /// The event travels down towards its target.
+    Capture,
+    /// The event is at its target, or it doesn't target any entity.
+    #[default]
+    Target,
+    /// The event travels up from its target.
+    Bubble,
 }
 
 impl<'w, E: Debug, B: Bundle> Debug for Trigger<'w, E, B> {
@@ -291,6 +332,18 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The sets the observer belongs to, used to order other observers against it.
+    sets: Vec<InternedSystemSet>,
+
+    /// The sets of observers that should run after this observer.
+    before: Vec<InternedSystemSet>,
+
+    /// The sets of observers that should run before this observer.
+    after: Vec<InternedSystemSet>,
+
+    /// Whether the observer runs during the capture phase instead of the bubble phase.
+    capture: bool,
 }
 
 impl ObserverDescriptor {
@@ -315,11 +368,39 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Add the given `sets` to the descriptor.
+    pub fn with_sets(mut self, sets: Vec<InternedSystemSet>) -> Self {
+        self.sets = sets;
+        self
+    }
+
+    /// Run the observer before the observers in the given `sets`.
+    pub fn with_before(mut self, sets: Vec<InternedSystemSet>) -> Self {
+        self.before = sets;
+        self
+    }
+
+    /// Run the observer after the observers in the given `sets`.
+    pub fn with_after(mut self, sets: Vec<InternedSystemSet>) -> Self {
+        self.after = sets;
+        self
+    }
+
+    /// Run the observer during the capture phase instead of the bubble phase.
+    pub fn with_capture(mut self, capture: bool) -> Self {
+        self.capture = capture;
+        self
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        self.sets.extend(descriptor.sets.iter().copied());
+        self.before.extend(descriptor.before.iter().copied());
+        self.after.extend(descriptor.after.iter().copied());
+        self.capture |= descriptor.capture;
     }
 
     /// Returns the `events` that the observer is watching.
@@ -336,6 +417,26 @@ impl ObserverDescriptor {
     pub fn entities(&self) -> &[Entity] {
         &self.entities
     }
+
+    /// Returns the `sets` that the observer belongs to.
+    pub fn sets(&self) -> &[InternedSystemSet] {
+        &self.sets
+    }
+
+    /// Returns the sets of observers that run after the observer.
+    pub fn before(&self) -> &[InternedSystemSet] {
+        &self.before
+    }
+
+    /// Returns the sets of observers that run before the observer.
+    pub fn after(&self) -> &[InternedSystemSet] {
+        &self.after
+    }
+
+    /// Returns `true` if the observer runs during the capture phase instead of the bubble phase.
+    pub fn is_capturing(&self) -> bool {
+        self.capture
+    }
 }
 
 /// Event trigger metadata for a given [`Observer`],
@@ -351,6 +452,8 @@ pub struct ObserverTrigger {
     pub target: Entity,
     /// The location of the source code that triggered the obserer.
     pub caller: MaybeLocation,
+    /// The propagation phase the trigger is in.
+    pub phase: PropagationPhase,
 }
 
 impl ObserverTrigger {
@@ -381,6 +484,44 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<ObserverMap>,
+    // The order in which the observers run
+    ordering: ObserverOrdering,
+}
+
+impl CachedObservers {
+    /// Calls `f` with every observer that watches the given `target` or `components`, grouped by
+    /// kind: observers of any trigger first, then entity observers, then component observers.
+    fn for_each_matching<'a>(
+        &'a self,
+        target: Entity,
+        components: impl Iterator<Item = ComponentId>,
+        mut f: impl FnMut(Entity, ObserverRunner),
+    ) {
+        let mut f = |(&observer, &runner): (&'a Entity, &'a ObserverRunner)| f(observer, runner);
+
+        // Observers listening for any kind of this trigger
+        self.map.iter().for_each(&mut f);
+
+        // Entity observers listening for this kind of trigger
+        if target != Entity::PLACEHOLDER {
+            if let Some(map) = self.entity_observers.get(&target) {
+                map.iter().for_each(&mut f);
+            }
+        }
+
+        // Observers listening to this trigger targeting a specific component
+        components.for_each(|id| {
+            if let Some(component_observers) = self.component_observers.get(&id) {
+                component_observers.map.iter().for_each(&mut f);
+
+                if target != Entity::PLACEHOLDER {
+                    if let Some(map) = component_observers.entity_map.get(&target) {
+                        map.iter().for_each(&mut f);
+                    }
+                }
+            }
+        });
+    }
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -419,7 +560,15 @@ impl Observers {
         }
     }
 
+    /// Returns `true` if any observer of the given `event_type` runs during the capture phase.
+    pub(crate) fn has_capturing_observers(&self, event_type: ComponentId) -> bool {
+        self.try_get_observers(event_type)
+            .is_some_and(|observers| observers.ordering.has_capturing())
+    }
+
     /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`.
+    ///
+    /// Only observers that take part in the given propagation `phase` run, sorted by their ordering constraints.
     pub(crate) fn invoke<T>(
         mut world: DeferredWorld,
         event_type: ComponentId,
@@ -428,6 +577,7 @@ impl Observers {
         data: &mut T,
         propagate: &mut bool,
         caller: MaybeLocation,
+        phase: PropagationPhase,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
         let (mut world, observers) = unsafe {
@@ -442,9 +592,7 @@ impl Observers {
             (world.into_deferred(), observers)
         };
 
-        let trigger_for_components = components.clone();
-
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
+        let mut run_observer = |observer: Entity, runner: ObserverRunner| {
             (runner)(
                 world.reborrow(),
                 ObserverTrigger {
@@ -453,36 +601,104 @@ impl Observers {
                     components: components.clone().collect(),
                     target,
                     caller,
+                    phase,
                 },
                 data.into(),
                 propagate,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
-
-        // Trigger entity observers listening for this kind of trigger
-        if target != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&target) {
-                map.iter().for_each(&mut trigger_observer);
+        let has_capturing = observers.ordering.has_capturing();
+        let runs_in_phase = |observer: Entity| {
+            if !has_capturing {
+                return phase != PropagationPhase::Capture;
             }
-        }
+            observers
+                .ordering
+                .get(observer)
+                .is_some_and(|rank| match phase {
+                    PropagationPhase::Capture => rank.capture,
+                    PropagationPhase::Target => true,
+                    PropagationPhase::Bubble => !rank.capture,
+                })
+        };
 
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
+        // Without ordering constraints, observers can run as soon as they are found.
+        if !observers.ordering.is_constrained() {
+            observers.for_each_matching(target, components.clone(), |observer, runner| {
+                if runs_in_phase(observer) {
+                    run_observer(observer, runner);
+                }
+            });
+            return;
+        }
 
-                if target != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&target) {
-                        map.iter().for_each(&mut trigger_observer);
-                    }
+        let mut matched: SmallVec<[(u64, Entity, ObserverRunner); 8]> = SmallVec::new();
+        observers.for_each_matching(target, components.clone(), |observer, runner| {
+            if let Some(rank) = observers.ordering.get(observer) {
+                if runs_in_phase(observer) {
+                    matched.push((rank.rank, observer, runner));
                 }
             }
         });
+        matched.sort_unstable_by_key(|&(rank, ..)| rank);
+        for (_, observer, runner) in matched {
+            run_observer(observer, runner);
+        }
+    }
+
+    /// This will run the observers of the lifecycle `event_type` that watch the resource
+    /// `resource_id`, and returns whether there were any.
+    ///
+    /// Observers that don't watch specific components don't run, since resource events have no
+    /// target entity.
+    pub(crate) fn invoke_resource(
+        mut world: DeferredWorld,
+        event_type: ComponentId,
+        resource_id: ComponentId,
+        caller: MaybeLocation,
+    ) -> bool {
+        // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
+        let (mut world, observers, resource_observers) = unsafe {
+            let world = world.as_unsafe_world_cell();
+            let observers = world.observers();
+            let Some(observers) = observers.try_get_observers(event_type) else {
+                return false;
+            };
+            let Some(resource_observers) = observers.component_observers.get(&resource_id) else {
+                return false;
+            };
+            // SAFETY: There are no outstanding world references
+            world.increment_trigger_id();
+            // SAFETY: The only outstanding references to world are `observers` and `resource_observers`
+            (world.into_deferred(), observers, resource_observers)
+        };
+
+        let mut matched: SmallVec<[(u64, Entity, ObserverRunner); 8]> = resource_observers
+            .map
+            .iter()
+            .filter_map(|(&observer, &runner)| {
+                Some((observers.ordering.get(observer)?.rank, observer, runner))
+            })
+            .collect();
+        if observers.ordering.is_constrained() {
+            matched.sort_unstable_by_key(|&(rank, ..)| rank);
+        }
+        for (_, observer, runner) in matched {
+            (runner)(
+                world.reborrow(),
+                ObserverTrigger {
+                    observer,
+                    event_type,
+                    components: SmallVec::from_elem(resource_id, 1),
+                    target: Entity::PLACEHOLDER,
+                    caller,
+                    phase: PropagationPhase::Target,
+                },
+                (&mut ()).into(),
+                &mut false,
+            );
+        }
+        true
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -579,6 +795,63 @@ impl World {
         self.trigger_with_caller(event, MaybeLocation::caller());
     }
 
+    /// Runs the observers of the lifecycle `events` that watch the resource `resource_id`, in
+    /// order, and returns whether there were any.
+    pub(crate) fn trigger_resource_observers(
+        &mut self,
+        events: &[ComponentId],
+        resource_id: ComponentId,
+        caller: MaybeLocation,
+    ) -> bool {
+        let mut triggered = false;
+        for &event in events {
+            triggered |= Observers::invoke_resource(self.into(), event, resource_id, caller);
+        }
+        triggered
+    }
+
+    /// Runs the [`OnResourceChange`] observers of every resource that was changed since the
+    /// previous call. This is called at the end of every [`Schedule`](crate::schedule::Schedule)
+    /// run.
+    #[track_caller]
+    pub fn trigger_resource_changes(&mut self) {
+        let Some(event_id) = OnResourceChange::component_id(self) else {
+            return;
+        };
+        if self.observers.try_get_observers(event_id).is_none() {
+            return;
+        }
+        let last_run = self.last_resource_change_tick;
+        let this_run = self.increment_change_tick();
+        self.last_resource_change_tick = this_run;
+
+        let Some(observers) = self.observers.try_get_observers(event_id) else {
+            return;
+        };
+        let storages = &self.storages;
+        let changed: SmallVec<[ComponentId; 8]> = observers
+            .component_observers
+            .keys()
+            .copied()
+            .filter(|&id| {
+                storages
+                    .resources
+                    .get(id)
+                    .and_then(ResourceData::get_ticks)
+                    .or_else(|| storages.non_send_resources.get(id)?.get_ticks())
+                    .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+            })
+            .collect();
+        if changed.is_empty() {
+            return;
+        }
+        let caller = MaybeLocation::caller();
+        for id in changed {
+            Observers::invoke_resource(self.into(), event_id, id, caller);
+        }
+        self.flush();
+    }
+
     pub(crate) fn trigger_with_caller<E: Event>(&mut self, mut event: E, caller: MaybeLocation) {
         let event_id = E::register_component_id(self);
         // SAFETY: We just registered `event_id` with the type of `event`
@@ -738,6 +1011,7 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.ordering.insert(observer_entity, descriptor);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.insert(observer_entity, observer_state.runner);
@@ -782,6 +1056,7 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.ordering.remove(entity);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -849,7 +1124,7 @@ mod tests {
     use crate::component::ComponentId;
     use crate::{
         change_detection::MaybeLocation,
-        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace},
+        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace, PropagationPhase},
         prelude::*,
         traversal::Traversal,
     };
@@ -920,6 +1195,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn observer_resource_lifecycle() {
+        #[derive(Resource)]
+        struct Settings(u32);
+
+        #[derive(Resource)]
+        struct Applied(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(
+            Observer::new(|_: Trigger<OnAdd>, mut res: ResMut<Order>| res.observed("add"))
+                .with_resource::<Settings>(),
+        );
+        world.spawn(
+            Observer::new(
+                |_: Trigger<OnInsert>,
+                 settings: Res<Settings>,
+                 mut res: ResMut<Order>,
+                 mut commands: Commands| {
+                    res.observed("insert");
+                    commands.insert_resource(Applied(settings.0));
+                },
+            )
+            .with_resource::<Settings>(),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<OnReplace>, mut res: ResMut<Order>| {
+                res.observed("replace");
+            })
+            .with_resource::<Settings>(),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<OnRemove>, mut res: ResMut<Order>| res.observed("remove"))
+                .with_resource::<Settings>(),
+        );
+        // Observers that don't watch the resource don't run.
+        world.add_observer(|_: Trigger<OnInsert>, mut res: ResMut<Order>| res.observed("global"));
+        world.flush();
+
+        world.insert_resource(Settings(1));
+        assert_eq!(world.resource::<Applied>().0, 1);
+        world.insert_resource(Settings(2));
+        assert_eq!(world.resource::<Applied>().0, 2);
+        assert_eq!(world.remove_resource::<Settings>().unwrap().0, 2);
+        assert!(world.remove_resource::<Settings>().is_none());
+
+        assert_eq!(
+            vec!["add", "insert", "replace", "insert", "replace", "remove"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_resource_change() {
+        #[derive(Resource)]
+        struct Settings(u32);
+
+        #[derive(Resource, Default)]
+        struct Saved(Vec<u32>);
+
+        let mut world = World::new();
+        world.init_resource::<Saved>();
+        world.spawn(
+            Observer::new(
+                |trigger: Trigger<OnResourceChange>,
+                 settings: Res<Settings>,
+                 mut saved: ResMut<Saved>| {
+                    assert_eq!(trigger.target(), Entity::PLACEHOLDER);
+                    saved.0.push(settings.0);
+                },
+            )
+            .with_resource::<Settings>(),
+        );
+        world.insert_resource(Settings(1));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut settings: ResMut<Settings>| {
+            if settings.0 < 3 {
+                settings.0 += 1;
+            }
+        });
+        // The insertion and the mutation are reported together at the end of the schedule.
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        // Reading the resource through `ResMut` doesn't change it.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Saved>().0, vec![2, 3]);
+
+        world.resource_mut::<Settings>().0 = 10;
+        world.trigger_resource_changes();
+        world.trigger_resource_changes();
+        assert_eq!(world.resource::<Saved>().0, vec![2, 3, 10]);
+    }
+
     #[test]
     fn observer_order_insert_remove() {
         let mut world = World::new();
@@ -1408,6 +1779,164 @@ mod tests {
         assert_eq!(vec!["child", "parent"], world.resource::<Order>().0);
     }
 
+    #[test]
+    fn observer_ordering() {
+        fn first(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("first");
+        }
+        fn second(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("second");
+        }
+        fn third(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("third");
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(Observer::new(third).after(second));
+        world.spawn(Observer::new(second).after(first));
+        world.spawn(Observer::new(first));
+        world.add_observer(|_: Trigger<EventA>, mut res: ResMut<Order>| {
+            res.observed("unordered");
+        });
+        world.flush();
+        world.trigger(EventA);
+        assert_eq!(
+            vec!["first", "second", "third", "unordered"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_ordering_sets() {
+        #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Ui;
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let entity = world.spawn_empty().id();
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| {
+                res.observed("global");
+            })
+            .after(Ui),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| {
+                res.observed("entity");
+            })
+            .with_entity(entity)
+            .in_set(Ui),
+        );
+        world.flush();
+        world.trigger_targets(EventA, entity);
+        assert_eq!(vec!["entity", "global"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_ordering_cycle() {
+        fn a(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("a");
+        }
+        fn b(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("b");
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(Observer::new(a).after(b));
+        world.spawn(Observer::new(b).after(a));
+        world.flush();
+        world.trigger(EventA);
+        assert_eq!(vec!["a", "b"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_capture_phase() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(parent)).id();
+        world.spawn(
+            Observer::new(
+                |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                    assert_eq!(trigger.phase(), PropagationPhase::Capture);
+                    res.observed("parent capture");
+                },
+            )
+            .with_entity(parent)
+            .capturing(),
+        );
+        world.spawn(
+            Observer::new(
+                |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                    assert_eq!(trigger.phase(), PropagationPhase::Bubble);
+                    res.observed("parent bubble");
+                },
+            )
+            .with_entity(parent),
+        );
+        world.spawn(
+            Observer::new(
+                |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                    assert_eq!(trigger.phase(), PropagationPhase::Target);
+                    res.observed("child capture");
+                },
+            )
+            .with_entity(child)
+            .capturing(),
+        );
+        world
+            .entity_mut(child)
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("child bubble");
+            });
+        world.flush();
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(
+            vec![
+                "parent capture",
+                "child capture",
+                "child bubble",
+                "parent bubble"
+            ],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_capture_phase_stop() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world.spawn_empty().id();
+        let child = world
+            .spawn(ChildOf(parent))
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("child");
+            })
+            .id();
+        world.spawn(
+            Observer::new(
+                |mut trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                    res.observed("parent capture");
+                    trigger.propagate(false);
+                },
+            )
+            .with_entity(parent)
+            .capturing(),
+        );
+        world.flush();
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(vec!["parent capture"], world.resource::<Order>().0);
+    }
+
     #[test]
     fn observer_propagating_redundant_dispatch_same_entity() {
         let mut world = World::new();
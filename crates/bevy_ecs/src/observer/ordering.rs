@@ -0,0 +1,217 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::cmp::Reverse;
+use log::warn;
+
+use crate::{
+    entity::{Entity, EntityHashMap},
+    observer::ObserverDescriptor,
+    schedule::InternedSystemSet,
+};
+
+/// Where an observer runs relative to the other observers of the same event.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ObserverRank {
+    /// The position of the observer in the order in which observers of the event run.
+    pub rank: u64,
+    /// Whether the observer runs during the capture phase instead of the bubble phase.
+    pub capture: bool,
+}
+
+/// The ordering constraints of a registered observer.
+#[derive(Debug)]
+struct OrderingEntry {
+    observer: Entity,
+    kind: u8,
+    registration: u32,
+    sets: Vec<InternedSystemSet>,
+    before: Vec<InternedSystemSet>,
+    after: Vec<InternedSystemSet>,
+}
+
+/// Computes the order in which the observers of a single event run.
+///
+/// Global observers run first, followed by the observers watching specific entities and specific
+/// components. [`Observer::before`](super::Observer::before) and
+/// [`Observer::after`](super::Observer::after) constraints take precedence over this. Observers
+/// that aren't ordered relative to each other run in an unspecified order.
+///
+/// Ranks are only used while some observers have constraints. Otherwise, observers run in the
+/// order they are found, which avoids sorting them every time an event is triggered.
+#[derive(Default, Debug)]
+pub(crate) struct ObserverOrdering {
+    // Observers in registration order
+    entries: Vec<OrderingEntry>,
+    // The rank of every registered observer
+    ranks: EntityHashMap<ObserverRank>,
+    // Number of observers with `before` or `after` constraints
+    constrained: usize,
+    // Number of observers that run during the capture phase
+    capturing: usize,
+    next_registration: u32,
+}
+
+impl ObserverOrdering {
+    /// Returns the rank of the given `observer`.
+    #[inline]
+    pub fn get(&self, observer: Entity) -> Option<ObserverRank> {
+        self.ranks.get(&observer).copied()
+    }
+
+    /// Returns `true` if any observer has `before` or `after` constraints, in which case observers
+    /// must be sorted by rank.
+    #[inline]
+    pub fn is_constrained(&self) -> bool {
+        self.constrained > 0
+    }
+
+    /// Returns `true` if any observer runs during the capture phase.
+    #[inline]
+    pub fn has_capturing(&self) -> bool {
+        self.capturing > 0
+    }
+
+    /// Adds the `observer` with the constraints of its `descriptor`, after all other observers
+    /// it isn't ordered against.
+    pub fn insert(&mut self, observer: Entity, descriptor: &ObserverDescriptor) {
+        if self.ranks.contains_key(&observer) {
+            return;
+        }
+        if !descriptor.before.is_empty() || !descriptor.after.is_empty() {
+            self.constrained += 1;
+        }
+        if descriptor.capture {
+            self.capturing += 1;
+        }
+        let kind = match (
+            descriptor.components.is_empty(),
+            descriptor.entities.is_empty(),
+        ) {
+            (true, true) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (false, false) => 3,
+        };
+        let entry = OrderingEntry {
+            observer,
+            kind,
+            registration: self.next_registration,
+            sets: descriptor.sets.clone(),
+            before: descriptor.before.clone(),
+            after: descriptor.after.clone(),
+        };
+        self.next_registration += 1;
+        self.ranks.insert(
+            observer,
+            ObserverRank {
+                rank: entry.default_rank(),
+                capture: descriptor.capture,
+            },
+        );
+        self.entries.push(entry);
+        if self.constrained > 0 {
+            self.sort();
+        }
+    }
+
+    /// Removes the `observer`. The remaining observers keep their relative order.
+    pub fn remove(&mut self, observer: Entity) {
+        let Some(rank) = self.ranks.remove(&observer) else {
+            return;
+        };
+        if rank.capture {
+            self.capturing -= 1;
+        }
+        if let Some(index) = self
+            .entries
+            .iter()
+            .position(|entry| entry.observer == observer)
+        {
+            let entry = self.entries.remove(index);
+            if !entry.before.is_empty() || !entry.after.is_empty() {
+                self.constrained -= 1;
+                if self.constrained == 0 {
+                    for entry in &self.entries {
+                        if let Some(rank) = self.ranks.get_mut(&entry.observer) {
+                            rank.rank = entry.default_rank();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Topologically sorts the observers, breaking ties by kind and registration order.
+    fn sort(&mut self) {
+        let mut members: HashMap<InternedSystemSet, Vec<usize>> = HashMap::default();
+        for (index, entry) in self.entries.iter().enumerate() {
+            for &set in &entry.sets {
+                members.entry(set).or_default().push(index);
+            }
+        }
+
+        let mut successors = vec![Vec::new(); self.entries.len()];
+        let mut predecessors = vec![0usize; self.entries.len()];
+        for (index, entry) in self.entries.iter().enumerate() {
+            let before = entry.before.iter().filter_map(|set| members.get(set));
+            for &other in before.flatten() {
+                if other != index {
+                    successors[index].push(other);
+                    predecessors[other] += 1;
+                }
+            }
+            let after = entry.after.iter().filter_map(|set| members.get(set));
+            for &other in after.flatten() {
+                if other != index {
+                    successors[other].push(index);
+                    predecessors[index] += 1;
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<_> = predecessors
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(index, _)| Reverse((self.entries[index].default_rank(), index)))
+            .collect();
+        let mut sorted = Vec::with_capacity(self.entries.len());
+        while let Some(Reverse((_, index))) = ready.pop() {
+            sorted.push(index);
+            for &next in &successors[index] {
+                predecessors[next] -= 1;
+                if predecessors[next] == 0 {
+                    ready.push(Reverse((self.entries[next].default_rank(), next)));
+                }
+            }
+        }
+
+        if sorted.len() < self.entries.len() {
+            let cycle: Vec<_> = (0..self.entries.len())
+                .filter(|&index| predecessors[index] > 0)
+                .collect();
+            warn!(
+                "The ordering constraints of observers {:?} contain a cycle. They will run after the other observers, in registration order.",
+                cycle
+                    .iter()
+                    .map(|&index| self.entries[index].observer)
+                    .collect::<Vec<_>>()
+            );
+            sorted.extend(cycle);
+        }
+
+        for (rank, index) in sorted.into_iter().enumerate() {
+            let observer = self.entries[index].observer;
+            if let Some(entry) = self.ranks.get_mut(&observer) {
+                entry.rank = rank as u64;
+            }
+        }
+    }
+}
+
+impl OrderingEntry {
+    /// The rank of the observer when there are no ordering constraints.
+    fn default_rank(&self) -> u64 {
+        (u64::from(self.kind) << 32) | u64::from(self.registration)
+    }
+}
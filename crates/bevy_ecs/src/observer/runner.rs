@@ -7,6 +7,7 @@ use crate::{
     observer::{ObserverDescriptor, ObserverTrigger},
     prelude::*,
     query::DebugCheckedUnwrap,
+    schedule::IntoSystemSet,
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
@@ -272,6 +273,7 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 pub struct Observer {
     system: Box<dyn Any + Send + Sync + 'static>,
     descriptor: ObserverDescriptor,
+    resources: Vec<fn(&mut World) -> ComponentId>,
     hook_on_add: ComponentHook,
     error_handler: Option<fn(BevyError, ErrorContext)>,
 }
@@ -283,6 +285,7 @@ impl Observer {
         Self {
             system: Box::new(IntoObserverSystem::into_system(system)),
             descriptor: Default::default(),
+            resources: Vec::new(),
             hook_on_add: hook_on_add::<E, B, I::System>,
             error_handler: None,
         }
@@ -319,6 +322,86 @@ impl Observer {
         self
     }
 
+    /// Observe the resource `R`. This will cause the [`Observer`] to run whenever a lifecycle
+    /// event ([`OnAdd`], [`OnInsert`], [`OnReplace`] or [`OnRemove`]) is triggered for the resource,
+    /// or when it is changed ([`OnResourceChange`]).
+    ///
+    /// Resource events have no target entity, so [`Trigger::target`] returns
+    /// [`Entity::PLACEHOLDER`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// #[derive(Resource)]
+    /// struct Settings {
+    ///     volume: f32,
+    /// }
+    ///
+    /// world.spawn(
+    ///     Observer::new(|_: Trigger<OnResourceChange>, settings: Res<Settings>| {
+    ///         println!("Volume is now {}", settings.volume);
+    ///     })
+    ///     .with_resource::<Settings>(),
+    /// );
+    /// world.insert_resource(Settings { volume: 0.5 });
+    ///
+    /// // Mutating the settings in a system prints the new volume at the end of the schedule.
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems(|mut settings: ResMut<Settings>| settings.volume = 1.0);
+    /// schedule.run(&mut world);
+    /// ```
+    pub fn with_resource<R: Resource>(mut self) -> Self {
+        self.resources.push(World::register_resource::<R>);
+        self
+    }
+
+    /// Adds this observer to the given `set`, so other observers can be ordered against it.
+    ///
+    /// Observers are always part of the set of their system type, so observers can also be
+    /// ordered against each other by passing their system function to [`Observer::before`] and
+    /// [`Observer::after`].
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.descriptor.sets.push(set.intern());
+        self
+    }
+
+    /// Runs this observer before the observers in `set` whenever they are triggered by the same event.
+    ///
+    /// By default, observers of the same event run in the order they were registered in.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// # #[derive(Event)]
+    /// # struct Click;
+    /// fn close_menu(_: Trigger<Click>) {}
+    /// fn record_click(_: Trigger<Click>) {}
+    ///
+    /// world.add_observer(close_menu);
+    /// world.spawn(Observer::new(record_click).before(close_menu));
+    /// ```
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.descriptor.before.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Runs this observer after the observers in `set` whenever they are triggered by the same event.
+    ///
+    /// By default, observers of the same event run in the order they were registered in.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.descriptor.after.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Runs this observer during the capture phase of propagating events, as they travel from the
+    /// root of their path down to their target, instead of the bubble phase.
+    ///
+    /// See [`PropagationPhase`](crate::observer::PropagationPhase) for more information.
+    pub fn capturing(mut self) -> Self {
+        self.descriptor.capture = true;
+        self
+    }
+
     /// Set the error handler to use for this observer.
     ///
     /// See the [`error` module-level documentation](crate::error) for more information.
@@ -452,6 +535,11 @@ fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
         B::component_ids(&mut world.components_registrator(), &mut |id| {
             components.push(id);
         });
+        let resources = world
+            .get::<Observer>(entity)
+            .map(|observe| observe.resources.clone())
+            .unwrap_or_default();
+        components.extend(resources.into_iter().map(|register| register(world)));
         let mut descriptor = ObserverDescriptor {
             events: vec![event_id],
             components,
@@ -468,6 +556,7 @@ fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
                     observe.error_handler = Some(error_handler);
                 }
                 let system = observe.system.downcast_mut::<S>().unwrap();
+                descriptor.sets.extend(system.default_system_sets());
                 &mut *system
             } else {
                 return;
@@ -0,0 +1,545 @@
+//! Prefabs: template entities that other entities are instantiated from, driven by the
+//! [`InstanceOf`] [`Relationship`] and the [`Instances`] [`RelationshipTarget`].
+//!
+//! A prefab is an ordinary entity with the [`Prefab`] component, which requires [`Disabled`],
+//! so that templates don't show up in queries. [`EntityWorldMut::instantiate`] clones the
+//! components of a prefab onto an entity using an [`EntityCloner`], and relates the entity to
+//! the prefab with [`InstanceOf`].
+//!
+//! Components that an instance already had when it was instantiated, or that were inserted with
+//! [`EntityWorldMut::insert_override`], are overrides: they are recorded in the
+//! [`PrefabOverrides`] of the instance. Whenever a component of a prefab changes,
+//! [`propagate_prefab_changes`] clones it again onto every instance that doesn't override it.
+//!
+//! Prefabs can be instances of other prefabs, in which case the changes of the outer prefab
+//! propagate through the inner prefab to its instances.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::prefab::{propagate_prefab_changes, Prefab};
+//! #[derive(Component, Clone, PartialEq, Debug)]
+//! struct Health(u32);
+//!
+//! #[derive(Component, Clone, PartialEq, Debug)]
+//! struct Speed(f32);
+//!
+//! let mut world = World::new();
+//! let goblin = world.spawn((Prefab, Health(10), Speed(1.0))).id();
+//!
+//! let weak = world.spawn_empty().instantiate(goblin).id();
+//! let fast = world.spawn(Speed(2.0)).instantiate(goblin).id();
+//! assert_eq!(world.get::<Speed>(fast), Some(&Speed(2.0)));
+//!
+//! // The template itself is disabled, and doesn't show up in queries.
+//! assert_eq!(world.query::<&Health>().iter(&world).count(), 2);
+//!
+//! # let mut schedule = Schedule::default();
+//! # schedule.add_systems(propagate_prefab_changes);
+//! # schedule.run(&mut world);
+//! world.entity_mut(goblin).insert((Health(20), Speed(1.5)));
+//! schedule.run(&mut world);
+//!
+//! assert_eq!(world.get::<Health>(weak), Some(&Health(20)));
+//! assert_eq!(world.get::<Speed>(weak), Some(&Speed(1.5)));
+//! // `Speed` is overridden by this instance.
+//! assert_eq!(world.get::<Health>(fast), Some(&Health(20)));
+//! assert_eq!(world.get::<Speed>(fast), Some(&Speed(2.0)));
+//! ```
+//!
+//! Only the components of the prefab entity itself are instantiated, and the hierarchy
+//! components [`ChildOf`] and [`Children`] are not: instances are not added to the parent of the
+//! prefab, and don't get its children. Components removed from a prefab are not removed from its
+//! instances.
+//!
+//! [`Relationship`]: crate::relationship::Relationship
+//! [`RelationshipTarget`]: crate::relationship::RelationshipTarget
+//! [`ChildOf`]: crate::hierarchy::ChildOf
+//! [`Children`]: crate::hierarchy::Children
+
+#[cfg(feature = "bevy_reflect")]
+use crate::reflect::{ReflectComponent, ReflectFromWorld};
+use crate::{
+    archetype::ArchetypeEntity,
+    bundle::Bundle,
+    component::{Component, ComponentId, Tick},
+    entity::{Entity, EntityCloner},
+    entity_disabling::Disabled,
+    hierarchy::{ChildOf, Children},
+    system::{EntityCommands, Local},
+    world::{EntityWorldMut, FromWorld, World},
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use bevy_platform_support::collections::HashSet;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::std_traits::ReflectDefault;
+use core::slice;
+
+/// Marks an entity as a template that other entities are instantiated from with
+/// [`EntityWorldMut::instantiate`].
+///
+/// Prefabs are [`Disabled`], so that they don't show up in queries.
+/// See the [module docs](crate::prefab) for more info.
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component, Debug, Clone, Default))]
+#[require(Disabled)]
+pub struct Prefab;
+
+/// Stores the prefab that this entity was instantiated from.
+///
+/// This is a [`Relationship`](crate::relationship::Relationship) with the [`Instances`]
+/// of the prefab. It is inserted by [`EntityWorldMut::instantiate`], and
+/// [`propagate_prefab_changes`] uses it to re-apply the components of the prefab when they
+/// change.
+#[derive(Component, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    reflect(Component, PartialEq, Debug, FromWorld, Clone)
+)]
+#[relationship(relationship_target = Instances)]
+pub struct InstanceOf(pub Entity);
+
+impl InstanceOf {
+    /// The prefab this entity was instantiated from.
+    #[inline]
+    pub fn prefab(&self) -> Entity {
+        self.0
+    }
+}
+
+impl FromWorld for InstanceOf {
+    #[inline(always)]
+    fn from_world(_world: &mut World) -> Self {
+        InstanceOf(Entity::PLACEHOLDER)
+    }
+}
+
+/// Tracks the entities that were instantiated from this prefab.
+///
+/// A [`RelationshipTarget`](crate::relationship::RelationshipTarget) collection component that
+/// is populated with entities that "target" this entity with the [`InstanceOf`]
+/// [`Relationship`](crate::relationship::Relationship) component.
+///
+/// Unlike [`Children`](crate::hierarchy::Children), the instances of a prefab are not despawned
+/// along with it.
+#[derive(Component, Default, Debug, PartialEq, Eq)]
+#[relationship_target(relationship = InstanceOf)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component, FromWorld, Default))]
+pub struct Instances(Vec<Entity>);
+
+impl<'a> IntoIterator for &'a Instances {
+    type Item = &'a Entity;
+
+    type IntoIter = slice::Iter<'a, Entity>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// The components of an instance that are not re-applied from its prefab when they change.
+///
+/// See [`EntityWorldMut::insert_override`] and [`EntityWorldMut::reset_override`].
+#[derive(Component, Clone, Default, Debug)]
+pub struct PrefabOverrides(HashSet<ComponentId>);
+
+impl PrefabOverrides {
+    /// Returns `true` if the component with the given id is overridden.
+    #[inline]
+    pub fn contains(&self, id: ComponentId) -> bool {
+        self.0.contains(&id)
+    }
+
+    /// Returns an iterator over the ids of the overridden components.
+    pub fn iter(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+/// Returns the ids of the components that describe the relation of an entity to prefabs and to
+/// the hierarchy, which are never cloned from a prefab to its instances.
+fn prefab_component_ids(world: &mut World) -> [ComponentId; 7] {
+    [
+        world.register_component::<Prefab>(),
+        world.register_component::<Disabled>(),
+        world.register_component::<InstanceOf>(),
+        world.register_component::<Instances>(),
+        world.register_component::<PrefabOverrides>(),
+        world.register_component::<ChildOf>(),
+        world.register_component::<Children>(),
+    ]
+}
+
+/// Clones the components with the given ids from `prefab` onto `instance`, ignoring the
+/// components they require.
+fn clone_from_prefab(world: &mut World, prefab: Entity, instance: Entity, ids: &[ComponentId]) {
+    if ids.is_empty() {
+        return;
+    }
+    EntityCloner::build(world)
+        .deny_all()
+        .without_required_components(|builder| {
+            builder.allow_by_ids(ids.iter().copied());
+        })
+        .clone_entity(prefab, instance);
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Makes this entity an instance of `prefab`: clones the components of the prefab that
+    /// this entity doesn't have yet, and inserts [`InstanceOf`].
+    ///
+    /// The components of the prefab that this entity already has are overrides, and are not
+    /// re-applied when they change on the prefab. See the [module docs](crate::prefab) for more
+    /// info.
+    ///
+    /// # Panics
+    ///
+    /// If `prefab` or this entity does not exist.
+    pub fn instantiate(&mut self, prefab: Entity) -> &mut Self {
+        self.assert_not_despawned();
+        let instance = self.id();
+        self.world_scope(|world| {
+            let ignored = prefab_component_ids(world);
+            let target = world.entity(instance);
+            let (overridden, inherited): (Vec<_>, Vec<_>) = world
+                .entity(prefab)
+                .archetype()
+                .components()
+                .filter(|id| !ignored.contains(id))
+                .partition(|&id| target.contains_id(id));
+
+            clone_from_prefab(world, prefab, instance, &inherited);
+            let mut entity = world.entity_mut(instance);
+            entity.insert(InstanceOf(prefab));
+            if !overridden.is_empty() {
+                entity
+                    .entry::<PrefabOverrides>()
+                    .or_default()
+                    .get_mut()
+                    .0
+                    .extend(overridden);
+            }
+        });
+        self
+    }
+
+    /// Inserts the components of the bundle, and marks them as overridden so that they are not
+    /// re-applied from the prefab of this entity when they change.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    #[track_caller]
+    pub fn insert_override<B: Bundle>(&mut self, bundle: B) -> &mut Self {
+        let ids =
+            self.world_scope(|world| world.register_bundle::<B>().explicit_components().to_vec());
+        self.insert(bundle);
+        self.entry::<PrefabOverrides>()
+            .or_default()
+            .get_mut()
+            .0
+            .extend(ids);
+        self
+    }
+
+    /// Stops overriding the components of the bundle, and clones them again from the prefab of
+    /// this entity.
+    ///
+    /// Components that the prefab doesn't have keep their current value.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn reset_override<B: Bundle>(&mut self) -> &mut Self {
+        self.assert_not_despawned();
+        let instance = self.id();
+        let prefab = self.get::<InstanceOf>().map(InstanceOf::prefab);
+        self.world_scope(|world| {
+            let ids = world.register_bundle::<B>().explicit_components().to_vec();
+            if let Some(mut overrides) = world.get_mut::<PrefabOverrides>(instance) {
+                for id in &ids {
+                    overrides.0.remove(id);
+                }
+            }
+            if let Some(prefab) = prefab.filter(|&prefab| world.get_entity(prefab).is_ok()) {
+                let ids: Vec<_> = ids
+                    .into_iter()
+                    .filter(|&id| world.entity(prefab).contains_id(id))
+                    .collect();
+                clone_from_prefab(world, prefab, instance, &ids);
+            }
+        });
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Makes this entity an instance of `prefab`.
+    ///
+    /// See [`EntityWorldMut::instantiate`] for more details.
+    pub fn instantiate(&mut self, prefab: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.instantiate(prefab);
+        })
+    }
+
+    /// Inserts the components of the bundle, and marks them as overridden so that they are not
+    /// re-applied from the prefab of this entity when they change.
+    ///
+    /// See [`EntityWorldMut::insert_override`] for more details.
+    pub fn insert_override<B: Bundle>(&mut self, bundle: B) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.insert_override(bundle);
+        })
+    }
+
+    /// Stops overriding the components of the bundle, and clones them again from the prefab of
+    /// this entity.
+    ///
+    /// See [`EntityWorldMut::reset_override`] for more details.
+    pub fn reset_override<B: Bundle>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.reset_override::<B>();
+        })
+    }
+}
+
+/// A system that clones the components of every [`Prefab`] that changed since its last run onto
+/// the instances of the prefab that don't override them.
+///
+/// Prefabs that are instances of other prefabs are updated before their own instances, so that
+/// changes propagate through them. On the first run, only the components that changed after an
+/// instance was instantiated are cloned onto it.
+pub fn propagate_prefab_changes(world: &mut World, mut last_run: Local<Option<Tick>>) {
+    let this_run = world.change_tick();
+    let ignored = prefab_component_ids(world);
+    let prefab_id = ignored[0];
+
+    // Prefabs are disabled, so they are found through their archetypes rather than with a query,
+    // which would be subject to the default query filters.
+    let mut queue: VecDeque<Entity> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(prefab_id))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        // Start from the prefabs that are not instances of other prefabs.
+        .filter(|&entity| {
+            world.get::<InstanceOf>(entity).is_none_or(|instance_of| {
+                world
+                    .get_entity(instance_of.prefab())
+                    .map_or(true, |prefab| !prefab.contains_id(prefab_id))
+            })
+        })
+        .collect();
+
+    let mut changed = Vec::new();
+    let mut inherited = Vec::new();
+    while let Some(prefab) = queue.pop_front() {
+        let entity = world.entity(prefab);
+        let Some(instances) = entity.get::<Instances>() else {
+            continue;
+        };
+        let instances = instances.0.clone();
+        changed.clear();
+        changed.extend(entity.archetype().components().filter_map(|id| {
+            if ignored.contains(&id) {
+                return None;
+            }
+            let ticks = entity.get_change_ticks_by_id(id)?;
+            last_run
+                .is_none_or(|last_run| ticks.changed.is_newer_than(last_run, this_run))
+                .then_some((id, ticks.changed))
+        }));
+
+        for instance in instances {
+            let Ok(entity) = world.get_entity(instance) else {
+                continue;
+            };
+            // Instances already have the components that changed before they were instantiated.
+            let instantiated = entity
+                .get_change_ticks::<InstanceOf>()
+                .map(|ticks| ticks.added);
+            let since = match instantiated {
+                Some(instantiated)
+                    if last_run
+                        .is_none_or(|last_run| instantiated.is_newer_than(last_run, this_run)) =>
+                {
+                    instantiated
+                }
+                _ => last_run.unwrap_or_default(),
+            };
+            let overrides = entity.get::<PrefabOverrides>();
+            inherited.clear();
+            inherited.extend(changed.iter().filter_map(|&(id, changed)| {
+                (changed.is_newer_than(since, this_run)
+                    && !overrides.is_some_and(|overrides| overrides.contains(id)))
+                .then_some(id)
+            }));
+            if entity.contains_id(prefab_id) {
+                queue.push_back(instance);
+            }
+            clone_from_prefab(world, prefab, instance, &inherited);
+        }
+    }
+
+    *last_run = Some(this_run);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{propagate_prefab_changes, InstanceOf, Instances, Prefab, PrefabOverrides};
+    use crate::{component::Component, entity_disabling::Disabled, prelude::*};
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct A(u32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct B(u32);
+
+    #[derive(Component, Clone, PartialEq, Debug, Default)]
+    struct C(u32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    #[require(C)]
+    struct D;
+
+    fn schedule() -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(propagate_prefab_changes);
+        schedule
+    }
+
+    #[test]
+    fn instantiate() {
+        let mut world = World::new();
+        let prefab = world.spawn((Prefab, A(1), B(2), D)).id();
+        assert!(world.entity(prefab).contains::<Disabled>());
+
+        let instance = world.spawn(B(20)).instantiate(prefab).id();
+        let instance = world.entity(instance);
+        assert_eq!(instance.get::<A>(), Some(&A(1)));
+        assert_eq!(instance.get::<B>(), Some(&B(20)));
+        assert_eq!(instance.get::<C>(), Some(&C(0)));
+        assert!(instance.contains::<D>());
+        assert!(!instance.contains::<Prefab>());
+        assert!(!instance.contains::<Disabled>());
+        assert_eq!(instance.get::<InstanceOf>(), Some(&InstanceOf(prefab)));
+
+        let overrides = instance.get::<PrefabOverrides>().unwrap();
+        assert!(overrides.contains(world.component_id::<B>().unwrap()));
+        assert_eq!(overrides.iter().count(), 1);
+
+        let instances: Vec<_> = world.get::<Instances>(prefab).unwrap().iter().collect();
+        assert_eq!(instances, [instance.id()]);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn propagate_changes() {
+        let mut world = World::new();
+        let mut schedule = schedule();
+        let prefab = world.spawn((Prefab, A(1), B(2))).id();
+        let plain = world.spawn_empty().instantiate(prefab).id();
+        let overridden = world.spawn_empty().instantiate(prefab).id();
+        world.entity_mut(overridden).insert_override(B(20));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<B>(overridden), Some(&B(20)));
+
+        world.get_mut::<B>(prefab).unwrap().0 = 3;
+        world.entity_mut(prefab).insert(C(4));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<B>(plain), Some(&B(3)));
+        assert_eq!(world.get::<C>(plain), Some(&C(4)));
+        assert_eq!(world.get::<B>(overridden), Some(&B(20)));
+        assert_eq!(world.get::<C>(overridden), Some(&C(4)));
+
+        // Unchanged components of the prefab are not cloned again.
+        world.get_mut::<A>(plain).unwrap().0 = 10;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<A>(plain), Some(&A(10)));
+
+        world.entity_mut(overridden).reset_override::<B>();
+        assert_eq!(world.get::<B>(overridden), Some(&B(3)));
+        world.get_mut::<B>(prefab).unwrap().0 = 5;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<B>(overridden), Some(&B(5)));
+    }
+
+    #[test]
+    fn propagate_through_nested_prefabs() {
+        let mut world = World::new();
+        let mut schedule = schedule();
+        let base = world.spawn((Prefab, A(1), B(2))).id();
+        let variant = world.spawn((Prefab, B(20))).instantiate(base).id();
+        let instance = world.spawn_empty().instantiate(variant).id();
+        assert_eq!(world.get::<A>(instance), Some(&A(1)));
+        assert_eq!(world.get::<B>(instance), Some(&B(20)));
+        assert!(!world.entity(instance).contains::<PrefabOverrides>());
+        schedule.run(&mut world);
+
+        world.entity_mut(base).insert((A(3), B(4)));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<A>(variant), Some(&A(3)));
+        assert_eq!(world.get::<A>(instance), Some(&A(3)));
+        assert_eq!(world.get::<B>(instance), Some(&B(20)));
+    }
+
+    #[test]
+    fn first_run_only_propagates_changes_after_instantiation() {
+        let mut world = World::new();
+        let mut schedule = schedule();
+        let prefab = world.spawn((Prefab, A(1), B(2))).id();
+        let instance = world.spawn_empty().instantiate(prefab).id();
+        world.get_mut::<A>(instance).unwrap().0 = 10;
+        world.increment_change_tick();
+        world.get_mut::<B>(prefab).unwrap().0 = 3;
+
+        schedule.run(&mut world);
+        assert_eq!(world.get::<A>(instance), Some(&A(10)));
+        assert_eq!(world.get::<B>(instance), Some(&B(3)));
+    }
+
+    #[test]
+    fn hierarchy_is_not_instantiated() {
+        let mut world = World::new();
+        let mut schedule = schedule();
+        let parent = world.spawn_empty().id();
+        let prefab = world.spawn((Prefab, A(1), ChildOf(parent))).id();
+        world.spawn(ChildOf(prefab));
+        let instance = world.spawn_empty().instantiate(prefab).id();
+        assert!(!world.entity(instance).contains::<ChildOf>());
+        assert!(!world.entity(instance).contains::<Children>());
+
+        world.increment_change_tick();
+        world.entity_mut(prefab).insert(ChildOf(parent));
+        schedule.run(&mut world);
+        assert!(!world.entity(instance).contains::<ChildOf>());
+        assert_eq!(world.get::<Children>(parent).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn instantiate_with_commands() {
+        let mut world = World::new();
+        let prefab = world.spawn((Prefab, A(1), B(2))).id();
+
+        let mut commands = world.commands();
+        let instance = commands
+            .spawn_empty()
+            .instantiate(prefab)
+            .insert_override(A(10))
+            .id();
+        world.flush();
+
+        assert_eq!(world.get::<A>(instance), Some(&A(10)));
+        assert_eq!(world.get::<B>(instance), Some(&B(2)));
+
+        world.commands().entity(instance).reset_override::<A>();
+        world.flush();
+        assert_eq!(world.get::<A>(instance), Some(&A(1)));
+    }
+}
@@ -897,6 +897,9 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     // An array of filter sets to express `With` or `Without` clauses in disjunctive normal form, for example: `Or<(With<A>, With<B>)>`.
     // Filters like `(With<A>, Or<(With<B>, Without<C>)>` are expanded into `Or<((With<A>, With<B>), (With<A>, Without<C>))>`.
     pub(crate) filter_sets: Vec<AccessFilters<T>>,
+    // Accesses to components of entities other than the ones matched by the filters, such as the targets of `Related` filters.
+    // These are also included in `access`, but conflicts on them can't be ruled out by `filter_sets`.
+    pub(crate) related: Access<T>,
 }
 
 // This is needed since `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -906,6 +909,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
             access: self.access.clone(),
             required: self.required.clone(),
             filter_sets: self.filter_sets.clone(),
+            related: self.related.clone(),
         }
     }
 
@@ -913,6 +917,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
         self.access.clone_from(&source.access);
         self.required.clone_from(&source.required);
         self.filter_sets.clone_from(&source.filter_sets);
+        self.related.clone_from(&source.related);
     }
 }
 
@@ -1000,6 +1005,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: vec![AccessFilters::default()],
+            related: Access::default(),
         }
     }
 
@@ -1010,6 +1016,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: Vec::new(),
+            related: Access::default(),
         }
     }
 
@@ -1082,9 +1089,27 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.filter_sets.append(&mut other.filter_sets.clone());
     }
 
+    /// Adds access to components of entities other than the ones matched by this `FilteredAccess`,
+    /// such as the targets of a [`Related`](super::Related) filter.
+    ///
+    /// Since those entities aren't restricted by the filters, conflicts on this access can't be ruled out by them.
+    pub fn add_related_access(&mut self, access: &Access<T>) {
+        self.access.extend(access);
+        self.related.extend(access);
+    }
+
+    /// Returns the access to components of entities other than the ones matched by this `FilteredAccess`.
+    ///
+    /// This is a subset of [`FilteredAccess::access`].
+    #[inline]
+    pub fn related_access(&self) -> &Access<T> {
+        &self.related
+    }
+
     /// Adds all of the accesses from `other` to `self`.
     pub fn extend_access(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.related.extend(&other.related);
     }
 
     /// Returns `true` if this and `other` can be active at the same time.
@@ -1099,6 +1124,13 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             return true;
         }
 
+        // Related entities aren't restricted by the filters, so their access must be compatible regardless.
+        if !self.related.is_components_compatible(&other.access)
+            || !other.related.is_components_compatible(&self.access)
+        {
+            return false;
+        }
+
         // If the access instances are incompatible, we want to check that whether filters can
         // guarantee that queries are disjoint.
         // Since the `filter_sets` array represents a Disjunctive Normal Form formula ("ORs of ANDs"),
@@ -1131,6 +1163,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// `Or<((With<A>, With<C>), (With<A>, Without<D>), (Without<B>, With<C>), (Without<B>, Without<D>))>`.
     pub fn extend(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.related.extend(&other.related);
         self.required.union_with(&other.required);
 
         // We can avoid allocating a new array of bitsets if `other` contains just a single set of filters:
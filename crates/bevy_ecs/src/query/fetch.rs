@@ -7,8 +7,8 @@ use crate::{
     query::{Access, DebugCheckedUnwrap, FilteredAccess, WorldQuery},
     storage::{ComponentSparseSet, Table, TableRow},
     world::{
-        unsafe_world_cell::UnsafeWorldCell, EntityMut, EntityMutExcept, EntityRef, EntityRefExcept,
-        FilteredEntityMut, FilteredEntityRef, Mut, Ref, World,
+        unsafe_world_cell::UnsafeWorldCell, ChangedEntities, EntityMut, EntityMutExcept, EntityRef,
+        EntityRefExcept, FilteredEntityMut, FilteredEntityRef, Mut, Ref, World,
     },
 };
 use bevy_ptr::{ThinSlicePtr, UnsafeCellDeref};
@@ -1468,6 +1468,7 @@ pub struct WriteFetch<'w, T: Component> {
     >,
     last_run: Tick,
     this_run: Tick,
+    changed_entities: Option<&'w ChangedEntities>,
 }
 
 impl<T: Component> Clone for WriteFetch<'_, T> {
@@ -1510,6 +1511,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
             ),
             last_run,
             this_run,
+            changed_entities: world.components().changed_entities(component_id),
         }
     }
 
@@ -1620,6 +1622,7 @@ unsafe impl<'__w, T: Component<Mutability = Mutable>> QueryData for &'__w mut T
                         changed: changed.deref_mut(),
                         this_run: fetch.this_run,
                         last_run: fetch.last_run,
+                        changed_entities: fetch.changed_entities.map(|log| (log, entity)),
                     },
                     changed_by: caller.map(|caller| caller.deref_mut()),
                 }
@@ -1635,7 +1638,8 @@ unsafe impl<'__w, T: Component<Mutability = Mutable>> QueryData for &'__w mut T
 
                 Mut {
                     value: component.assert_unique().deref_mut(),
-                    ticks: TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run),
+                    ticks: TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run)
+                        .with_changed_entities(fetch.changed_entities, entity),
                     changed_by: caller.map(|caller| caller.deref_mut()),
                 }
             },
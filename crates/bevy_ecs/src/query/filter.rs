@@ -2,7 +2,8 @@ use crate::{
     archetype::Archetype,
     component::{Component, ComponentId, Components, StorageType, Tick},
     entity::Entity,
-    query::{DebugCheckedUnwrap, FilteredAccess, StorageSwitch, WorldQuery},
+    query::{DebugCheckedUnwrap, FilteredAccess, QueryData, StorageSwitch, WorldQuery},
+    relationship::Relationship,
     storage::{ComponentSparseSet, Table, TableRow},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -105,6 +106,21 @@ pub unsafe trait QueryFilter: WorldQuery {
         entity: Entity,
         table_row: TableRow,
     ) -> bool;
+
+    /// Returns the id of a component that must have been added or changed on an entity since
+    /// the last run for the entity to pass this filter, among the ones for which `is_tracked`
+    /// returns `true`.
+    ///
+    /// Queries in systems use it to only check the entities recorded for that component by
+    /// [`World::track_changed_entities`]. The default implementation returns `None`, which
+    /// makes them check every entity.
+    #[inline]
+    fn changed_component(
+        _state: &Self::State,
+        _is_tracked: &impl Fn(ComponentId) -> bool,
+    ) -> Option<ComponentId> {
+        None
+    }
 }
 
 /// Filter that selects entities with a component `T`.
@@ -534,6 +550,15 @@ macro_rules! impl_tuple_query_filter {
                 // SAFETY: The invariants are upheld by the caller.
                 true $(&& unsafe { $name::filter_fetch($name, entity, table_row) })*
             }
+
+            #[inline]
+            fn changed_component(
+                state: &Self::State,
+                is_tracked: &impl Fn(ComponentId) -> bool,
+            ) -> Option<ComponentId> {
+                let ($($name,)*) = state;
+                None $(.or_else(|| $name::changed_component($name, is_tracked)))*
+            }
         }
 
     };
@@ -775,6 +800,14 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
             },
         )
     }
+
+    #[inline]
+    fn changed_component(
+        &id: &ComponentId,
+        is_tracked: &impl Fn(ComponentId) -> bool,
+    ) -> Option<ComponentId> {
+        is_tracked(id).then_some(id)
+    }
 }
 
 /// A filter on a component that only retains results the first time after they have been added or mutably dereferenced.
@@ -1003,6 +1036,218 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
             },
         )
     }
+
+    #[inline]
+    fn changed_component(
+        &id: &ComponentId,
+        is_tracked: &impl Fn(ComponentId) -> bool,
+    ) -> Option<ComponentId> {
+        is_tracked(id).then_some(id)
+    }
+}
+
+/// A filter on a [`Relationship`]: selects entities with a relationship `R` whose target entity matches the filter `F`.
+///
+/// For example, `Related<ChildOf, With<Foo>>` selects all entities whose parent has a `Foo` component.
+/// Filters can be nested to filter on targets further away, such as `Related<ChildOf, Related<ChildOf, With<Foo>>>`
+/// for entities whose grandparent has a `Foo` component.
+///
+/// Unlike other filters, the components accessed by `F` are read from the target entities rather than from the entities
+/// matched by the query. Those reads are registered in the query's [`FilteredAccess`] in a way that can't be ruled out by the
+/// query's other filters, so the scheduler can still determine which systems can run in parallel.
+///
+/// Since `F` is evaluated once per matched entity (using a lookup of the target entity), this filter is not archetypal.
+///
+/// # Panics
+///
+/// Panics when used in a query that mutably accesses a component read by `F`, as the target entities
+/// may be matched by the query themselves.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::hierarchy::ChildOf;
+/// # use bevy_ecs::query::{Related, With};
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::system::Query;
+/// #
+/// # #[derive(Component, Debug)]
+/// # struct Name { name: &'static str };
+/// # #[derive(Component)]
+/// # struct Hidden;
+/// #
+/// fn print_hidden_children_system(query: Query<&Name, Related<ChildOf, With<Hidden>>>) {
+///     for name in &query {
+///         println!("{name:?} has a hidden parent");
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(print_hidden_children_system);
+/// ```
+pub struct Related<R, F>(PhantomData<(R, F)>);
+
+#[doc(hidden)]
+pub struct RelatedFetch<'w, R: Relationship, F: QueryFilter> {
+    relationship: <&'static R as WorldQuery>::Fetch<'w>,
+    filter: F::Fetch<'w>,
+    filter_state: F::State,
+    world: UnsafeWorldCell<'w>,
+}
+
+impl<R: Relationship, F: QueryFilter<State: Clone>> Clone for RelatedFetch<'_, R, F> {
+    fn clone(&self) -> Self {
+        Self {
+            relationship: self.relationship,
+            filter: self.filter.clone(),
+            filter_state: self.filter_state.clone(),
+            world: self.world,
+        }
+    }
+}
+
+/// SAFETY:
+/// `fetch` reads the `R` component of the matched entities, and the components read by `F` on the target entities.
+/// `update_component_access` adds read access for `R` and panics when appropriate, and adds the access of `F` as
+/// related access, which can't be ruled out by the query's filters. It panics if that access conflicts with the
+/// query's previous access, as the target entities may be matched by the query too.
+/// `update_component_access` adds a `With` filter for `R`.
+/// This is sound because `matches_component_set` returns whether the set contains `R`.
+unsafe impl<R: Relationship, F: QueryFilter<State: Clone>> WorldQuery for Related<R, F> {
+    type Fetch<'w> = RelatedFetch<'w, R, F>;
+    type State = (ComponentId, F::State);
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        RelatedFetch {
+            relationship: <&R>::shrink_fetch(fetch.relationship),
+            filter: F::shrink_fetch(fetch.filter),
+            filter_state: fetch.filter_state,
+            world: fetch.world,
+        }
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        (relationship_id, filter_state): &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        RelatedFetch {
+            // SAFETY: The invariants are upheld by the caller.
+            relationship: unsafe { <&R>::init_fetch(world, relationship_id, last_run, this_run) },
+            // SAFETY: The invariants are upheld by the caller, and the access of `F` is registered
+            // in `update_component_access`.
+            filter: unsafe { F::init_fetch(world, filter_state, last_run, this_run) },
+            filter_state: filter_state.clone(),
+            world,
+        }
+    }
+
+    const IS_DENSE: bool = <&R>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        (relationship_id, _): &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { <&R>::set_archetype(&mut fetch.relationship, relationship_id, archetype, table) };
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        (relationship_id, _): &Self::State,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { <&R>::set_table(&mut fetch.relationship, relationship_id, table) };
+    }
+
+    #[inline]
+    fn update_component_access(
+        (relationship_id, filter_state): &Self::State,
+        access: &mut FilteredAccess<ComponentId>,
+    ) {
+        <&R>::update_component_access(relationship_id, access);
+
+        let mut filter_access = FilteredAccess::matches_everything();
+        F::update_component_access(filter_state, &mut filter_access);
+        assert!(
+            access
+                .access()
+                .is_components_compatible(filter_access.access()),
+            "Related<{}, {}> conflicts with a previous access in this query. Components read from related entities cannot be accessed mutably.",
+            core::any::type_name::<R>(),
+            core::any::type_name::<F>(),
+        );
+        access.add_related_access(filter_access.access());
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        (<&R>::init_state(world), F::init_state(world))
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        Some((<&R>::get_state(components)?, F::get_state(components)?))
+    }
+
+    fn matches_component_set(
+        (relationship_id, _): &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <&R>::matches_component_set(relationship_id, set_contains_id)
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on the relationship and the components read by `F`
+unsafe impl<R: Relationship, F: QueryFilter<State: Clone>> QueryFilter for Related<R, F> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are upheld by the caller.
+        let target = unsafe { <&R>::fetch(&mut fetch.relationship, entity, table_row) }.get();
+        let Some(location) = fetch.world.entities().get(target) else {
+            return false;
+        };
+        // SAFETY: `location` was just retrieved from the world's entities, so its archetype exists.
+        let archetype = unsafe {
+            fetch
+                .world
+                .archetypes()
+                .get(location.archetype_id)
+                .debug_checked_unwrap()
+        };
+        if !F::matches_component_set(&fetch.filter_state, &|id| archetype.contains(id)) {
+            return false;
+        }
+        // SAFETY: Only the table of the target entity is accessed, through the access of `F`
+        // which was registered in `update_component_access`.
+        let table = unsafe {
+            fetch
+                .world
+                .storages()
+                .tables
+                .get(location.table_id)
+                .debug_checked_unwrap()
+        };
+        // SAFETY:
+        // - `archetype` and `table` are from the world the fetch was initialized with, and `table` corresponds to `archetype`.
+        // - `filter_state` is the state `filter` was initialized with.
+        // - `archetype` matches `F`, and `location` is in range of `archetype` and `table`.
+        unsafe {
+            F::set_archetype(&mut fetch.filter, &fetch.filter_state, archetype, table);
+            F::filter_fetch(&mut fetch.filter, target, location.table_row)
+        }
+    }
 }
 
 /// A marker trait to indicate that the filter works at an archetype level.
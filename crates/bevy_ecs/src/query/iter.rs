@@ -49,7 +49,9 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             tables: unsafe { &world.storages().tables },
             archetypes: world.archetypes(),
             // SAFETY: The invariants are upheld by the caller.
-            cursor: unsafe { QueryIterationCursor::init(world, query_state, last_run, this_run) },
+            cursor: unsafe {
+                QueryIterationCursor::init_changed(world, query_state, last_run, this_run)
+            },
         }
     }
 
@@ -904,6 +906,13 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Iterator for QueryIter<'w, 's, D, F>
         Func: FnMut(B, Self::Item) -> B,
     {
         let mut accum = init;
+        if self.cursor.changed_entities.is_some() {
+            for item in self.by_ref() {
+                accum = func(accum, item);
+            }
+            return accum;
+        }
+
         // Empty any remaining uniterated values from the current table/archetype
         while self.cursor.current_row != self.cursor.current_len {
             let Some(item) = self.next() else { break };
@@ -2320,6 +2329,9 @@ struct QueryIterationCursor<'w, 's, D: QueryData, F: QueryFilter> {
     current_len: usize,
     // either table row or archetype index, depending on whether both `D`'s and `F`'s fetches are dense
     current_row: usize,
+    // the remaining entities to check instead of the matched storages, when the filter only
+    // passes entities recorded by `World::track_changed_entities`
+    changed_entities: Option<(core::slice::Iter<'s, Entity>, &'w Entities)>,
 }
 
 impl<D: QueryData, F: QueryFilter> Clone for QueryIterationCursor<'_, '_, D, F> {
@@ -2333,6 +2345,7 @@ impl<D: QueryData, F: QueryFilter> Clone for QueryIterationCursor<'_, '_, D, F>
             filter: self.filter.clone(),
             current_len: self.current_len,
             current_row: self.current_row,
+            changed_entities: self.changed_entities.clone(),
         }
     }
 }
@@ -2373,6 +2386,31 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
             is_dense: query_state.is_dense,
             current_len: 0,
             current_row: 0,
+            changed_entities: None,
+        }
+    }
+
+    /// Like [`init`](Self::init), but only checks the entities collected by
+    /// [`QueryState::update_changed_entities`] for these ticks, if any.
+    ///
+    /// # Safety
+    /// - `world` must have permission to access any of the components registered in `query_state`.
+    /// - `world` must be the same one used to initialize `query_state`.
+    unsafe fn init_changed(
+        world: UnsafeWorldCell<'w>,
+        query_state: &'s QueryState<D, F>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        // SAFETY: The invariants are upheld by the caller.
+        let cursor = unsafe { Self::init(world, query_state, last_run, this_run) };
+        match query_state.changed_entities.entities(last_run, this_run) {
+            Some(entities) => QueryIterationCursor {
+                storage_id_iter: [].iter(),
+                changed_entities: Some((entities.iter(), world.entities())),
+                ..cursor
+            },
+            None => cursor,
         }
     }
 
@@ -2386,6 +2424,7 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
             storage_id_iter: self.storage_id_iter.clone(),
             current_len: self.current_len,
             current_row: self.current_row,
+            changed_entities: self.changed_entities.clone(),
         }
     }
 
@@ -2435,6 +2474,9 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
     /// Note that if `F::IS_ARCHETYPAL`, the return value
     /// will be **the exact count of remaining values**.
     fn max_remaining(&self, tables: &'w Tables, archetypes: &'w Archetypes) -> usize {
+        if let Some((entities, _)) = &self.changed_entities {
+            return entities.len();
+        }
         let ids = self.storage_id_iter.clone();
         let remaining_matched: usize = if self.is_dense {
             // SAFETY: The if check ensures that storage_id_iter stores TableIds
@@ -2461,6 +2503,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
         archetypes: &'w Archetypes,
         query_state: &'s QueryState<D, F>,
     ) -> Option<D::Item<'w>> {
+        if self.changed_entities.is_some() {
+            // SAFETY: The invariants are upheld by the caller.
+            return unsafe { self.next_changed(tables, archetypes, query_state) };
+        }
         if self.is_dense {
             loop {
                 // we are on the beginning of the query, or finished processing a table, so skip to the next
@@ -2560,6 +2606,57 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
             }
         }
     }
+
+    /// Returns the next item among the entities in `changed_entities`.
+    ///
+    /// # Safety
+    /// Same as [`next`](Self::next).
+    #[inline]
+    unsafe fn next_changed(
+        &mut self,
+        tables: &'w Tables,
+        archetypes: &'w Archetypes,
+        query_state: &'s QueryState<D, F>,
+    ) -> Option<D::Item<'w>> {
+        let (entities, locations) = self.changed_entities.as_mut()?;
+        for &entity in entities {
+            let Some(location) = locations.get(entity) else {
+                continue;
+            };
+            if !query_state
+                .matched_archetypes
+                .contains(location.archetype_id.index())
+            {
+                continue;
+            }
+
+            let archetype = archetypes.get(location.archetype_id).debug_checked_unwrap();
+            let table = tables.get(location.table_id).debug_checked_unwrap();
+            // SAFETY: `archetype` and `table` are from the world that `fetch/filter` were created for,
+            // `fetch_state`/`filter_state` are the states that `fetch/filter` were initialized with
+            unsafe {
+                D::set_archetype(&mut self.fetch, &query_state.fetch_state, archetype, table);
+                F::set_archetype(
+                    &mut self.filter,
+                    &query_state.filter_state,
+                    archetype,
+                    table,
+                );
+            }
+            // Keeps `QueryIter::sort` from being called after `next`.
+            self.archetype_entities = archetype.entities();
+
+            // SAFETY: set_archetype was called prior, and `location` is the location of `entity`
+            // in the current archetype.
+            if unsafe { F::filter_fetch(&mut self.filter, entity, location.table_row) } {
+                // SAFETY:
+                // - set_archetype was called prior, and `location` is the location of `entity`.
+                // - fetch is only called once for each entity, as `changed_entities` has no duplicates.
+                return Some(unsafe { D::fetch(&mut self.fetch, entity, location.table_row) });
+            }
+        }
+        None
+    }
 }
 
 // A wrapper struct that gives its data a neutral ordering.
@@ -107,10 +107,11 @@ mod tests {
     use crate::{
         archetype::Archetype,
         component::{Component, ComponentId, Components, Tick},
+        hierarchy::ChildOf,
         prelude::{AnyOf, Changed, Entity, Or, QueryState, Res, ResMut, Resource, With, Without},
         query::{
-            ArchetypeFilter, FilteredAccess, Has, QueryCombinationIter, QueryData,
-            ReadOnlyQueryData, WorldQuery,
+            ArchetypeFilter, FilteredAccess, Has, QueryCombinationIter, QueryData, QueryFilter,
+            ReadOnlyQueryData, Related, WorldQuery,
         },
         schedule::{IntoScheduleConfigs, Schedule},
         storage::{Table, TableRow},
@@ -118,7 +119,6 @@ mod tests {
         world::{unsafe_world_cell::UnsafeWorldCell, World},
     };
     use alloc::{vec, vec::Vec};
-    use bevy_ecs_macros::QueryFilter;
     use core::{any::type_name, fmt::Debug, hash::Hash};
     use std::{collections::HashSet, println};
 
@@ -745,6 +745,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn related_filter() {
+        let mut world = World::new();
+        let parent_a = world.spawn(A(0)).id();
+        let parent_b = world.spawn(B(0)).id();
+        let grandchild_parent = world.spawn((C(0), ChildOf(parent_a))).id();
+        world.spawn((C(1), ChildOf(parent_a)));
+        world.spawn((C(2), ChildOf(parent_b)));
+        world.spawn((C(3), ChildOf(grandchild_parent)));
+        world.spawn(C(4));
+
+        fn values<F: QueryFilter>(world: &mut World) -> Vec<usize> {
+            let mut values: Vec<usize> = world
+                .query_filtered::<&C, F>()
+                .iter(world)
+                .map(|c| c.0)
+                .collect();
+            values.sort();
+            values
+        }
+
+        assert_eq!(values::<Related<ChildOf, With<A>>>(&mut world), vec![0, 1]);
+        assert_eq!(
+            values::<Related<ChildOf, Without<A>>>(&mut world),
+            vec![2, 3]
+        );
+        assert_eq!(
+            values::<Related<ChildOf, Related<ChildOf, With<A>>>>(&mut world),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn related_filter_change_detection() {
+        let mut world = World::new();
+        let parent_a = world.spawn(A(0)).id();
+        let parent_b = world.spawn(A(1)).id();
+        world.spawn((B(0), ChildOf(parent_a)));
+        world.spawn((B(1), ChildOf(parent_b)));
+
+        fn children_of_changed(query: Query<&B, Related<ChildOf, Changed<A>>>) -> Vec<usize> {
+            let mut values: Vec<usize> = query.iter().map(|b| b.0).collect();
+            values.sort();
+            values
+        }
+        let mut system = IntoSystem::into_system(children_of_changed);
+        system.initialize(&mut world);
+
+        assert_eq!(system.run((), &mut world), vec![0, 1]);
+        assert_eq!(system.run((), &mut world), Vec::<usize>::new());
+        world.get_mut::<A>(parent_b).unwrap().0 = 2;
+        assert_eq!(system.run((), &mut world), vec![1]);
+    }
+
     #[test]
     fn mut_to_immut_query_methods_have_immut_item() {
         #[derive(Component)]
@@ -7,7 +7,7 @@ use crate::{
     query::{Access, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter, WorldQuery},
     storage::{SparseSetIndex, TableId},
     system::Query,
-    world::{unsafe_world_cell::UnsafeWorldCell, World, WorldId},
+    world::{unsafe_world_cell::UnsafeWorldCell, ChangedEntitiesCursor, World, WorldId},
 };
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
@@ -83,6 +83,9 @@ pub struct QueryState<D: QueryData, F: QueryFilter = ()> {
     pub(super) is_dense: bool,
     pub(crate) fetch_state: D::State,
     pub(crate) filter_state: F::State,
+    /// The entities that can pass the filter, for filters on components that track their changed
+    /// entities.
+    pub(crate) changed_entities: ChangedEntitiesCursor,
     #[cfg(feature = "trace")]
     par_iter_span: Span,
 }
@@ -250,6 +253,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         // because they are evaluated *before* a specific reference is constructed.
         let mut filter_component_access = FilteredAccess::default();
         F::update_component_access(&filter_state, &mut filter_component_access);
+        validate_related_filter_access::<D, F>(&component_access, &filter_component_access);
 
         // Merge the temporary filter access with the main access. This ensures that filter access is
         // properly considered in a global "cross-query" context (both within systems and across systems).
@@ -274,6 +278,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
+            changed_entities: Default::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -309,6 +314,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
+            changed_entities: Default::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -581,6 +587,25 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         }
     }
 
+    /// Collects the entities recorded by [`World::track_changed_entities`] since the last call
+    /// for a component that the filter requires to be added or changed, so that iterating the
+    /// query with `last_run` and `this_run` only checks those entities.
+    ///
+    /// This method only accesses world metadata.
+    pub(crate) fn update_changed_entities(
+        &mut self,
+        world: UnsafeWorldCell,
+        last_run: Tick,
+        this_run: Tick,
+    ) {
+        let components = world.components();
+        let component = F::changed_component(&self.filter_state, &|id| {
+            components.changed_entities(id).is_some()
+        });
+        self.changed_entities
+            .update(components, component, last_run, this_run);
+    }
+
     /// # Panics
     ///
     /// If `world_id` does not match the [`World`] used to call `QueryState::new` for this instance.
@@ -772,6 +797,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         let mut filter_component_access = FilteredAccess::default();
         NewF::update_component_access(&filter_state, &mut filter_component_access);
+        validate_related_filter_access::<NewD, NewF>(&component_access, &filter_component_access);
 
         component_access.extend(&filter_component_access);
         assert!(
@@ -790,6 +816,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access: self.component_access.clone(),
             matched_tables: self.matched_tables.clone(),
             matched_archetypes: self.matched_archetypes.clone(),
+            changed_entities: Default::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -886,6 +913,10 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         let mut new_filter_component_access = FilteredAccess::default();
         NewF::update_component_access(&new_filter_state, &mut new_filter_component_access);
+        validate_related_filter_access::<NewD, NewF>(
+            &component_access,
+            &new_filter_component_access,
+        );
 
         component_access.extend(&new_filter_component_access);
 
@@ -933,6 +964,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access: joined_component_access,
             matched_tables,
             matched_archetypes,
+            changed_entities: Default::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -1871,6 +1903,26 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     }
 }
 
+/// Panics if the components that the filter `F` reads from related entities (such as the targets of a
+/// [`Related`](super::Related) filter) conflict with the access of the query data `D`.
+///
+/// Unlike other filter access, these can't be allowed to conflict with the query data: the related
+/// entities may be matched by the query too, so their items may be alive while the filter is evaluated.
+#[track_caller]
+fn validate_related_filter_access<D: QueryData, F: QueryFilter>(
+    data_access: &FilteredAccess<ComponentId>,
+    filter_access: &FilteredAccess<ComponentId>,
+) {
+    assert!(
+        data_access
+            .access()
+            .is_components_compatible(filter_access.related_access()),
+        "{} conflicts with the access of {}. Components read from related entities cannot be accessed mutably.",
+        core::any::type_name::<F>(),
+        core::any::type_name::<D>(),
+    );
+}
+
 impl<D: QueryData, F: QueryFilter> From<QueryBuilder<'_, D, F>> for QueryState<D, F> {
     fn from(mut value: QueryBuilder<D, F>) -> Self {
         QueryState::from_builder(&mut value)
@@ -0,0 +1,466 @@
+use alloc::{format, vec::Vec};
+use log::warn;
+
+use crate::{
+    component::{Component, HookContext, Mutable},
+    entity::{ComponentCloneCtx, Entity, SourceComponent},
+    error::{ignore, CommandWithEntity, HandleError},
+    relationship::{RelationshipHookMode, RelationshipSourceCollection},
+    system::entity_command,
+    world::{DeferredWorld, EntityWorldMut},
+};
+
+/// A [`Component`] on a "source" [`Entity`] that references a collection of "target" entities, creating a many-to-many
+/// relationship between them. This is the many-to-many counterpart of [`Relationship`](crate::relationship::Relationship):
+/// every [`ManyRelationship`] has a corresponding [`ManyRelationshipTarget`] type (and vice-versa), which exists on each
+/// "target" entity and contains the list of all "source" entities that relate to it.
+///
+/// Both sides store their entities in a [`RelationshipSourceCollection`], so any of the supported collection types
+/// (such as [`Vec<Entity>`] or [`EntityHashSet`](crate::entity::hash_set::EntityHashSet)) can be used on either side.
+///
+/// The [`ManyRelationship`] component is the "source of truth" and the [`ManyRelationshipTarget`] component reflects that source of truth.
+/// When a [`ManyRelationship`] component is inserted on an [`Entity`], the corresponding [`ManyRelationshipTarget`] component is inserted
+/// on each of its targets if it does not already exist, and the "source" entity is added to each target's collection. Like
+/// [`Relationship`](crate::relationship::Relationship), [`ManyRelationship`] components are immutable: to change the targets of
+/// an entity, insert a new [`ManyRelationship`] component.
+///
+/// [`ManyRelationship`] and [`ManyRelationshipTarget`] should always be derived via the [`Component`] trait,
+/// using the `many` option of the `relationship` and `relationship_target` attributes:
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity::Entity;
+/// # use bevy_ecs::relationship::ManyRelationshipTarget;
+/// # use bevy_ecs::world::World;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = TaggedBy, many)]
+/// pub struct Tags(pub Vec<Entity>);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = Tags, many)]
+/// pub struct TaggedBy(Vec<Entity>);
+///
+/// let mut world = World::new();
+/// let red = world.spawn_empty().id();
+/// let round = world.spawn_empty().id();
+/// let apple = world.spawn(Tags(vec![red, round])).id();
+/// let ball = world.spawn(Tags(vec![round])).id();
+///
+/// assert_eq!(world.entity(red).get::<TaggedBy>().unwrap().collection(), &[apple]);
+/// assert_eq!(world.entity(round).get::<TaggedBy>().unwrap().collection(), &[apple, ball]);
+/// ```
+///
+/// When deriving [`ManyRelationshipTarget`] you can specify the `linked_spawn` option to automatically despawn the
+/// "source" entities stored in an entity's [`ManyRelationshipTarget`] when that entity is despawned.
+///
+/// The spawning APIs ([`SpawnRelated`](crate::spawn::SpawnRelated), [`related!`](crate::related) and
+/// [`RelatedSpawner`](crate::relationship::RelatedSpawner)) support many-to-many relationships: each spawned
+/// entity will be related to the entity it was spawned for.
+pub trait ManyRelationship: Component + Sized {
+    /// The [`Component`] added to the "target" entities of this [`ManyRelationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: ManyRelationshipTarget<Relationship = Self>;
+    /// The collection type that stores the "target" entities of this [`ManyRelationship`] component.
+    ///
+    /// The collection should not contain duplicates.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyRelationship::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Creates this [`ManyRelationship`] from the given [`ManyRelationship::Collection`] of targets.
+    fn from_collection(collection: Self::Collection) -> Self;
+
+    /// Iterates the "target" entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of "target" entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_insert` component hook that maintains the [`ManyRelationship`] / [`ManyRelationshipTarget`] connection.
+    ///
+    /// Targets that point to the source entity itself or to entities that do not exist are removed from the relationship.
+    fn on_insert(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            caller,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        // Many-to-many relationships are never cloned as part of linked cloning, so hooks only need
+        // to be skipped when explicitly requested.
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        let mut valid_targets = Vec::with_capacity(targets.len());
+        for target_entity in targets {
+            if target_entity == entity {
+                warn!(
+                    "{}The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid target has been removed.",
+                    caller.map(|location|format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+            } else if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+                valid_targets.push(target_entity);
+                if let Some(mut relationship_target) =
+                    target_entity_mut.get_mut::<Self::RelationshipTarget>()
+                {
+                    relationship_target.collection_mut_risky().add(entity);
+                } else {
+                    let mut target =
+                        <Self::RelationshipTarget as ManyRelationshipTarget>::with_capacity(1);
+                    target.collection_mut_risky().add(entity);
+                    world.commands().entity(target_entity).insert(target);
+                }
+            } else {
+                warn!(
+                    "{}The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid target has been removed.",
+                    caller.map(|location|format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+            }
+        }
+
+        let len = world.entity(entity).get::<Self>().unwrap().len();
+        if valid_targets.len() != len {
+            world
+                .commands()
+                .entity(entity)
+                .queue(move |mut entity: EntityWorldMut| {
+                    if valid_targets.is_empty() {
+                        entity.remove::<Self>();
+                    } else {
+                        let mut collection = Self::Collection::with_capacity(valid_targets.len());
+                        collection.extend_from_iter(valid_targets);
+                        // The valid targets already contain this entity, so the hooks don't need to run again.
+                        entity.insert_with_relationship_hook_mode(
+                            Self::from_collection(collection),
+                            RelationshipHookMode::Skip,
+                        );
+                    }
+                });
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`ManyRelationship`] / [`ManyRelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        for target_entity in targets {
+            let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                continue;
+            };
+            let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            else {
+                continue;
+            };
+            relationship_target.collection_mut_risky().remove(entity);
+            if relationship_target.is_empty() {
+                if let Ok(mut entity) = world.commands().get_entity(target_entity) {
+                    // this "remove" operation must check emptiness because in the event that an identical
+                    // relationship is inserted on top, this removal would break that identical relationship.
+                    entity.queue(|mut entity: EntityWorldMut| {
+                        if entity
+                            .get::<Self::RelationshipTarget>()
+                            .is_some_and(ManyRelationshipTarget::is_empty)
+                        {
+                            entity.remove::<Self::RelationshipTarget>();
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated [`ManyRelationship`] type.
+/// See the [`ManyRelationship`] documentation for more information.
+pub trait ManyRelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// If this is true, when despawning, the related entities targeting this entity will also be despawned.
+    ///
+    /// Unlike [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN), this does
+    /// not affect cloning: "source" entities can relate to many targets, so they are never cloned alongside a single one of them.
+    /// This defaults to false when derived.
+    const LINKED_SPAWN: bool;
+    /// The [`ManyRelationship`] that populates this [`ManyRelationshipTarget`] collection.
+    type Relationship: ManyRelationship<RelationshipTarget = Self>;
+    /// The collection type that stores the "source" entities for this [`ManyRelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyRelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+    /// Returns a mutable reference to the stored [`ManyRelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`ManyRelationshipTarget`] from the given [`ManyRelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`ManyRelationship`] / [`ManyRelationshipTarget`] connection.
+    ///
+    /// This removes the target entity from the [`ManyRelationship`] of each of its sources. Sources that are left
+    /// without any targets have their [`ManyRelationship`] removed.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, HookContext { entity, caller, .. }: HookContext) {
+        let (entities, mut commands) = world.entities_and_commands();
+        let relationship_target = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source_entity in relationship_target.iter() {
+            if entities.get(source_entity).is_ok() {
+                commands.queue(
+                    unrelate::<Self::Relationship>(entity)
+                        .with_entity(source_entity)
+                        .handle_error_with(ignore),
+                );
+            } else {
+                warn!(
+                    "{}Tried to unrelate non-existent entity {}",
+                    caller
+                        .map(|location| format!("{location}: "))
+                        .unwrap_or_default(),
+                    source_entity
+                );
+            }
+        }
+    }
+
+    /// The `on_despawn` component hook that despawns entities stored in an entity's [`ManyRelationshipTarget`] when
+    /// that entity is despawned.
+    // note: think of this as "on_drop"
+    fn on_despawn(mut world: DeferredWorld, HookContext { entity, caller, .. }: HookContext) {
+        let (entities, mut commands) = world.entities_and_commands();
+        let relationship_target = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source_entity in relationship_target.iter() {
+            if entities.get(source_entity).is_ok() {
+                commands.queue(
+                    entity_command::despawn()
+                        .with_entity(source_entity)
+                        .handle_error_with(ignore),
+                );
+            } else {
+                warn!(
+                    "{}Tried to despawn non-existent entity {}",
+                    caller
+                        .map(|location| format!("{location}: "))
+                        .unwrap_or_default(),
+                    source_entity
+                );
+            }
+        }
+    }
+
+    /// Creates this [`ManyRelationshipTarget`] with the given pre-allocated entity capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        let collection =
+            <Self::Collection as RelationshipSourceCollection>::with_capacity(capacity);
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the "source" entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of "source" entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// An [`EntityCommand`](crate::system::EntityCommand) that removes `target` from the [`ManyRelationship`] of an entity,
+/// removing the [`ManyRelationship`] entirely if no targets remain.
+fn unrelate<R: ManyRelationship>(target: Entity) -> impl FnOnce(EntityWorldMut) {
+    move |mut entity: EntityWorldMut| {
+        let Some(relationship) = entity.get::<R>() else {
+            return;
+        };
+        let mut collection = R::Collection::with_capacity(relationship.len());
+        collection.extend_from_iter(relationship.iter().filter(|source| *source != target));
+        if collection.is_empty() {
+            entity.remove::<R>();
+        } else {
+            // The remaining targets are unaffected, so the hooks don't need to run.
+            entity.insert_with_relationship_hook_mode(
+                R::from_collection(collection),
+                RelationshipHookMode::Skip,
+            );
+        }
+    }
+}
+
+/// The "clone behavior" for [`ManyRelationshipTarget`]. This creates an empty [`ManyRelationshipTarget`] instance with space
+/// reserved for the number of sources in the original instance. The [`ManyRelationshipTarget`] will then be populated
+/// when [`ManyRelationship`] components targeting the clone are inserted.
+pub fn clone_many_relationship_target<T: ManyRelationshipTarget>(
+    source: &SourceComponent,
+    context: &mut ComponentCloneCtx,
+) {
+    if let Some(component) = source.read::<T>() {
+        context.write_target_component(T::with_capacity(component.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Component,
+        entity::{hash_set::EntityHashSet, Entity},
+        related,
+        relationship::{ManyRelationshipTarget, RelatedSpawner},
+        spawn::{Spawn, SpawnRelated, SpawnWith},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = TaggedBy, many)]
+    struct Tags(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Tags, many)]
+    struct TaggedBy(Vec<Entity>);
+
+    fn sources(world: &World, target: Entity) -> Option<Vec<Entity>> {
+        world
+            .get::<TaggedBy>(target)
+            .map(|tagged| tagged.iter().collect())
+    }
+
+    #[test]
+    fn many_relationship() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(Tags(vec![a, b])).id();
+        let d = world.spawn(Tags(vec![b])).id();
+        assert_eq!(sources(&world, a), Some(vec![c]));
+        assert_eq!(sources(&world, b), Some(vec![c, d]));
+
+        world.entity_mut(c).insert(Tags(vec![a]));
+        assert_eq!(sources(&world, a), Some(vec![c]));
+        assert_eq!(sources(&world, b), Some(vec![d]));
+
+        world.entity_mut(d).remove::<Tags>();
+        assert_eq!(sources(&world, b), None);
+
+        world.despawn(c);
+        assert_eq!(sources(&world, a), None);
+    }
+
+    #[test]
+    fn despawn_target_unrelates_sources() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(Tags(vec![a, b])).id();
+        let d = world.spawn(Tags(vec![a])).id();
+
+        world.despawn(a);
+        assert_eq!(world.get::<Tags>(c).unwrap().0, vec![b]);
+        assert!(!world.entity(d).contains::<Tags>());
+        assert_eq!(sources(&world, b), Some(vec![c]));
+    }
+
+    #[test]
+    fn invalid_targets_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let b = world.spawn_empty().id();
+        world.entity_mut(b).insert(Tags(vec![a, b, missing]));
+        assert_eq!(world.get::<Tags>(b).unwrap().0, vec![a]);
+        assert_eq!(sources(&world, a), Some(vec![b]));
+        assert_eq!(sources(&world, b), None);
+
+        let c = world.spawn(Tags(vec![missing])).id();
+        assert!(!world.entity(c).contains::<Tags>());
+    }
+
+    #[test]
+    fn linked_spawn_despawns_sources() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Members, many)]
+        struct MemberOf(EntityHashSet);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = MemberOf, many, linked_spawn)]
+        struct Members(EntityHashSet);
+
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(MemberOf(EntityHashSet::from_iter([a, b]))).id();
+
+        world.despawn(a);
+        assert!(world.get_entity(c).is_err());
+        assert!(!world.entity(b).contains::<Members>());
+    }
+
+    #[test]
+    fn spawn_many_related() {
+        let mut world = World::new();
+        let a = world
+            .spawn(related!(TaggedBy[(), ()]))
+            .with_related::<Tags>(|spawner: &mut RelatedSpawner<Tags>| {
+                spawner.spawn(());
+            })
+            .id();
+        let b = world
+            .spawn(TaggedBy::spawn((
+                Spawn(()),
+                SpawnWith(|spawner: &mut RelatedSpawner<Tags>| {
+                    spawner.spawn_empty();
+                }),
+            )))
+            .id();
+
+        let a_sources = sources(&world, a).unwrap();
+        assert_eq!(a_sources.len(), 3);
+        for source in a_sources {
+            assert_eq!(world.get::<Tags>(source).unwrap().0, vec![a]);
+        }
+        assert_eq!(sources(&world, b).unwrap().len(), 2);
+    }
+}
@@ -1,11 +1,13 @@
 //! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
 
+mod many_relationship;
 mod related_methods;
 mod relationship_query;
 mod relationship_source_collection;
 
 use alloc::format;
 
+pub use many_relationship::*;
 pub use related_methods::*;
 pub use relationship_query::*;
 pub use relationship_source_collection::*;
@@ -177,6 +179,34 @@ pub trait Relationship: Component + Sized {
     }
 }
 
+/// A [`Component`] on a "source" [`Entity`] that relates it to one or more "target" entities. This is implemented for every
+/// [`Relationship`] and every [`ManyRelationship`], and is used by the spawning APIs (such as [`RelatedSpawner`],
+/// [`SpawnRelated`](crate::spawn::SpawnRelated) and [`related!`](crate::related)) to relate newly spawned entities to their target.
+pub trait RelationshipSource: Component + Sized {
+    /// The [`Component`] added to the "target" entities, which contains the list of all "source" entities that relate to the "target".
+    type Target: Component;
+
+    /// Creates this component, relating its entity to the given `target` entity.
+    fn from_target(target: Entity) -> Self;
+
+    /// Creates the [`RelationshipSource::Target`] component with the given pre-allocated entity capacity.
+    fn target_with_capacity(capacity: usize) -> Self::Target;
+}
+
+impl<R: Relationship> RelationshipSource for R {
+    type Target = R::RelationshipTarget;
+
+    #[inline]
+    fn from_target(target: Entity) -> Self {
+        R::from(target)
+    }
+
+    #[inline]
+    fn target_with_capacity(capacity: usize) -> Self::Target {
+        <R::RelationshipTarget as RelationshipTarget>::with_capacity(capacity)
+    }
+}
+
 /// The iterator type for the source entities in a [`RelationshipTarget`] collection,
 /// as defined in the [`RelationshipSourceCollection`] trait.
 pub type SourceIter<'w, R> =
@@ -325,6 +355,8 @@ pub enum RelationshipHookMode {
     /// Relationship insert/replace hooks will always run
     Run,
     /// Relationship insert/replace hooks will run if [`RelationshipTarget::LINKED_SPAWN`] is false
+    ///
+    /// [`ManyRelationship`] hooks always run in this mode.
     RunIfNotLinked,
     /// Relationship insert/replace hooks will always be skipped
     Skip,
@@ -2,7 +2,8 @@ use crate::{
     bundle::Bundle,
     entity::{hash_set::EntityHashSet, Entity},
     relationship::{
-        Relationship, RelationshipHookMode, RelationshipSourceCollection, RelationshipTarget,
+        Relationship, RelationshipHookMode, RelationshipSource, RelationshipSourceCollection,
+        RelationshipTarget,
     },
     system::{Commands, EntityCommands},
     world::{EntityWorldMut, World},
@@ -14,7 +15,7 @@ use super::OrderedRelationshipSourceCollection;
 
 impl<'w> EntityWorldMut<'w> {
     /// Spawns entities related to this entity (with the `R` relationship) by taking a function that operates on a [`RelatedSpawner`].
-    pub fn with_related<R: Relationship>(
+    pub fn with_related<R: RelationshipSource>(
         &mut self,
         func: impl FnOnce(&mut RelatedSpawner<R>),
     ) -> &mut Self {
@@ -323,7 +324,7 @@ impl<'w> EntityWorldMut<'w> {
 
 impl<'a> EntityCommands<'a> {
     /// Spawns entities related to this entity (with the `R` relationship) by taking a function that operates on a [`RelatedSpawner`].
-    pub fn with_related<R: Relationship>(
+    pub fn with_related<R: RelationshipSource>(
         &mut self,
         func: impl FnOnce(&mut RelatedSpawnerCommands<R>),
     ) -> &mut Self {
@@ -443,15 +444,15 @@ impl<'a> EntityCommands<'a> {
     }
 }
 
-/// Directly spawns related "source" entities with the given [`Relationship`], targeting
-/// a specific entity.
-pub struct RelatedSpawner<'w, R: Relationship> {
+/// Directly spawns related "source" entities with the given [`RelationshipSource`] (either a [`Relationship`] or a
+/// [`ManyRelationship`](crate::relationship::ManyRelationship)), targeting a specific entity.
+pub struct RelatedSpawner<'w, R: RelationshipSource> {
     target: Entity,
     world: &'w mut World,
     _marker: PhantomData<R>,
 }
 
-impl<'w, R: Relationship> RelatedSpawner<'w, R> {
+impl<'w, R: RelationshipSource> RelatedSpawner<'w, R> {
     /// Creates a new instance that will spawn entities targeting the `target` entity.
     pub fn new(world: &'w mut World, target: Entity) -> Self {
         Self {
@@ -464,30 +465,30 @@ impl<'w, R: Relationship> RelatedSpawner<'w, R> {
     /// Spawns an entity with the given `bundle` and an `R` relationship targeting the `target`
     /// entity this spawner was initialized with.
     pub fn spawn(&mut self, bundle: impl Bundle) -> EntityWorldMut<'_> {
-        self.world.spawn((R::from(self.target), bundle))
+        self.world.spawn((R::from_target(self.target), bundle))
     }
 
     /// Spawns an entity with an `R` relationship targeting the `target`
     /// entity this spawner was initialized with.
     pub fn spawn_empty(&mut self) -> EntityWorldMut<'_> {
-        self.world.spawn(R::from(self.target))
+        self.world.spawn(R::from_target(self.target))
     }
 
-    /// Returns the "target entity" used when spawning entities with an `R` [`Relationship`].
+    /// Returns the "target entity" used when spawning entities with an `R` [`RelationshipSource`].
     pub fn target_entity(&self) -> Entity {
         self.target
     }
 }
 
-/// Uses commands to spawn related "source" entities with the given [`Relationship`], targeting
-/// a specific entity.
-pub struct RelatedSpawnerCommands<'w, R: Relationship> {
+/// Uses commands to spawn related "source" entities with the given [`RelationshipSource`] (either a [`Relationship`] or a
+/// [`ManyRelationship`](crate::relationship::ManyRelationship)), targeting a specific entity.
+pub struct RelatedSpawnerCommands<'w, R: RelationshipSource> {
     target: Entity,
     commands: Commands<'w, 'w>,
     _marker: PhantomData<R>,
 }
 
-impl<'w, R: Relationship> RelatedSpawnerCommands<'w, R> {
+impl<'w, R: RelationshipSource> RelatedSpawnerCommands<'w, R> {
     /// Creates a new instance that will spawn entities targeting the `target` entity.
     pub fn new(commands: Commands<'w, 'w>, target: Entity) -> Self {
         Self {
@@ -500,16 +501,16 @@ impl<'w, R: Relationship> RelatedSpawnerCommands<'w, R> {
     /// Spawns an entity with the given `bundle` and an `R` relationship targeting the `target`
     /// entity this spawner was initialized with.
     pub fn spawn(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
-        self.commands.spawn((R::from(self.target), bundle))
+        self.commands.spawn((R::from_target(self.target), bundle))
     }
 
     /// Spawns an entity with an `R` relationship targeting the `target`
     /// entity this spawner was initialized with.
     pub fn spawn_empty(&mut self) -> EntityCommands<'_> {
-        self.commands.spawn(R::from(self.target))
+        self.commands.spawn(R::from_target(self.target))
     }
 
-    /// Returns the "target entity" used when spawning entities with an `R` [`Relationship`].
+    /// Returns the "target entity" used when spawning entities with an `R` [`RelationshipSource`].
     pub fn target_entity(&self) -> Entity {
         self.target
     }
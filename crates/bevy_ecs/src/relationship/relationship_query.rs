@@ -1,15 +1,59 @@
 use crate::{
     entity::Entity,
-    query::{QueryData, QueryFilter},
+    query::{QueryData, QueryFilter, ROQueryItem},
     relationship::{Relationship, RelationshipTarget},
     system::Query,
 };
-use alloc::collections::VecDeque;
+use alloc::{collections::VecDeque, vec::Vec};
 use smallvec::SmallVec;
 
 use super::SourceIter;
 
 impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
+    /// Joins this query with the `targets` query through the `R` [`Relationship`], returning an iterator over
+    /// `(source_item, target_item)` pairs: one for each entity matched by this query whose `R` target is matched by `targets`.
+    ///
+    /// Both queries are regular system parameters, so their accesses are registered as usual and the scheduler can run
+    /// systems using them in parallel. To only filter on the targets without fetching their data, use
+    /// [`Related`](crate::query::Related) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn print_parent_health(children: Query<(&Name, &ChildOf)>, parents: Query<&Health>) {
+    ///     for ((name, _), health) in children.join_related::<ChildOf, _, _>(&parents) {
+    ///         println!("The parent of {name} has {} health", health.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(print_parent_health);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this query does not have read access to `R`.
+    #[track_caller]
+    pub fn join_related<'a, R: Relationship, OtherD: QueryData, OtherF: QueryFilter>(
+        &'a self,
+        targets: &'a Query<'_, '_, OtherD, OtherF>,
+    ) -> impl Iterator<Item = (ROQueryItem<'a, D>, ROQueryItem<'a, OtherD>)> + 'a {
+        let mut relationships = self
+            .as_readonly()
+            .transmute_lens_filtered_inner::<(Entity, &R), F>();
+        let pairs: Vec<(Entity, Entity)> = relationships
+            .query()
+            .iter()
+            .map(|(source, relationship)| (source, relationship.get()))
+            .collect();
+        pairs.into_iter().filter_map(|(source, target)| {
+            Some((self.get(source).ok()?, targets.get(target).ok()?))
+        })
+    }
+
     /// If the given `entity` contains the `R` [`Relationship`] component, returns the
     /// target entity of that relationship.
     pub fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
@@ -270,3 +314,46 @@ where
         self.next
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Component,
+        hierarchy::ChildOf,
+        system::{Query, SystemState},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, Debug, PartialEq)]
+    struct A(usize);
+
+    #[test]
+    fn join_related() {
+        let mut world = World::new();
+        let parent_a = world.spawn(A(0)).id();
+        let parent_b = world.spawn_empty().id();
+        world.spawn((A(1), ChildOf(parent_a)));
+        world.spawn((A(2), ChildOf(parent_a)));
+        world.spawn((A(3), ChildOf(parent_b)));
+        world.spawn(A(4));
+
+        let mut state = SystemState::<(Query<(&A, &ChildOf)>, Query<&A>)>::new(&mut world);
+        let (children, parents) = state.get(&world);
+        let mut pairs: Vec<(usize, usize)> = children
+            .join_related::<ChildOf, _, _>(&parents)
+            .map(|((child, _), parent)| (child.0, parent.0))
+            .collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![(1, 0), (2, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn join_related_requires_relationship_access() {
+        let mut world = World::new();
+        let mut state = SystemState::<(Query<&A>, Query<&A>)>::new(&mut world);
+        let (children, parents) = state.get(&world);
+        let _ = children.join_related::<ChildOf, _, _>(&parents).count();
+    }
+}
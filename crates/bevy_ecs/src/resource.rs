@@ -33,6 +33,18 @@ pub use bevy_ecs_macros::Resource;
 /// # schedule.run(&mut world);
 /// ```
 ///
+/// # Observers
+///
+/// Inserting, replacing and removing a resource triggers the [`OnAdd`], [`OnInsert`],
+/// [`OnReplace`] and [`OnRemove`] lifecycle events, like it does for components. Resources that
+/// were changed, for example by mutating them through [`ResMut`], trigger [`OnResourceChange`] at
+/// the end of the [`Schedule`] run. These events run the observers that watch the resource with
+/// [`Observer::with_resource`], so code can react to a resource changing without checking it every
+/// frame.
+///
+/// Unlike components, resources don't support [component hooks](crate::component::ComponentHooks)
+/// or relationships.
+///
 /// # `!Sync` Resources
 /// A `!Sync` type cannot implement `Resource`. However, it is possible to wrap a `Send` but not `Sync`
 /// type in [`SyncCell`] or the currently unstable [`Exclusive`] to make it `Sync`. This forces only
@@ -67,6 +79,13 @@ pub use bevy_ecs_macros::Resource;
 /// [`Res`]: crate::system::Res
 /// [`ResMut`]: crate::system::ResMut
 /// [`SyncCell`]: bevy_utils::synccell::SyncCell
+/// [`OnAdd`]: crate::world::OnAdd
+/// [`OnInsert`]: crate::world::OnInsert
+/// [`OnReplace`]: crate::world::OnReplace
+/// [`OnRemove`]: crate::world::OnRemove
+/// [`OnResourceChange`]: crate::world::OnResourceChange
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`Observer::with_resource`]: crate::observer::Observer::with_resource
 #[diagnostic::on_unimplemented(
     message = "`{Self}` is not a `Resource`",
     label = "invalid `Resource`",
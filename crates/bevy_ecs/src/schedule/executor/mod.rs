@@ -19,7 +19,10 @@ use crate::{
     error::{BevyError, ErrorContext, Result},
     prelude::{IntoSystemSet, SystemSet},
     query::Access,
-    schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemTypeSet},
+    schedule::{
+        skips::SkippedSystems, BoxedCondition, InternedSystemSet, NodeId, ScheduleProfile,
+        SystemTypeSet,
+    },
     system::{ScheduleSystem, System, SystemIn, SystemParamValidationError},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
@@ -34,6 +37,8 @@ pub(super) trait SystemExecutor: Send + Sync {
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
+        profile: Option<&mut ScheduleProfile>,
+        skipped: Option<&mut SkippedSystems>,
     );
     fn set_apply_final_deferred(&mut self, value: bool);
 }
@@ -75,17 +80,9 @@ pub struct SystemSchedule {
     pub(super) system_conditions: Vec<Vec<BoxedCondition>>,
     /// Indexed by system node id.
     /// Number of systems that the system immediately depends on.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependencies: Vec<usize>,
     /// Indexed by system node id.
     /// List of systems that immediately depend on the system.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependents: Vec<Vec<usize>>,
     /// Indexed by system node id.
     /// List of sets containing the system that have conditions
@@ -116,6 +113,85 @@ impl SystemSchedule {
             systems_in_sets_with_conditions: Vec::new(),
         }
     }
+
+    /// Removes the systems with the given ids, without rebuilding the schedule.
+    ///
+    /// Systems that were ordered before and after a removed system keep running in that order.
+    pub(super) fn remove_systems(&mut self, ids: &[NodeId]) {
+        let removed: Vec<usize> = self
+            .system_ids
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| ids.contains(id))
+            .map(|(index, _)| index)
+            .collect();
+        if removed.is_empty() {
+            return;
+        }
+
+        // Systems are sorted topologically, so bridging the removed systems in order also bridges
+        // edges that were added when bridging an earlier removed system.
+        for &index in &removed {
+            let dependents = core::mem::take(&mut self.system_dependents[index]);
+            for predecessor in 0..index {
+                let Some(position) = self.system_dependents[predecessor]
+                    .iter()
+                    .position(|&dependent| dependent == index)
+                else {
+                    continue;
+                };
+                self.system_dependents[predecessor].swap_remove(position);
+                for &dependent in &dependents {
+                    if !self.system_dependents[predecessor].contains(&dependent) {
+                        self.system_dependents[predecessor].push(dependent);
+                        self.system_dependencies[dependent] += 1;
+                    }
+                }
+            }
+            for &dependent in &dependents {
+                self.system_dependencies[dependent] -= 1;
+            }
+        }
+
+        // Map the old system indices to the new ones.
+        let mut new_indices = Vec::with_capacity(self.system_ids.len());
+        let mut next = 0;
+        for index in 0..self.system_ids.len() {
+            if removed.binary_search(&index).is_ok() {
+                new_indices.push(None);
+            } else {
+                new_indices.push(Some(next));
+                next += 1;
+            }
+        }
+
+        retain_systems(&mut self.system_ids, &new_indices);
+        retain_systems(&mut self.systems, &new_indices);
+        retain_systems(&mut self.system_conditions, &new_indices);
+        retain_systems(&mut self.system_dependencies, &new_indices);
+        retain_systems(&mut self.system_dependents, &new_indices);
+        retain_systems(&mut self.sets_with_conditions_of_systems, &new_indices);
+
+        for dependents in &mut self.system_dependents {
+            for dependent in dependents {
+                *dependent = new_indices[*dependent].unwrap();
+            }
+        }
+        for systems in &mut self.systems_in_sets_with_conditions {
+            let mut remapped = FixedBitSet::with_capacity(next);
+            remapped.extend(systems.ones().filter_map(|index| new_indices[index]));
+            *systems = remapped;
+        }
+    }
+}
+
+/// Keeps the elements of `values` whose index maps to a new index.
+fn retain_systems<T>(values: &mut Vec<T>, new_indices: &[Option<usize>]) {
+    let mut index = 0;
+    values.retain(|_| {
+        index += 1;
+        new_indices[index - 1].is_some()
+    });
 }
 
 /// See [`ApplyDeferred`].
@@ -1,9 +1,9 @@
 use alloc::{boxed::Box, vec::Vec};
-use bevy_platform_support::sync::Arc;
+use bevy_platform_support::{sync::Arc, time::Instant};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 #[cfg(feature = "std")]
 use std::eprintln;
@@ -17,8 +17,11 @@ use crate::{
     error::{default_error_handler, BevyError, ErrorContext, Result},
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
-    system::ScheduleSystem,
+    schedule::{
+        is_apply_deferred, profiling::SystemTiming, skips::SkippedSystems, BoxedCondition,
+        ExecutorKind, ScheduleProfile, SystemExecutor, SystemSchedule,
+    },
+    system::{ScheduleSystem, SystemParamValidationError},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 
@@ -30,6 +33,8 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    /// Is `true` if the run time of systems should be measured.
+    profiling: bool,
 }
 
 struct Conditions<'a> {
@@ -44,6 +49,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
         executor: &'env MultiThreadedExecutor,
         schedule: &'sys mut SystemSchedule,
         world: &'env mut World,
+        profiling: bool,
     ) -> Self {
         Environment {
             executor,
@@ -55,6 +61,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            profiling,
         }
     }
 }
@@ -75,6 +82,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// The time it took to run the system, if it is being profiled.
+    run_time: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -121,6 +130,16 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Is `true` if the current run is being profiled.
+    profiling: bool,
+    /// When each system had no remaining dependencies, if the current run is being profiled.
+    ready_times: Vec<Option<Instant>>,
+    /// The timings of each system, if the current run is being profiled.
+    system_timings: Vec<SystemTiming>,
+    /// Is `true` if the systems skipped because of invalid parameters are being recorded.
+    recording_skips: bool,
+    /// The systems skipped because of invalid parameters, if they are being recorded.
+    skipped_params: SkippedSystems,
 }
 
 /// References to data required by the executor.
@@ -183,6 +202,8 @@ impl SystemExecutor for MultiThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
+        mut profile: Option<&mut ScheduleProfile>,
+        skipped: Option<&mut SkippedSystems>,
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
@@ -195,6 +216,22 @@ impl SystemExecutor for MultiThreadedExecutor {
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
 
+        state.recording_skips = skipped.is_some();
+        state.profiling = profile.is_some();
+        if state.profiling {
+            let sys_count = schedule.systems.len();
+            state.system_timings.clear();
+            state
+                .system_timings
+                .resize(sys_count, SystemTiming::default());
+            state.ready_times.clear();
+            state.ready_times.resize(sys_count, None);
+            let now = Instant::now();
+            for system_index in self.starting_systems.ones() {
+                state.ready_times[system_index] = Some(now);
+            }
+        }
+
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
         #[cfg(feature = "bevy_debug_stepping")]
@@ -216,7 +253,7 @@ impl SystemExecutor for MultiThreadedExecutor {
             .map(|e| e.0.clone());
         let thread_executor = thread_executor.as_deref();
 
-        let environment = &Environment::new(self, schedule, world);
+        let environment = &Environment::new(self, schedule, world, profile.is_some());
 
         ComputeTaskPool::get_or_init(TaskPool::default).scope_with_executor(
             false,
@@ -249,6 +286,14 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if let Some(profile) = profile.as_mut() {
+            profile.record(state.system_timings.drain(..));
+        }
+
+        if let Some(skipped) = skipped {
+            skipped.append(&mut state.skipped_params);
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -274,12 +319,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
+        run_time: Option<Duration>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                run_time,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             #[cfg(feature = "std")]
@@ -357,6 +406,11 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            profiling: false,
+            ready_times: Vec::new(),
+            system_timings: Vec::new(),
+            recording_skips: false,
+            skipped_params: Vec::new(),
         }
     }
 
@@ -420,6 +474,12 @@ impl ExecutorState {
 
                 self.ready_systems.remove(system_index);
 
+                if self.profiling {
+                    if let Some(ready_time) = self.ready_times[system_index] {
+                        self.system_timings[system_index].blocked = ready_time.elapsed();
+                    }
+                }
+
                 // SAFETY: `can_run` returned true, which means that:
                 // - It must have called `update_archetype_component_access` for each run condition.
                 // - There can be no systems running whose accesses would conflict with any conditions.
@@ -431,6 +491,9 @@ impl ExecutorState {
                         context.environment.world_cell,
                     )
                 } {
+                    if self.profiling {
+                        self.system_timings[system_index].skipped = true;
+                    }
                     self.skip_system_and_signal_dependents(system_index);
                     // signal_dependents may have set more systems to ready.
                     check_for_new_ready_systems = true;
@@ -550,14 +613,26 @@ impl ExecutorState {
             // - The caller ensures that `world` has permission to read any data
             //   required by the conditions.
             // - `update_archetype_component_access` has been called for each run condition.
+            let mut invalid = None;
             let set_conditions_met = unsafe {
-                evaluate_and_fold_conditions(&mut conditions.set_conditions[set_idx], world)
+                evaluate_and_fold_conditions(
+                    &mut conditions.set_conditions[set_idx],
+                    world,
+                    &mut invalid,
+                )
             };
 
             if !set_conditions_met {
                 self.skipped_systems
                     .union_with(&conditions.systems_in_sets_with_conditions[set_idx]);
             }
+            if let (true, Some(reason)) = (self.recording_skips, invalid) {
+                self.skipped_params.extend(
+                    conditions.systems_in_sets_with_conditions[set_idx]
+                        .ones()
+                        .map(|index| (index, reason.clone())),
+                );
+            }
 
             should_run &= set_conditions_met;
             self.evaluated_sets.insert(set_idx);
@@ -568,9 +643,17 @@ impl ExecutorState {
         // - The caller ensures that `world` has permission to read any data
         //   required by the conditions.
         // - `update_archetype_component_access` has been called for each run condition.
+        let mut invalid = None;
         let system_conditions_met = unsafe {
-            evaluate_and_fold_conditions(&mut conditions.system_conditions[system_index], world)
+            evaluate_and_fold_conditions(
+                &mut conditions.system_conditions[system_index],
+                world,
+                &mut invalid,
+            )
         };
+        if let (true, Some(reason)) = (self.recording_skips, invalid) {
+            self.skipped_params.push((system_index, reason));
+        }
 
         if !system_conditions_met {
             self.skipped_systems.insert(system_index);
@@ -586,6 +669,9 @@ impl ExecutorState {
             let valid_params = match unsafe { system.validate_param_unsafe(world) } {
                 Ok(()) => true,
                 Err(e) => {
+                    if self.recording_skips {
+                        self.skipped_params.push((system_index, e.clone()));
+                    }
                     if !e.skipped {
                         error_handler(
                             e.into(),
@@ -624,6 +710,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -645,7 +732,8 @@ impl ExecutorState {
                     }
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let run_time = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, run_time);
         };
 
         self.active_access
@@ -675,8 +763,10 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.profiling.then(Instant::now);
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                let run_time = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, run_time);
             };
 
             context.scope.spawn_on_scope(task);
@@ -685,6 +775,7 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.profiling.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                         (context.error_handler)(
@@ -696,7 +787,8 @@ impl ExecutorState {
                         );
                     }
                 }));
-                context.system_completed(system_index, res, system);
+                let run_time = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, run_time);
             };
 
             context.scope.spawn_on_scope(task);
@@ -707,7 +799,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            run_time,
+        } = result;
+
+        if self.profiling {
+            self.system_timings[system_index].run_time = run_time;
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -738,6 +837,9 @@ impl ExecutorState {
             *remaining -= 1;
             if *remaining == 0 && !self.completed_systems.contains(dep_idx) {
                 self.ready_systems.insert(dep_idx);
+                if self.profiling {
+                    self.ready_times[dep_idx] = Some(Instant::now());
+                }
             }
         }
     }
@@ -783,9 +885,13 @@ fn apply_deferred(
 ///   required by `conditions`.
 /// - `update_archetype_component_access` must have been called
 ///   with `world` for each condition in `conditions`.
+///
+/// The first error returned by the validation of the parameters of `conditions` is stored in
+/// `invalid`.
 unsafe fn evaluate_and_fold_conditions(
     conditions: &mut [BoxedCondition],
     world: UnsafeWorldCell,
+    invalid: &mut Option<SystemParamValidationError>,
 ) -> bool {
     let error_handler = default_error_handler();
 
@@ -805,13 +911,14 @@ unsafe fn evaluate_and_fold_conditions(
                 Err(e) => {
                     if !e.skipped {
                         error_handler(
-                            e.into(),
+                            e.clone().into(),
                             ErrorContext::System {
                                 name: condition.name(),
                                 last_run: condition.get_last_run(),
                             },
                         );
                     }
+                    invalid.get_or_insert(e);
                     return false;
                 }
             }
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+use bevy_platform_support::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -10,8 +12,10 @@ use std::eprintln;
 use crate::{
     error::{default_error_handler, BevyError, ErrorContext},
     schedule::{
-        executor::is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        executor::is_apply_deferred, profiling::SystemTiming, skips::SkippedSystems,
+        BoxedCondition, ExecutorKind, ScheduleProfile, SystemExecutor, SystemSchedule,
     },
+    system::SystemParamValidationError,
     world::World,
 };
 
@@ -25,6 +29,8 @@ pub struct SimpleExecutor {
     evaluated_sets: FixedBitSet,
     /// Systems that have run or been skipped.
     completed_systems: FixedBitSet,
+    /// The timings of each system, if the current run is being profiled.
+    system_timings: Vec<SystemTiming>,
 }
 
 impl SystemExecutor for SimpleExecutor {
@@ -45,6 +51,8 @@ impl SystemExecutor for SimpleExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
+        profile: Option<&mut ScheduleProfile>,
+        mut skipped: Option<&mut SkippedSystems>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -54,6 +62,13 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let profiling = profile.is_some();
+        if profiling {
+            self.system_timings.clear();
+            self.system_timings
+                .resize(schedule.systems.len(), SystemTiming::default());
+        }
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -67,21 +82,39 @@ impl SystemExecutor for SimpleExecutor {
                 }
 
                 // evaluate system set's conditions
-                let set_conditions_met =
-                    evaluate_and_fold_conditions(&mut schedule.set_conditions[set_idx], world);
+                let mut invalid = None;
+                let set_conditions_met = evaluate_and_fold_conditions(
+                    &mut schedule.set_conditions[set_idx],
+                    world,
+                    &mut invalid,
+                );
 
                 if !set_conditions_met {
                     self.completed_systems
                         .union_with(&schedule.systems_in_sets_with_conditions[set_idx]);
                 }
+                if let (Some(skipped), Some(reason)) = (skipped.as_deref_mut(), invalid) {
+                    skipped.extend(
+                        schedule.systems_in_sets_with_conditions[set_idx]
+                            .ones()
+                            .map(|index| (index, reason.clone())),
+                    );
+                }
 
                 should_run &= set_conditions_met;
                 self.evaluated_sets.insert(set_idx);
             }
 
             // evaluate system's conditions
-            let system_conditions_met =
-                evaluate_and_fold_conditions(&mut schedule.system_conditions[system_index], world);
+            let mut invalid = None;
+            let system_conditions_met = evaluate_and_fold_conditions(
+                &mut schedule.system_conditions[system_index],
+                world,
+                &mut invalid,
+            );
+            if let (Some(skipped), Some(reason)) = (skipped.as_deref_mut(), invalid) {
+                skipped.push((system_index, reason));
+            }
 
             should_run &= system_conditions_met;
 
@@ -90,6 +123,9 @@ impl SystemExecutor for SimpleExecutor {
                 let valid_params = match system.validate_param(world) {
                     Ok(()) => true,
                     Err(e) => {
+                        if let Some(skipped) = &mut skipped {
+                            skipped.push((system_index, e.clone()));
+                        }
                         if !e.skipped {
                             error_handler(
                                 e.into(),
@@ -112,6 +148,9 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                if profiling {
+                    self.system_timings[system_index].skipped = true;
+                }
                 continue;
             }
 
@@ -119,6 +158,8 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            let start = profiling.then(Instant::now);
+
             let f = AssertUnwindSafe(|| {
                 if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                     error_handler(
@@ -144,6 +185,14 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            if let Some(start) = start {
+                self.system_timings[system_index].run_time = Some(start.elapsed());
+            }
+        }
+
+        if let Some(profile) = profile {
+            profile.record(self.system_timings.drain(..));
         }
 
         self.evaluated_sets.clear();
@@ -162,11 +211,18 @@ impl SimpleExecutor {
         Self {
             evaluated_sets: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
+            system_timings: Vec::new(),
         }
     }
 }
 
-fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &mut World) -> bool {
+/// Evaluates `conditions`, and stores the first error returned by the validation of their
+/// parameters in `invalid`.
+fn evaluate_and_fold_conditions(
+    conditions: &mut [BoxedCondition],
+    world: &mut World,
+    invalid: &mut Option<SystemParamValidationError>,
+) -> bool {
     let error_handler = default_error_handler();
 
     #[expect(
@@ -181,13 +237,14 @@ fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &mut W
                 Err(e) => {
                     if !e.skipped {
                         error_handler(
-                            e.into(),
+                            e.clone().into(),
                             ErrorContext::System {
                                 name: condition.name(),
                                 last_run: condition.get_last_run(),
                             },
                         );
                     }
+                    invalid.get_or_insert(e);
                     return false;
                 }
             }
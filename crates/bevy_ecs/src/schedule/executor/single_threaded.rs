@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+use bevy_platform_support::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -9,7 +11,11 @@ use std::eprintln;
 
 use crate::{
     error::{default_error_handler, BevyError, ErrorContext},
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, profiling::SystemTiming, skips::SkippedSystems, BoxedCondition,
+        ExecutorKind, ScheduleProfile, SystemExecutor, SystemSchedule,
+    },
+    system::SystemParamValidationError,
     world::World,
 };
 
@@ -29,6 +35,8 @@ pub struct SingleThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
+    /// The timings of each system, if the current run is being profiled.
+    system_timings: Vec<SystemTiming>,
 }
 
 impl SystemExecutor for SingleThreadedExecutor {
@@ -51,6 +59,8 @@ impl SystemExecutor for SingleThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
+        profile: Option<&mut ScheduleProfile>,
+        mut skipped: Option<&mut SkippedSystems>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -60,6 +70,13 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let profiling = profile.is_some();
+        if profiling {
+            self.system_timings.clear();
+            self.system_timings
+                .resize(schedule.systems.len(), SystemTiming::default());
+        }
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -73,21 +90,39 @@ impl SystemExecutor for SingleThreadedExecutor {
                 }
 
                 // evaluate system set's conditions
-                let set_conditions_met =
-                    evaluate_and_fold_conditions(&mut schedule.set_conditions[set_idx], world);
+                let mut invalid = None;
+                let set_conditions_met = evaluate_and_fold_conditions(
+                    &mut schedule.set_conditions[set_idx],
+                    world,
+                    &mut invalid,
+                );
 
                 if !set_conditions_met {
                     self.completed_systems
                         .union_with(&schedule.systems_in_sets_with_conditions[set_idx]);
                 }
+                if let (Some(skipped), Some(reason)) = (skipped.as_deref_mut(), invalid) {
+                    skipped.extend(
+                        schedule.systems_in_sets_with_conditions[set_idx]
+                            .ones()
+                            .map(|index| (index, reason.clone())),
+                    );
+                }
 
                 should_run &= set_conditions_met;
                 self.evaluated_sets.insert(set_idx);
             }
 
             // evaluate system's conditions
-            let system_conditions_met =
-                evaluate_and_fold_conditions(&mut schedule.system_conditions[system_index], world);
+            let mut invalid = None;
+            let system_conditions_met = evaluate_and_fold_conditions(
+                &mut schedule.system_conditions[system_index],
+                world,
+                &mut invalid,
+            );
+            if let (Some(skipped), Some(reason)) = (skipped.as_deref_mut(), invalid) {
+                skipped.push((system_index, reason));
+            }
 
             should_run &= system_conditions_met;
 
@@ -96,6 +131,9 @@ impl SystemExecutor for SingleThreadedExecutor {
                 let valid_params = match system.validate_param(world) {
                     Ok(()) => true,
                     Err(e) => {
+                        if let Some(skipped) = &mut skipped {
+                            skipped.push((system_index, e.clone()));
+                        }
                         if !e.skipped {
                             error_handler(
                                 e.into(),
@@ -119,11 +157,19 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                if profiling {
+                    self.system_timings[system_index].skipped = true;
+                }
                 continue;
             }
 
+            let start = profiling.then(Instant::now);
+
             if is_apply_deferred(system) {
                 self.apply_deferred(schedule, world);
+                if let Some(start) = start {
+                    self.system_timings[system_index].run_time = Some(start.elapsed());
+                }
                 continue;
             }
 
@@ -172,12 +218,18 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)();
             }
 
+            if let Some(start) = start {
+                self.system_timings[system_index].run_time = Some(start.elapsed());
+            }
             self.unapplied_systems.insert(system_index);
         }
 
         if self.apply_final_deferred {
             self.apply_deferred(schedule, world);
         }
+        if let Some(profile) = profile {
+            profile.record(self.system_timings.drain(..));
+        }
         self.evaluated_sets.clear();
         self.completed_systems.clear();
     }
@@ -197,6 +249,7 @@ impl SingleThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_deferred: true,
+            system_timings: Vec::new(),
         }
     }
 
@@ -210,7 +263,13 @@ impl SingleThreadedExecutor {
     }
 }
 
-fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &mut World) -> bool {
+/// Evaluates `conditions`, and stores the first error returned by the validation of their
+/// parameters in `invalid`.
+fn evaluate_and_fold_conditions(
+    conditions: &mut [BoxedCondition],
+    world: &mut World,
+    invalid: &mut Option<SystemParamValidationError>,
+) -> bool {
     let error_handler: fn(BevyError, ErrorContext) = default_error_handler();
 
     #[expect(
@@ -225,13 +284,14 @@ fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &mut W
                 Err(e) => {
                     if !e.skipped {
                         error_handler(
-                            e.into(),
+                            e.clone().into(),
                             ErrorContext::System {
                                 name: condition.name(),
                                 last_run: condition.get_last_run(),
                             },
                         );
                     }
+                    invalid.get_or_insert(e);
                     return false;
                 }
             }
@@ -5,12 +5,17 @@ mod condition;
 mod config;
 mod executor;
 mod pass;
+mod profiling;
+mod report;
 mod schedule;
 mod set;
+mod skips;
 mod stepping;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, executor::*, profiling::*, report::*, schedule::*, set::*, skips::*,
+};
 pub use pass::ScheduleBuildPass;
 
 pub use self::graph::NodeId;
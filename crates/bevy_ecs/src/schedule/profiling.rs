@@ -0,0 +1,334 @@
+use alloc::{borrow::Cow, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::time::Duration;
+
+use crate::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleLabel, SystemSchedule},
+};
+
+/// Resource that collects the run time of every system, for each [`Schedule`](super::Schedule)
+/// that runs while it exists in the [`World`](crate::world::World).
+///
+/// Profiling is enabled by inserting this resource, and has no cost while it is absent.
+/// Systems are only ever blocked by other systems in the
+/// [`MultiThreaded`](super::ExecutorKind::MultiThreaded) executor, so
+/// [`SystemProfile::blocked_time`] is always zero with the other executors.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ExecutorKind, ScheduleLabel, SystemProfiles};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemProfiles>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+/// schedule.add_systems(|| {});
+/// schedule.run(&mut world);
+///
+/// let profiles = world.resource::<SystemProfiles>();
+/// for system in profiles.get(Update).unwrap().systems() {
+///     println!("{}: {:?} on average", system.name, system.average_time());
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct SystemProfiles {
+    schedules: HashMap<InternedScheduleLabel, ScheduleProfile>,
+}
+
+impl SystemProfiles {
+    /// Creates an empty [`SystemProfiles`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the profile of the schedule with the given `label`, if it ran since profiling
+    /// started.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleProfile> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the profiles of all schedules. Iteration order is undefined.
+    pub fn iter(&self) -> impl Iterator<Item = (&dyn ScheduleLabel, &ScheduleProfile)> {
+        self.schedules
+            .iter()
+            .map(|(label, profile)| (&**label, profile))
+    }
+
+    /// Discards all timings collected so far.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Takes the profile of the schedule with the given `label` out of the resource, so that it
+    /// can be updated while the schedule runs.
+    pub(super) fn take(&mut self, label: InternedScheduleLabel) -> ScheduleProfile {
+        self.schedules.remove(&label).unwrap_or_default()
+    }
+
+    /// Puts back a profile previously taken out with [`take`](Self::take).
+    pub(super) fn insert(&mut self, label: InternedScheduleLabel, profile: ScheduleProfile) {
+        self.schedules.insert(label, profile);
+    }
+}
+
+/// The timings collected for the systems of a single [`Schedule`](super::Schedule).
+#[derive(Default, Debug, Clone)]
+pub struct ScheduleProfile {
+    runs: u32,
+    systems: Vec<SystemProfile>,
+}
+
+impl ScheduleProfile {
+    /// Returns the number of times the schedule ran since profiling started.
+    pub fn runs(&self) -> u32 {
+        self.runs
+    }
+
+    /// Returns the timings of every system in the schedule, in the order in which they were
+    /// topologically sorted.
+    pub fn systems(&self) -> &[SystemProfile] {
+        &self.systems
+    }
+
+    /// Returns the timings of the system with the given `id`, if it is part of the schedule.
+    pub fn get(&self, id: NodeId) -> Option<&SystemProfile> {
+        self.systems.iter().find(|system| system.id == id)
+    }
+
+    /// Returns the timings of the system that takes the longest on average.
+    pub fn slowest(&self) -> Option<&SystemProfile> {
+        self.systems
+            .iter()
+            .filter(|system| system.runs > 0)
+            .max_by_key(|system| system.average_time())
+    }
+
+    /// Matches the system profiles up with the systems of `schedule`, keeping the timings of
+    /// the systems that are still part of it.
+    pub(super) fn sync(&mut self, schedule: &SystemSchedule) {
+        if self.systems.len() == schedule.system_ids.len()
+            && self
+                .systems
+                .iter()
+                .zip(&schedule.system_ids)
+                .all(|(system, &id)| system.id == id)
+        {
+            return;
+        }
+
+        let mut previous: HashMap<NodeId, SystemProfile> = self
+            .systems
+            .drain(..)
+            .map(|system| (system.id, system))
+            .collect();
+        self.systems = schedule
+            .system_ids
+            .iter()
+            .zip(&schedule.systems)
+            .map(|(&id, system)| {
+                previous
+                    .remove(&id)
+                    .unwrap_or_else(|| SystemProfile::new(id, system.name()))
+            })
+            .collect();
+    }
+
+    /// Records one run of the schedule, where `systems` contains the timings of each system
+    /// in the same order as [`systems`](Self::systems).
+    pub(super) fn record(&mut self, systems: impl IntoIterator<Item = SystemTiming>) {
+        self.runs += 1;
+        for (profile, timing) in self.systems.iter_mut().zip(systems) {
+            profile.record(timing);
+        }
+    }
+}
+
+/// The timings collected for a single system.
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    /// The id of the system in its schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The number of times the system ran.
+    pub runs: u32,
+    /// The number of times the system was skipped, because its run conditions (or the ones of
+    /// its sets) were not met, or because its parameters were invalid.
+    pub skips: u32,
+    /// The total time spent running the system.
+    pub total_time: Duration,
+    /// The shortest time it took to run the system, or zero if it never ran.
+    pub min_time: Duration,
+    /// The longest time it took to run the system.
+    pub max_time: Duration,
+    /// The total time the system waited to start after all systems it depends on completed,
+    /// because other running systems had conflicting access.
+    pub blocked_time: Duration,
+}
+
+impl SystemProfile {
+    fn new(id: NodeId, name: Cow<'static, str>) -> Self {
+        Self {
+            id,
+            name,
+            runs: 0,
+            skips: 0,
+            total_time: Duration::ZERO,
+            min_time: Duration::ZERO,
+            max_time: Duration::ZERO,
+            blocked_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns the average time it took to run the system.
+    pub fn average_time(&self) -> Duration {
+        if self.runs == 0 {
+            Duration::ZERO
+        } else {
+            self.total_time / self.runs
+        }
+    }
+
+    fn record(&mut self, timing: SystemTiming) {
+        self.blocked_time += timing.blocked;
+        if let Some(run_time) = timing.run_time {
+            self.min_time = if self.runs == 0 {
+                run_time
+            } else {
+                self.min_time.min(run_time)
+            };
+            self.max_time = self.max_time.max(run_time);
+            self.total_time += run_time;
+            self.runs += 1;
+        } else if timing.skipped {
+            self.skips += 1;
+        }
+    }
+}
+
+/// The timings of a system during a single run of its schedule.
+#[derive(Default, Clone, Copy)]
+pub(super) struct SystemTiming {
+    /// The time it took to run the system, if it ran.
+    pub run_time: Option<Duration>,
+    /// Whether the system was skipped.
+    pub skipped: bool,
+    /// The time between the system becoming ready and starting to run.
+    pub blocked: Duration,
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, SystemProfiles},
+    };
+    use core::time::Duration;
+    use std::thread::sleep;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(Resource, Default)]
+    struct R;
+
+    fn slow() {
+        sleep(Duration::from_millis(5));
+    }
+
+    fn slow_mut(_: ResMut<R>) {
+        sleep(Duration::from_millis(5));
+    }
+
+    #[test]
+    fn profile_systems() {
+        for executor in [
+            ExecutorKind::MultiThreaded,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::Simple,
+        ] {
+            let mut world = World::new();
+            world.init_resource::<R>();
+            world.init_resource::<SystemProfiles>();
+
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.set_executor_kind(executor);
+            schedule.add_systems((slow, slow_mut, slow_mut.run_if(|| false)));
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let profiles = world.resource::<SystemProfiles>();
+            let profile = profiles.get(TestSchedule).unwrap();
+            assert_eq!(profile.runs(), 2, "{executor:?}");
+            assert_eq!(profile.systems().len(), 3, "{executor:?}");
+
+            let runs = profile
+                .systems()
+                .iter()
+                .map(|system| system.runs)
+                .sum::<u32>();
+            let skips = profile
+                .systems()
+                .iter()
+                .map(|system| system.skips)
+                .sum::<u32>();
+            assert_eq!(runs, 4, "{executor:?}");
+            assert_eq!(skips, 2, "{executor:?}");
+
+            let slowest = profile.slowest().unwrap();
+            assert!(slowest.min_time >= Duration::from_millis(5));
+            assert!(slowest.max_time >= slowest.average_time());
+            assert!(slowest.average_time() >= slowest.min_time);
+        }
+    }
+
+    #[test]
+    fn profile_blocked_systems() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world.init_resource::<SystemProfiles>();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        // Ambiguous systems that conflict, so one has to wait for the other.
+        schedule.add_systems((slow_mut, slow_mut));
+        schedule.run(&mut world);
+
+        let profile = world
+            .resource::<SystemProfiles>()
+            .get(TestSchedule)
+            .unwrap();
+        let blocked = profile
+            .systems()
+            .iter()
+            .map(|system| system.blocked_time)
+            .max()
+            .unwrap();
+        assert!(blocked >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn profiles_follow_schedule_changes() {
+        let mut world = World::new();
+        world.init_resource::<SystemProfiles>();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems(slow);
+        schedule.run(&mut world);
+        schedule.add_systems(|| {});
+        schedule.run(&mut world);
+
+        let profile = world
+            .resource::<SystemProfiles>()
+            .get(TestSchedule)
+            .unwrap();
+        assert_eq!(profile.systems().len(), 2);
+        let runs = profile.systems().iter().map(|system| system.runs);
+        assert_eq!(runs.sum::<u32>(), 3);
+    }
+}
@@ -0,0 +1,363 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::{component::ComponentId, schedule::NodeId};
+
+/// A structured description of the systems and system sets of a [`Schedule`](super::Schedule),
+/// how they are ordered and which of them are ambiguous with each other.
+///
+/// Created by [`Schedule::report`](super::Schedule::report). The report can be inspected
+/// directly, for example to fail a test when a crate introduces new ambiguities, or exported to
+/// [Graphviz](https://graphviz.org/) with [`to_dot`](Self::to_dot) and to JSON with
+/// [`to_json`](Self::to_json). Both exports are sorted, so they can be diffed between builds.
+///
+/// Names are never shortened, regardless of [`ScheduleBuildSettings::use_shortnames`].
+///
+/// [`ScheduleBuildSettings::use_shortnames`]: super::ScheduleBuildSettings::use_shortnames
+#[derive(Debug, Clone)]
+pub struct ScheduleReport {
+    /// The label of the schedule.
+    pub label: String,
+    /// Every system and system set in the schedule, sorted by id.
+    pub nodes: Vec<NodeReport>,
+    /// The ordering dependencies between systems and sets, as `(before, after)` pairs.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// The hierarchy of system sets, as `(set, member)` pairs.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// The pairs of systems with conflicting data access that have no defined order.
+    pub ambiguities: Vec<AmbiguityReport>,
+}
+
+/// A system or system set in a [`ScheduleReport`].
+#[derive(Debug, Clone)]
+pub struct NodeReport {
+    /// The id of the system or set.
+    pub id: NodeId,
+    /// The name of the system or set.
+    pub name: String,
+    /// All the sets that contain this node, directly or through other sets, sorted by id.
+    pub sets: Vec<NodeId>,
+}
+
+/// A pair of ambiguous systems in a [`ScheduleReport`].
+#[derive(Debug, Clone)]
+pub struct AmbiguityReport {
+    /// The first system.
+    pub system_a: NodeId,
+    /// The second system.
+    pub system_b: NodeId,
+    /// The components and resources that both systems access, where at least one of them
+    /// accesses it mutably.
+    ///
+    /// If this is empty, the systems conflict on the whole [`World`](crate::world::World).
+    pub conflicts: Vec<ConflictReport>,
+}
+
+/// A component or resource that two systems in an [`AmbiguityReport`] conflict on.
+#[derive(Debug, Clone)]
+pub struct ConflictReport {
+    /// The id of the component or resource.
+    pub id: ComponentId,
+    /// The name of the component or resource.
+    pub name: String,
+}
+
+impl ScheduleReport {
+    /// Returns the system or set with the given `id`.
+    pub fn node(&self, id: NodeId) -> Option<&NodeReport> {
+        self.nodes
+            .binary_search_by_key(&id, |node| node.id)
+            .ok()
+            .map(|index| &self.nodes[index])
+    }
+
+    /// Returns the name of the system or set with the given `id`, or an empty string if it
+    /// is not part of the schedule.
+    pub fn name(&self, id: NodeId) -> &str {
+        self.node(id).map_or("", |node| &node.name)
+    }
+
+    /// Returns the ambiguities where at least one of the systems has a name that starts with
+    /// `prefix`, such as a crate or module path like `"my_crate::"`.
+    pub fn ambiguities_involving<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = &'a AmbiguityReport> + 'a {
+        self.ambiguities.iter().filter(move |ambiguity| {
+            self.name(ambiguity.system_a).starts_with(prefix)
+                || self.name(ambiguity.system_b).starts_with(prefix)
+        })
+    }
+
+    /// Exports the schedule as a [Graphviz](https://graphviz.org/) DOT graph.
+    ///
+    /// Systems are drawn as boxes and sets as ellipses. Solid edges are ordering dependencies,
+    /// dashed edges go from a set to its members, and red edges connect ambiguous systems.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_string(&self.label)).unwrap();
+        writeln!(dot, "  node [shape=box];").unwrap();
+        for node in &self.nodes {
+            let shape = if node.id.is_set() {
+                ", shape=ellipse"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "  {} [label={}{shape}];",
+                dot_id(node.id),
+                dot_string(&node.name)
+            )
+            .unwrap();
+        }
+        for &(set, member) in &self.hierarchy {
+            writeln!(
+                dot,
+                "  {} -> {} [style=dashed];",
+                dot_id(set),
+                dot_id(member)
+            )
+            .unwrap();
+        }
+        for &(before, after) in &self.dependencies {
+            writeln!(dot, "  {} -> {};", dot_id(before), dot_id(after)).unwrap();
+        }
+        for ambiguity in &self.ambiguities {
+            let conflicts = if ambiguity.conflicts.is_empty() {
+                String::from("World")
+            } else {
+                ambiguity
+                    .conflicts
+                    .iter()
+                    .map(|conflict| conflict.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            writeln!(
+                dot,
+                "  {} -> {} [dir=none, color=red, label={}];",
+                dot_id(ambiguity.system_a),
+                dot_id(ambiguity.system_b),
+                dot_string(&conflicts)
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the schedule as JSON, with one node, edge or ambiguity per line.
+    ///
+    /// Nodes are identified by strings like `"System(3)"` or `"Set(1)"`:
+    ///
+    /// ```json
+    /// {
+    ///   "label": "Update",
+    ///   "nodes": [
+    ///     {"id": "System(0)", "name": "my_crate::movement", "sets": ["Set(1)"]}
+    ///   ],
+    ///   "dependencies": [
+    ///     {"before": "System(0)", "after": "System(2)"}
+    ///   ],
+    ///   "hierarchy": [
+    ///     {"set": "Set(1)", "member": "System(0)"}
+    ///   ],
+    ///   "ambiguities": [
+    ///     {"system_a": "System(0)", "system_b": "System(3)", "conflicts": [{"id": 5, "name": "my_crate::Position"}]}
+    ///   ]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n");
+        writeln!(json, "  \"label\": {},", json_string(&self.label)).unwrap();
+
+        let nodes = self.nodes.iter().map(|node| {
+            let sets = node
+                .sets
+                .iter()
+                .map(|&set| json_id(set))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{{\"id\": {}, \"name\": {}, \"sets\": [{sets}]}}",
+                json_id(node.id),
+                json_string(&node.name)
+            )
+        });
+        write_json_array(&mut json, "nodes", nodes, false);
+
+        let dependencies = self.dependencies.iter().map(|&(before, after)| {
+            format!(
+                "{{\"before\": {}, \"after\": {}}}",
+                json_id(before),
+                json_id(after)
+            )
+        });
+        write_json_array(&mut json, "dependencies", dependencies, false);
+
+        let hierarchy = self.hierarchy.iter().map(|&(set, member)| {
+            format!(
+                "{{\"set\": {}, \"member\": {}}}",
+                json_id(set),
+                json_id(member)
+            )
+        });
+        write_json_array(&mut json, "hierarchy", hierarchy, false);
+
+        let ambiguities = self.ambiguities.iter().map(|ambiguity| {
+            let conflicts = ambiguity
+                .conflicts
+                .iter()
+                .map(|conflict| {
+                    format!(
+                        "{{\"id\": {}, \"name\": {}}}",
+                        conflict.id.index(),
+                        json_string(&conflict.name)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{{\"system_a\": {}, \"system_b\": {}, \"conflicts\": [{conflicts}]}}",
+                json_id(ambiguity.system_a),
+                json_id(ambiguity.system_b)
+            )
+        });
+        write_json_array(&mut json, "ambiguities", ambiguities, true);
+
+        json.push_str("}\n");
+        json
+    }
+}
+
+fn dot_id(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn json_id(id: NodeId) -> String {
+    json_string(&format!("{id:?}"))
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn write_json_array(json: &mut String, key: &str, items: impl Iterator<Item = String>, last: bool) {
+    let items: Vec<_> = items.collect();
+    if items.is_empty() {
+        write!(json, "  \"{key}\": []").unwrap();
+    } else {
+        writeln!(json, "  \"{key}\": [").unwrap();
+        writeln!(json, "    {}", items.join(",\n    ")).unwrap();
+        json.push_str("  ]");
+    }
+    json.push_str(if last { "\n" } else { ",\n" });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        schedule::{NodeId, ScheduleLabel},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSet;
+
+    #[derive(Resource, Default)]
+    struct R;
+
+    fn read_r(_: Res<R>) {}
+    fn write_r(_: ResMut<R>) {}
+    fn unrelated() {}
+
+    fn report() -> super::ScheduleReport {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems((read_r.in_set(TestSet), write_r, unrelated.before(write_r)));
+        schedule.initialize(&mut world).unwrap();
+        schedule.report(world.components()).unwrap()
+    }
+
+    #[test]
+    fn ambiguities() {
+        let report = report();
+        assert_eq!(report.ambiguities.len(), 1);
+
+        let ambiguity = &report.ambiguities[0];
+        let mut names = [
+            report.name(ambiguity.system_a),
+            report.name(ambiguity.system_b),
+        ];
+        names.sort();
+        assert!(names[0].ends_with("read_r"));
+        assert!(names[1].ends_with("write_r"));
+        assert_eq!(ambiguity.conflicts.len(), 1);
+        assert!(ambiguity.conflicts[0].name.ends_with("report::tests::R"));
+
+        // Full names are reported, so ambiguities can be filtered by crate.
+        assert_eq!(report.ambiguities_involving("bevy_ecs::").count(), 1);
+        assert_eq!(report.ambiguities_involving("other_crate::").count(), 0);
+
+        let read_r = report
+            .nodes
+            .iter()
+            .find(|node| node.name.ends_with("read_r"))
+            .unwrap();
+        assert!(read_r.sets.iter().any(|&set| report.name(set) == "TestSet"));
+    }
+
+    #[test]
+    fn export() {
+        let report = report();
+        let unrelated = report
+            .nodes
+            .iter()
+            .find(|node| node.name.ends_with("unrelated"))
+            .unwrap()
+            .id;
+        let NodeId::System(unrelated) = unrelated else {
+            panic!("expected a system");
+        };
+
+        let dot = report.to_dot();
+        assert!(dot.starts_with("digraph \"TestSchedule\" {\n"));
+        assert!(dot.contains(&alloc::format!("  system_{unrelated} -> set_")));
+        assert!(
+            dot.contains("[dir=none, color=red, label=\"bevy_ecs::schedule::report::tests::R\"]")
+        );
+
+        let json = report.to_json();
+        assert!(json.starts_with("{\n  \"label\": \"TestSchedule\",\n  \"nodes\": [\n"));
+        assert!(json.contains(&alloc::format!(
+            "{{\"before\": \"System({unrelated})\", \"after\": \"Set("
+        )));
+        assert!(json.ends_with("\"}]}\n  ]\n}\n"));
+    }
+}
@@ -27,7 +27,7 @@ use tracing::info_span;
 
 use crate::{
     component::{ComponentId, Components, Tick},
-    error::default_error_handler,
+    error::{default_error_handler, BevyError, ErrorContext},
     prelude::Component,
     resource::Resource,
     schedule::*,
@@ -382,6 +382,41 @@ impl Schedule {
         self
     }
 
+    /// Removes the systems in `set` from the schedule, and returns how many were removed.
+    ///
+    /// `set` can also be a system function, in which case all instances of it are removed.
+    /// Systems and sets that were ordered before and after a removed system stay ordered relative
+    /// to each other. The set itself is kept; use [`Schedule::remove_set`] to remove it.
+    ///
+    /// If the schedule has already been built, the systems are removed from the executable
+    /// schedule directly, so removing systems doesn't rebuild the schedule.
+    pub fn remove_systems_in_set<M>(&mut self, set: impl IntoSystemSet<M>) -> usize {
+        let removed = self
+            .graph
+            .remove_systems_in_set(set.into_system_set().intern());
+        if removed.is_empty() {
+            return 0;
+        }
+
+        self.executable.remove_systems(&removed);
+        if self.executor_initialized {
+            self.executor.init(&self.executable);
+        }
+        removed.len()
+    }
+
+    /// Removes `set` from the schedule, and returns whether it was in the schedule.
+    ///
+    /// The systems and sets in `set` are kept, but its run conditions and ordering constraints
+    /// no longer apply to them. Systems and sets that were ordered before and after `set` stay
+    /// ordered relative to each other.
+    ///
+    /// Unlike [`Schedule::remove_systems_in_set`], this rebuilds the schedule the next time it
+    /// runs.
+    pub fn remove_set<M>(&mut self, set: impl IntoSystemSet<M>) -> bool {
+        self.graph.remove_set(set.into_system_set().intern())
+    }
+
     /// Add a custom build pass to the schedule.
     pub fn add_build_pass<T: ScheduleBuildPass>(&mut self, pass: T) -> &mut Self {
         self.graph.passes.insert(TypeId::of::<T>(), Box::new(pass));
@@ -443,9 +478,25 @@ impl Schedule {
 
         let error_handler = default_error_handler();
 
+        let mut profile = world
+            .get_resource_mut::<SystemProfiles>()
+            .map(|mut profiles| profiles.take(self.label));
+        if let Some(profile) = &mut profile {
+            profile.sync(&self.executable);
+        }
+        let mut skipped = world
+            .contains_resource::<SystemSkips>()
+            .then(SkippedSystems::new);
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        self.executor.run(
+            &mut self.executable,
+            world,
+            None,
+            error_handler,
+            profile.as_mut(),
+            skipped.as_mut(),
+        );
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
@@ -459,8 +510,66 @@ impl Schedule {
                 world,
                 skip_systems.as_ref(),
                 error_handler,
+                profile.as_mut(),
+                skipped.as_mut(),
             );
         }
+
+        if let Some(profile) = profile {
+            if let Some(mut profiles) = world.get_resource_mut::<SystemProfiles>() {
+                profiles.insert(self.label, profile);
+            }
+        }
+
+        if let Some(skipped) = skipped {
+            self.record_skips(world, skipped, error_handler);
+        }
+
+        world.trigger_resource_changes();
+    }
+
+    /// Records the systems that were skipped because of invalid parameters in [`SystemSkips`],
+    /// and handles the skips it selects as errors.
+    fn record_skips(
+        &self,
+        world: &mut World,
+        mut skipped: SkippedSystems,
+        error_handler: fn(BevyError, ErrorContext),
+    ) {
+        if skipped.is_empty() {
+            return;
+        }
+        // A system can be skipped by the conditions of several of its sets, but is only skipped
+        // once per run.
+        skipped.sort_by_key(|&(index, _)| index);
+        skipped.dedup_by_key(|&mut (index, _)| index);
+        let tick = world.change_tick();
+        let Some(mut skips) = world.get_resource_mut::<SystemSkips>() else {
+            return;
+        };
+        let mut errors = Vec::new();
+        for (index, reason) in skipped {
+            let system = &self.executable.systems[index];
+            if skips.is_error(&system.name(), &reason) {
+                errors.push((
+                    reason.clone(),
+                    ErrorContext::System {
+                        name: system.name(),
+                        last_run: system.get_last_run(),
+                    },
+                ));
+            }
+            skips.record(
+                self.label,
+                self.executable.system_ids[index],
+                system.name(),
+                reason,
+                tick,
+            );
+        }
+        for (error, context) in errors {
+            error_handler(error.into(), context);
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -566,10 +675,109 @@ impl Schedule {
         Ok(iter)
     }
 
+    /// Returns a [`ScheduleReport`] describing the systems and sets of this schedule, their
+    /// ordering dependencies and the ambiguities between systems, which can be exported to DOT
+    /// or JSON.
+    ///
+    /// Ambiguities are reported regardless of [`ScheduleBuildSettings::ambiguity_detection`],
+    /// but respect `ambiguous_with` and the ambiguities ignored in [`Schedules`].
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn report(
+        &self,
+        components: &Components,
+    ) -> Result<ScheduleReport, ScheduleNotInitialized> {
+        if !self.executor_initialized {
+            return Err(ScheduleNotInitialized);
+        }
+
+        let graph = &self.graph;
+        // Initialized systems are stored in the executable schedule, not in the graph.
+        let mut system_names: Vec<_> = graph
+            .systems
+            .iter()
+            .map(|node| node.get().map(|system| system.name().to_string()))
+            .collect();
+        for (id, system) in self
+            .executable
+            .system_ids
+            .iter()
+            .zip(&self.executable.systems)
+        {
+            system_names[id.index()] = Some(system.name().to_string());
+        }
+
+        let mut nodes: Vec<_> = system_names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| Some((NodeId::System(index), name.clone()?)))
+            .chain(
+                (0..graph.system_sets.len())
+                    .filter(|&index| {
+                        // Skip removed sets.
+                        graph.system_set_ids.get(&graph.system_sets[index].inner)
+                            == Some(&NodeId::Set(index))
+                    })
+                    .map(|index| {
+                        (
+                            NodeId::Set(index),
+                            graph.report_set_name(index, &system_names),
+                        )
+                    }),
+            )
+            .map(|(id, name)| {
+                let mut sets = Vec::new();
+                graph.traverse_sets_containing_node(id, &mut |set_id| {
+                    let is_new = !sets.contains(&set_id);
+                    if is_new {
+                        sets.push(set_id);
+                    }
+                    is_new
+                });
+                sets.sort();
+                NodeReport { id, name, sets }
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.id);
+
+        let mut dependencies: Vec<_> = graph.dependency.graph.all_edges().collect();
+        dependencies.sort();
+        let mut hierarchy: Vec<_> = graph.hierarchy.graph.all_edges().collect();
+        hierarchy.sort();
+
+        let mut ambiguities: Vec<_> = graph
+            .conflicting_systems
+            .iter()
+            .map(|(system_a, system_b, conflicts)| AmbiguityReport {
+                system_a: *system_a,
+                system_b: *system_b,
+                conflicts: conflicts
+                    .iter()
+                    .map(|&id| ConflictReport {
+                        id,
+                        name: components
+                            .get_name(id)
+                            .map_or_else(|| format!("{id:?}"), |name| name.to_string()),
+                    })
+                    .collect(),
+            })
+            .collect();
+        ambiguities.sort_by_key(|ambiguity| (ambiguity.system_a, ambiguity.system_b));
+
+        Ok(ScheduleReport {
+            label: format!("{:?}", self.label),
+            nodes,
+            dependencies,
+            hierarchy,
+            ambiguities,
+        })
+    }
+
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
-            self.graph.systems.len()
+            self.graph.systems().count()
         } else {
             self.executable.systems.len()
         }
@@ -1144,6 +1352,80 @@ impl ScheduleGraph {
         Ok(())
     }
 
+    /// Removes the systems in `set` from the graph, and returns their ids.
+    fn remove_systems_in_set(&mut self, set: InternedSystemSet) -> Vec<NodeId> {
+        let Some(&set_id) = self.system_set_ids.get(&set) else {
+            return Vec::new();
+        };
+
+        let mut systems = Vec::new();
+        let mut visited = <HashSet<_>>::default();
+        let mut stack = vec![set_id];
+        while let Some(id) = stack.pop() {
+            for child in self.hierarchy.graph.neighbors_directed(id, Outgoing) {
+                if !visited.insert(child) {
+                    continue;
+                }
+                if child.is_system() {
+                    systems.push(child);
+                } else {
+                    stack.push(child);
+                }
+            }
+        }
+
+        for &id in &systems {
+            self.remove_node(id);
+            self.systems[id.index()].inner = None;
+            self.system_conditions[id.index()] = Vec::new();
+        }
+        systems
+    }
+
+    /// Removes `set` from the graph, and returns whether it was in the graph.
+    fn remove_set(&mut self, set: InternedSystemSet) -> bool {
+        let Some(id) = self.system_set_ids.remove(&set) else {
+            return false;
+        };
+
+        self.remove_node(id);
+        self.system_set_conditions[id.index()] = Vec::new();
+        self.changed = true;
+        true
+    }
+
+    /// Removes a node from the hierarchy, dependency and ambiguity graphs.
+    ///
+    /// The nodes ordered before it are ordered before the nodes ordered after it, so the
+    /// remaining nodes keep their order and the graphs stay valid.
+    fn remove_node(&mut self, id: NodeId) {
+        let before: Vec<_> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Incoming)
+            .collect();
+        let after: Vec<_> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Outgoing)
+            .collect();
+        for &a in &before {
+            for &b in &after {
+                self.dependency.graph.add_edge(a, b);
+            }
+        }
+
+        self.hierarchy.graph.remove_node(id);
+        self.hierarchy.topsort.retain(|&node| node != id);
+        self.dependency.graph.remove_node(id);
+        self.dependency.topsort.retain(|&node| node != id);
+        self.ambiguous_with.remove_node(id);
+        self.ambiguous_with_all.remove(&id);
+        self.conflicting_systems
+            .retain(|&(a, b, _)| a != id && b != id);
+        self.uninit.retain(|&(node, _)| node != id);
+    }
+
     /// Initializes any newly-added systems and conditions by calling [`System::initialize`](crate::system::System)
     pub fn initialize(&mut self, world: &mut World) {
         for (id, i) in self.uninit.drain(..) {
@@ -1525,7 +1807,10 @@ impl ScheduleGraph {
             .drain(..)
             .zip(schedule.set_conditions.drain(..))
         {
-            self.system_set_conditions[id.index()] = conditions;
+            // Drop the conditions of sets that have been removed.
+            if self.hierarchy.graph.contains_node(id) {
+                self.system_set_conditions[id.index()] = conditions;
+            }
         }
 
         *schedule = self.build_schedule(world, schedule_label, ignored_ambiguities)?;
@@ -1922,6 +2207,25 @@ impl ScheduleGraph {
             })
     }
 
+    /// Returns the full name of a set for a [`ScheduleReport`], where `system_names` contains the
+    /// names of all systems, including the ones that were moved to the executable schedule.
+    fn report_set_name(&self, index: usize, system_names: &[Option<String>]) -> String {
+        let set = &self.system_sets[index];
+        if !set.is_anonymous() {
+            return set.name();
+        }
+        let members: Vec<_> = self
+            .hierarchy
+            .graph
+            .edges_directed(NodeId::Set(index), Outgoing)
+            .map(|(_, member_id)| match member_id {
+                NodeId::System(index) => system_names[index].clone().unwrap_or_default(),
+                NodeId::Set(index) => self.report_set_name(index, system_names),
+            })
+            .collect();
+        format!("({})", members.join(", "))
+    }
+
     fn traverse_sets_containing_node(&self, id: NodeId, f: &mut impl FnMut(NodeId) -> bool) {
         for (set_id, _) in self.hierarchy.graph.edges_directed(id, Incoming) {
             if f(set_id) {
@@ -2057,12 +2361,13 @@ pub struct ScheduleNotInitialized;
 
 #[cfg(test)]
 mod tests {
+    use alloc::{vec, vec::Vec};
     use bevy_ecs_macros::ScheduleLabel;
 
     use crate::{
         prelude::{ApplyDeferred, Res, Resource},
         schedule::{
-            tests::ResMut, IntoScheduleConfigs, Schedule, ScheduleBuildSettings, SystemSet,
+            tests::ResMut, IntoScheduleConfigs, NodeId, Schedule, ScheduleBuildSettings, SystemSet,
         },
         system::Commands,
         world::World,
@@ -2809,4 +3114,108 @@ mod tests {
             .expect("CheckSystemRan Resource Should Exist");
         assert_eq!(value.0, 2);
     }
+
+    #[derive(Resource, Default)]
+    struct RunOrder(Vec<u32>);
+
+    fn first(mut order: ResMut<RunOrder>) {
+        order.0.push(1);
+    }
+
+    fn second(mut order: ResMut<RunOrder>) {
+        order.0.push(2);
+    }
+
+    fn third(mut order: ResMut<RunOrder>) {
+        order.0.push(3);
+    }
+
+    fn fourth(mut order: ResMut<RunOrder>) {
+        order.0.push(4);
+    }
+
+    #[test]
+    fn remove_systems_keeps_order() {
+        let mut world = World::new();
+        world.init_resource::<RunOrder>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((first, second, third).chain());
+        schedule.run(&mut world);
+
+        assert_eq!(schedule.remove_systems_in_set(second), 1);
+        assert_eq!(schedule.systems_len(), 2);
+
+        // `first` still runs before `third`, without rebuilding the schedule.
+        assert!(!schedule.graph().changed);
+        let executable = schedule.executable();
+        assert_eq!(executable.system_dependencies, vec![0, 1]);
+        assert_eq!(executable.system_dependents, vec![vec![1], vec![]]);
+        world.resource_mut::<RunOrder>().0.clear();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RunOrder>().0, vec![1, 3]);
+
+        // The order is also kept when the schedule is rebuilt.
+        schedule.add_systems(fourth);
+        schedule.initialize(&mut world).unwrap();
+        assert!(schedule
+            .graph()
+            .dependency()
+            .graph()
+            .contains_edge(NodeId::System(0), NodeId::System(2)));
+    }
+
+    #[test]
+    fn remove_systems_in_set() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Removed;
+
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Skipped;
+
+        let mut world = World::new();
+        world.init_resource::<RunOrder>();
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Skipped.run_if(|| false));
+        schedule.add_systems(
+            (
+                first.in_set(Removed),
+                second.in_set(Skipped),
+                third.in_set(Removed).in_set(Skipped),
+                fourth,
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RunOrder>().0, vec![1, 4]);
+
+        assert_eq!(schedule.remove_systems_in_set(Removed), 2);
+        assert_eq!(schedule.remove_systems_in_set(Removed), 0);
+        assert!(schedule.graph().contains_set(Removed));
+
+        world.resource_mut::<RunOrder>().0.clear();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RunOrder>().0, vec![4]);
+    }
+
+    #[test]
+    fn remove_set() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Skipped;
+
+        let mut world = World::new();
+        world.init_resource::<RunOrder>();
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Skipped.run_if(|| false));
+        schedule.add_systems((first.in_set(Skipped), second).chain());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RunOrder>().0, vec![2]);
+
+        assert!(schedule.remove_set(Skipped));
+        assert!(!schedule.remove_set(Skipped));
+        assert!(!schedule.graph().contains_set(Skipped));
+
+        world.resource_mut::<RunOrder>().0.clear();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RunOrder>().0, vec![1, 2]);
+    }
 }
@@ -0,0 +1,294 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::{any::type_name, fmt};
+
+use crate::{
+    component::Tick,
+    resource::Resource,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleLabel},
+    system::{SystemParam, SystemParamValidationError},
+};
+
+/// The systems whose parameters, or the parameters of whose run conditions, failed validation
+/// during a single run of a schedule, by their index in the [`SystemSchedule`](super::SystemSchedule).
+pub(super) type SkippedSystems = Vec<(usize, SystemParamValidationError)>;
+
+type SkipFilter = Box<dyn Fn(&str, &SystemParamValidationError) -> bool + Send + Sync>;
+
+/// Resource that records the systems that were skipped because their parameters failed
+/// [validation](SystemParam::validate_param), for each [`Schedule`](super::Schedule) that runs
+/// while it exists in the [`World`](crate::world::World).
+///
+/// Systems are also recorded when a run condition of the system or of one of its sets is skipped
+/// because of its parameters, with the validation error of the condition as the reason.
+///
+/// Recording is enabled by inserting this resource, and has no cost while it is absent.
+///
+/// Parameters such as [`Single`](crate::system::Single) skip their system without reporting an
+/// error. Skips selected with [`SystemSkips::error_on_skip`] or [`SystemSkips::error_on_param`]
+/// are additionally handled as errors by the [error handler](crate::error) once the schedule
+/// has run, so that missing data doesn't go unnoticed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ScheduleLabel, SystemSkips};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemSkips>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems(|_player: Single<&Player>| {});
+/// schedule.run(&mut world);
+///
+/// let skips = world.resource::<SystemSkips>();
+/// for system in skips.get(Update).unwrap().systems() {
+///     println!("{} was skipped {} times: {}", system.name, system.count, system.reason);
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct SystemSkips {
+    schedules: HashMap<InternedScheduleLabel, ScheduleSkips>,
+    errors: Vec<SkipFilter>,
+}
+
+impl SystemSkips {
+    /// Creates an empty [`SystemSkips`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the skips of the schedule with the given `label`, if any of its systems was
+    /// skipped since recording started.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleSkips> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the skips of all schedules. Iteration order is undefined.
+    pub fn iter(&self) -> impl Iterator<Item = (&dyn ScheduleLabel, &ScheduleSkips)> {
+        self.schedules
+            .iter()
+            .map(|(label, skips)| (&**label, skips))
+    }
+
+    /// Discards all skips recorded so far.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Handles the skips for which `filter` returns `true` as errors. The filter is called with
+    /// the name of the skipped system and the validation error of its parameter.
+    pub fn error_on_skip(
+        &mut self,
+        filter: impl Fn(&str, &SystemParamValidationError) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.errors.push(Box::new(filter));
+        self
+    }
+
+    /// Handles the skips caused by the system parameter `P` as errors, for example
+    /// `Single<&Player>`.
+    pub fn error_on_param<P: SystemParam>(&mut self) -> &mut Self {
+        self.error_on_skip(|_, error| error.param == type_name::<P>())
+    }
+
+    /// Returns `true` if the skip of the system `name` caused by `error` should be handled as an
+    /// error.
+    pub(super) fn is_error(&self, name: &str, error: &SystemParamValidationError) -> bool {
+        error.skipped && self.errors.iter().any(|filter| filter(name, error))
+    }
+
+    /// Records that the system `id` of the schedule `label` was skipped at `tick`.
+    pub(super) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        id: NodeId,
+        name: Cow<'static, str>,
+        reason: SystemParamValidationError,
+        tick: Tick,
+    ) {
+        let systems = &mut self.schedules.entry(label).or_default().systems;
+        match systems.iter_mut().find(|system| system.id == id) {
+            Some(system) => {
+                system.count += 1;
+                system.last_tick = tick;
+                system.reason = reason;
+            }
+            None => systems.push(SystemSkip {
+                id,
+                name,
+                count: 1,
+                last_tick: tick,
+                reason,
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for SystemSkips {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemSkips")
+            .field("schedules", &self.schedules)
+            .field("errors", &self.errors.len())
+            .finish()
+    }
+}
+
+/// The systems of a single [`Schedule`](super::Schedule) that were skipped.
+#[derive(Default, Debug, Clone)]
+pub struct ScheduleSkips {
+    systems: Vec<SystemSkip>,
+}
+
+impl ScheduleSkips {
+    /// Returns the skipped systems, in the order in which they were first skipped.
+    pub fn systems(&self) -> &[SystemSkip] {
+        &self.systems
+    }
+
+    /// Returns the skips of the system with the given `id`, if it was skipped.
+    pub fn get(&self, id: NodeId) -> Option<&SystemSkip> {
+        self.systems.iter().find(|system| system.id == id)
+    }
+}
+
+/// The skips recorded for a single system.
+#[derive(Debug, Clone)]
+pub struct SystemSkip {
+    /// The id of the system in its schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The number of times the system was skipped.
+    pub count: u32,
+    /// The change tick of the world when the system was last skipped.
+    pub last_tick: Tick,
+    /// Why the system was last skipped.
+    pub reason: SystemParamValidationError,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, SystemSkips},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(Component)]
+    struct Player;
+
+    #[derive(Resource, Default)]
+    struct R;
+
+    fn needs_player(_: Single<&Player>) {}
+
+    fn needs_resource(_: Option<Res<R>>) {}
+
+    #[test]
+    fn record_skips() {
+        for kind in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            world.init_resource::<SystemSkips>();
+
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.set_executor_kind(kind);
+            schedule.add_systems((needs_player, needs_resource));
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let skips = world.resource::<SystemSkips>().get(TestSchedule).unwrap();
+            assert_eq!(skips.systems().len(), 1);
+            let skip = &skips.systems()[0];
+            assert!(skip.name.ends_with("needs_player"));
+            assert_eq!(skip.count, 2);
+            assert!(skip.reason.skipped);
+            assert!(skip.reason.param.contains("Single"));
+            assert_eq!(skips.get(skip.id).unwrap().count, 2);
+
+            world.spawn(Player);
+            schedule.run(&mut world);
+            let skips = world.resource::<SystemSkips>().get(TestSchedule).unwrap();
+            assert_eq!(skips.systems()[0].count, 2);
+        }
+    }
+
+    #[test]
+    fn record_condition_skips() {
+        #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn has_player(_: Single<&Player>) -> bool {
+            true
+        }
+
+        fn in_set() {}
+
+        for kind in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            world.init_resource::<SystemSkips>();
+
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.set_executor_kind(kind);
+            schedule.configure_sets(Set.run_if(has_player));
+            schedule.add_systems((
+                needs_resource.run_if(has_player),
+                (in_set, needs_player).in_set(Set),
+                in_set.in_set(Set).run_if(has_player),
+            ));
+            schedule.run(&mut world);
+
+            let skips = world.resource::<SystemSkips>().get(TestSchedule).unwrap();
+            assert_eq!(skips.systems().len(), 4);
+            for skip in skips.systems() {
+                assert_eq!(skip.count, 1);
+                assert!(skip.reason.param.contains("Single"));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "needs_player")]
+    fn skip_as_error() {
+        let mut world = World::new();
+        world.init_resource::<SystemSkips>();
+        world
+            .resource_mut::<SystemSkips>()
+            .error_on_param::<Single<&Player>>();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(needs_player);
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn skip_filter() {
+        let mut world = World::new();
+        world.init_resource::<SystemSkips>();
+        world
+            .resource_mut::<SystemSkips>()
+            .error_on_skip(|name, _| name.ends_with("other_system"));
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(needs_player);
+        // Doesn't panic, since the filter doesn't match.
+        schedule.run(&mut world);
+        let skips = world.resource::<SystemSkips>().get(TestSchedule).unwrap();
+        assert_eq!(skips.systems()[0].count, 1);
+    }
+}
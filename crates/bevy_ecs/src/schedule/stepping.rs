@@ -158,6 +158,27 @@ impl Stepping {
         }
     }
 
+    /// Check if stepping is enabled for the provided schedule
+    ///
+    /// NOTE: Schedules added with [`Stepping::add_schedule`] are only included
+    /// from the start of the next render frame.
+    pub fn contains_schedule(&self, schedule: impl ScheduleLabel) -> bool {
+        self.schedule_states.contains_key(&schedule.intern())
+    }
+
+    /// Return the system instances with a breakpoint in the provided schedule
+    ///
+    /// NOTE: Breakpoints set with [`Stepping::set_breakpoint`] are only included
+    /// once the schedule has run with stepping enabled.
+    pub fn breakpoints(&self, schedule: impl ScheduleLabel) -> impl Iterator<Item = NodeId> + '_ {
+        self.schedule_states
+            .get(&schedule.intern())
+            .into_iter()
+            .flat_map(|state| state.behaviors.iter())
+            .filter(|(_, behavior)| matches!(behavior, SystemBehavior::Break))
+            .map(|(node_id, _)| *node_id)
+    }
+
     /// Return our current position within the stepping frame
     ///
     /// NOTE: This function **will** return `None` during normal execution with
@@ -1190,6 +1211,36 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping, first_system, second_system);
     }
 
+    #[test]
+    fn breakpoints() {
+        let (schedule, _world) = setup();
+        let second = schedule
+            .systems()
+            .unwrap()
+            .find(|(_, system)| system.name().ends_with("second_system"))
+            .unwrap()
+            .0;
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .set_breakpoint_node(TestSchedule, second);
+        assert!(!stepping.contains_schedule(TestSchedule));
+
+        stepping.next_frame();
+        assert!(stepping.contains_schedule(TestSchedule));
+        assert!(!stepping.contains_schedule(TestScheduleA));
+        assert_eq!(
+            stepping.breakpoints(TestSchedule).collect::<Vec<_>>(),
+            [second]
+        );
+
+        stepping
+            .clear_breakpoint_node(TestSchedule, second)
+            .next_frame();
+        assert_eq!(stepping.breakpoints(TestSchedule).count(), 0);
+    }
+
     #[test]
     fn clear_system() {
         let (schedule, _world) = setup();
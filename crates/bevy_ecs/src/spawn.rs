@@ -3,8 +3,9 @@
 
 use crate::{
     bundle::{Bundle, BundleEffect, DynamicBundle, NoBundleEffect},
+    component::Component,
     entity::Entity,
-    relationship::{RelatedSpawner, Relationship, RelationshipTarget},
+    relationship::{RelatedSpawner, RelationshipSource, RelationshipTarget},
     world::{EntityWorldMut, World},
 };
 use alloc::vec::Vec;
@@ -46,9 +47,9 @@ pub trait SpawnableList<R> {
     fn size_hint(&self) -> usize;
 }
 
-impl<R: Relationship, B: Bundle<Effect: NoBundleEffect>> SpawnableList<R> for Vec<B> {
+impl<R: RelationshipSource, B: Bundle<Effect: NoBundleEffect>> SpawnableList<R> for Vec<B> {
     fn spawn(self, world: &mut World, entity: Entity) {
-        let mapped_bundles = self.into_iter().map(|b| (R::from(entity), b));
+        let mapped_bundles = self.into_iter().map(|b| (R::from_target(entity), b));
         world.spawn_batch(mapped_bundles);
     }
 
@@ -57,9 +58,9 @@ impl<R: Relationship, B: Bundle<Effect: NoBundleEffect>> SpawnableList<R> for Ve
     }
 }
 
-impl<R: Relationship, B: Bundle> SpawnableList<R> for Spawn<B> {
+impl<R: RelationshipSource, B: Bundle> SpawnableList<R> for Spawn<B> {
     fn spawn(self, world: &mut World, entity: Entity) {
-        world.spawn((R::from(entity), self.0));
+        world.spawn((R::from_target(entity), self.0));
     }
 
     fn size_hint(&self) -> usize {
@@ -85,12 +86,12 @@ impl<R: Relationship, B: Bundle> SpawnableList<R> for Spawn<B> {
 /// ```
 pub struct SpawnIter<I>(pub I);
 
-impl<R: Relationship, I: Iterator<Item = B> + Send + Sync + 'static, B: Bundle> SpawnableList<R>
-    for SpawnIter<I>
+impl<R: RelationshipSource, I: Iterator<Item = B> + Send + Sync + 'static, B: Bundle>
+    SpawnableList<R> for SpawnIter<I>
 {
     fn spawn(self, world: &mut World, entity: Entity) {
         for bundle in self.0 {
-            world.spawn((R::from(entity), bundle));
+            world.spawn((R::from_target(entity), bundle));
         }
     }
 
@@ -121,8 +122,8 @@ impl<R: Relationship, I: Iterator<Item = B> + Send + Sync + 'static, B: Bundle>
 /// ```
 pub struct SpawnWith<F>(pub F);
 
-impl<R: Relationship, F: FnOnce(&mut RelatedSpawner<R>) + Send + Sync + 'static> SpawnableList<R>
-    for SpawnWith<F>
+impl<R: RelationshipSource, F: FnOnce(&mut RelatedSpawner<R>) + Send + Sync + 'static>
+    SpawnableList<R> for SpawnWith<F>
 {
     fn spawn(self, world: &mut World, entity: Entity) {
         world.entity_mut(entity).with_related(self.0);
@@ -139,7 +140,7 @@ macro_rules! spawnable_list_impl {
             clippy::allow_attributes,
             reason = "This is a tuple-related macro; as such, the lints below may not always apply."
         )]
-        impl<R: Relationship, $($list: SpawnableList<R>),*> SpawnableList<R> for ($($list,)*) {
+        impl<R: RelationshipSource, $($list: SpawnableList<R>),*> SpawnableList<R> for ($($list,)*) {
             fn spawn(self, _world: &mut World, _entity: Entity) {
                 #[allow(
                     non_snake_case,
@@ -164,16 +165,16 @@ macro_rules! spawnable_list_impl {
 all_tuples!(spawnable_list_impl, 0, 12, P);
 
 /// A [`Bundle`] that:
-/// 1. Contains a [`RelationshipTarget`] component (associated with the given [`Relationship`]). This reserves space for the [`SpawnableList`].
-/// 2. Spawns a [`SpawnableList`] of related entities with a given [`Relationship`].
+/// 1. Contains a [`RelationshipTarget`] component (associated with the given [`RelationshipSource`]). This reserves space for the [`SpawnableList`].
+/// 2. Spawns a [`SpawnableList`] of related entities with a given [`RelationshipSource`].
 ///
 /// This is intended to be created using [`SpawnRelated`].
-pub struct SpawnRelatedBundle<R: Relationship, L: SpawnableList<R>> {
+pub struct SpawnRelatedBundle<R: RelationshipSource, L: SpawnableList<R>> {
     list: L,
     marker: PhantomData<R>,
 }
 
-impl<R: Relationship, L: SpawnableList<R>> BundleEffect for SpawnRelatedBundle<R, L> {
+impl<R: RelationshipSource, L: SpawnableList<R>> BundleEffect for SpawnRelatedBundle<R, L> {
     fn apply(self, entity: &mut EntityWorldMut) {
         let id = entity.id();
         entity.world_scope(|world: &mut World| {
@@ -183,57 +184,53 @@ impl<R: Relationship, L: SpawnableList<R>> BundleEffect for SpawnRelatedBundle<R
 }
 
 // SAFETY: This internally relies on the RelationshipTarget's Bundle implementation, which is sound.
-unsafe impl<R: Relationship, L: SpawnableList<R> + Send + Sync + 'static> Bundle
+unsafe impl<R: RelationshipSource, L: SpawnableList<R> + Send + Sync + 'static> Bundle
     for SpawnRelatedBundle<R, L>
 {
     fn component_ids(
         components: &mut crate::component::ComponentsRegistrator,
         ids: &mut impl FnMut(crate::component::ComponentId),
     ) {
-        <R::RelationshipTarget as Bundle>::component_ids(components, ids);
+        <R::Target as Bundle>::component_ids(components, ids);
     }
 
     fn get_component_ids(
         components: &crate::component::Components,
         ids: &mut impl FnMut(Option<crate::component::ComponentId>),
     ) {
-        <R::RelationshipTarget as Bundle>::get_component_ids(components, ids);
+        <R::Target as Bundle>::get_component_ids(components, ids);
     }
 
     fn register_required_components(
         components: &mut crate::component::ComponentsRegistrator,
         required_components: &mut crate::component::RequiredComponents,
     ) {
-        <R::RelationshipTarget as Bundle>::register_required_components(
-            components,
-            required_components,
-        );
+        <R::Target as Bundle>::register_required_components(components, required_components);
     }
 }
-impl<R: Relationship, L: SpawnableList<R>> DynamicBundle for SpawnRelatedBundle<R, L> {
+impl<R: RelationshipSource, L: SpawnableList<R>> DynamicBundle for SpawnRelatedBundle<R, L> {
     type Effect = Self;
 
     fn get_components(
         self,
         func: &mut impl FnMut(crate::component::StorageType, bevy_ptr::OwningPtr<'_>),
     ) -> Self::Effect {
-        <R::RelationshipTarget as RelationshipTarget>::with_capacity(self.list.size_hint())
-            .get_components(func);
+        R::target_with_capacity(self.list.size_hint()).get_components(func);
         self
     }
 }
 
 /// A [`Bundle`] that:
-/// 1. Contains a [`RelationshipTarget`] component (associated with the given [`Relationship`]). This reserves space for a single entity.
-/// 2. Spawns a single related entity containing the given `B` [`Bundle`] and the given [`Relationship`].
+/// 1. Contains a [`RelationshipTarget`] component (associated with the given [`RelationshipSource`]). This reserves space for a single entity.
+/// 2. Spawns a single related entity containing the given `B` [`Bundle`] and the given [`RelationshipSource`].
 ///
 /// This is intended to be created using [`SpawnRelated`].
-pub struct SpawnOneRelated<R: Relationship, B: Bundle> {
+pub struct SpawnOneRelated<R: RelationshipSource, B: Bundle> {
     bundle: B,
     marker: PhantomData<R>,
 }
 
-impl<R: Relationship, B: Bundle> BundleEffect for SpawnOneRelated<R, B> {
+impl<R: RelationshipSource, B: Bundle> BundleEffect for SpawnOneRelated<R, B> {
     fn apply(self, entity: &mut EntityWorldMut) {
         entity.with_related::<R>(|s| {
             s.spawn(self.bundle);
@@ -241,60 +238,68 @@ impl<R: Relationship, B: Bundle> BundleEffect for SpawnOneRelated<R, B> {
     }
 }
 
-impl<R: Relationship, B: Bundle> DynamicBundle for SpawnOneRelated<R, B> {
+impl<R: RelationshipSource, B: Bundle> DynamicBundle for SpawnOneRelated<R, B> {
     type Effect = Self;
 
     fn get_components(
         self,
         func: &mut impl FnMut(crate::component::StorageType, bevy_ptr::OwningPtr<'_>),
     ) -> Self::Effect {
-        <R::RelationshipTarget as RelationshipTarget>::with_capacity(1).get_components(func);
+        R::target_with_capacity(1).get_components(func);
         self
     }
 }
 
 // SAFETY: This internally relies on the RelationshipTarget's Bundle implementation, which is sound.
-unsafe impl<R: Relationship, B: Bundle> Bundle for SpawnOneRelated<R, B> {
+unsafe impl<R: RelationshipSource, B: Bundle> Bundle for SpawnOneRelated<R, B> {
     fn component_ids(
         components: &mut crate::component::ComponentsRegistrator,
         ids: &mut impl FnMut(crate::component::ComponentId),
     ) {
-        <R::RelationshipTarget as Bundle>::component_ids(components, ids);
+        <R::Target as Bundle>::component_ids(components, ids);
     }
 
     fn get_component_ids(
         components: &crate::component::Components,
         ids: &mut impl FnMut(Option<crate::component::ComponentId>),
     ) {
-        <R::RelationshipTarget as Bundle>::get_component_ids(components, ids);
+        <R::Target as Bundle>::get_component_ids(components, ids);
     }
 
     fn register_required_components(
         components: &mut crate::component::ComponentsRegistrator,
         required_components: &mut crate::component::RequiredComponents,
     ) {
-        <R::RelationshipTarget as Bundle>::register_required_components(
-            components,
-            required_components,
-        );
+        <R::Target as Bundle>::register_required_components(components, required_components);
     }
 }
 
 /// [`RelationshipTarget`] methods that create a [`Bundle`] with a [`DynamicBundle::Effect`] that:
 ///
 /// 1. Contains the [`RelationshipTarget`] component, pre-allocated with the necessary space for spawned entities.
-/// 2. Spawns an entity (or a list of entities) that relate to the entity the [`Bundle`] is added to via the [`RelationshipTarget::Relationship`].
-pub trait SpawnRelated: RelationshipTarget {
+/// 2. Spawns an entity (or a list of entities) that relate to the entity the [`Bundle`] is added to via the [`SpawnRelated::Relationship`].
+///
+/// This is implemented for every [`RelationshipTarget`], and for every [`ManyRelationshipTarget`](crate::relationship::ManyRelationshipTarget)
+/// when derived.
+pub trait SpawnRelated: Component + Sized {
+    /// The [`RelationshipSource`] component inserted on spawned entities to relate them to the bundle's entity.
+    type Relationship: RelationshipSource<Target = Self>;
+
     /// Returns a [`Bundle`] containing this [`RelationshipTarget`] component. It also spawns a [`SpawnableList`] of entities, each related to the bundle's entity
-    /// via [`RelationshipTarget::Relationship`]. The [`RelationshipTarget`] (when possible) will pre-allocate space for the related entities.
+    /// via [`SpawnRelated::Relationship`]. The [`RelationshipTarget`] (when possible) will pre-allocate space for the related entities.
     ///
     /// See [`Spawn`], [`SpawnIter`], and [`SpawnWith`] for usage examples.
     fn spawn<L: SpawnableList<Self::Relationship>>(
         list: L,
-    ) -> SpawnRelatedBundle<Self::Relationship, L>;
+    ) -> SpawnRelatedBundle<Self::Relationship, L> {
+        SpawnRelatedBundle {
+            list,
+            marker: PhantomData,
+        }
+    }
 
     /// Returns a [`Bundle`] containing this [`RelationshipTarget`] component. It also spawns a single entity containing [`Bundle`] that is related to the bundle's entity
-    /// via [`RelationshipTarget::Relationship`].
+    /// via [`SpawnRelated::Relationship`].
     ///
     /// ```
     /// # use bevy_ecs::hierarchy::Children;
@@ -307,19 +312,6 @@ pub trait SpawnRelated: RelationshipTarget {
     ///     Children::spawn_one(Name::new("Child")),
     /// ));
     /// ```
-    fn spawn_one<B: Bundle>(bundle: B) -> SpawnOneRelated<Self::Relationship, B>;
-}
-
-impl<T: RelationshipTarget> SpawnRelated for T {
-    fn spawn<L: SpawnableList<Self::Relationship>>(
-        list: L,
-    ) -> SpawnRelatedBundle<Self::Relationship, L> {
-        SpawnRelatedBundle {
-            list,
-            marker: PhantomData,
-        }
-    }
-
     fn spawn_one<B: Bundle>(bundle: B) -> SpawnOneRelated<Self::Relationship, B> {
         SpawnOneRelated {
             bundle,
@@ -328,6 +320,10 @@ impl<T: RelationshipTarget> SpawnRelated for T {
     }
 }
 
+impl<T: RelationshipTarget> SpawnRelated for T {
+    type Relationship = T::Relationship;
+}
+
 /// Returns a [`SpawnRelatedBundle`] that will insert the given [`RelationshipTarget`], spawn a [`SpawnableList`] of entities with given bundles that
 /// relate to the [`RelationshipTarget`] entity via the [`RelationshipTarget::Relationship`] component, and reserve space in the [`RelationshipTarget`] for each spawned entity.
 ///
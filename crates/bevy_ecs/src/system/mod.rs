@@ -337,8 +337,9 @@ mod tests {
         component::{Component, Components},
         entity::{Entities, Entity},
         error::Result,
+        hierarchy::ChildOf,
         prelude::{AnyOf, EntityRef},
-        query::{Added, Changed, Or, With, Without},
+        query::{Added, Changed, Or, Related, With, Without},
         removal_detection::RemovedComponents,
         resource::Resource,
         schedule::{
@@ -662,6 +663,33 @@ mod tests {
         run_system(&mut world, sys);
     }
 
+    #[test]
+    #[should_panic = "error[B0001]"]
+    fn related_has_no_filter_with() {
+        fn sys(
+            _: Query<&mut A, With<B>>,
+            _: Query<Entity, (Without<B>, Related<ChildOf, Changed<A>>)>,
+        ) {
+        }
+        let mut world = World::default();
+        run_system(&mut world, sys);
+    }
+
+    #[test]
+    fn related_archetypal_filter_has_no_access() {
+        fn sys(_: Query<&mut B>, _: Query<&A, Related<ChildOf, With<B>>>) {}
+        let mut world = World::default();
+        run_system(&mut world, sys);
+    }
+
+    #[test]
+    #[should_panic = "Components read from related entities cannot be accessed mutably."]
+    fn related_with_mut_in_same_query() {
+        fn sys(_: Query<&mut A, Related<ChildOf, Changed<A>>>) {}
+        let mut world = World::default();
+        run_system(&mut world, sys);
+    }
+
     #[test]
     fn or_expanded_with_and_without_common() {
         fn sys(_: Query<&mut D, (With<A>, Or<(With<B>, With<C>)>)>, _: Query<&mut D, Without<A>>) {}
@@ -363,6 +363,7 @@ unsafe impl<D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for Qu
         world: UnsafeWorldCell<'w>,
         change_tick: Tick,
     ) -> Self::Item<'w, 's> {
+        state.update_changed_entities(world, system_meta.last_run, change_tick);
         // SAFETY: We have registered all of the query's world accesses,
         // so the caller ensures that `world` has permission to access any
         // world data that the query needs.
@@ -1039,6 +1040,7 @@ unsafe impl<'a, T: Resource> SystemParam for ResMut<'a, T> {
                 changed: value.ticks.changed,
                 last_run: system_meta.last_run,
                 this_run: change_tick,
+                changed_entities: None,
             },
             changed_by: value.changed_by,
         }
@@ -1070,6 +1072,7 @@ unsafe impl<'a, T: Resource> SystemParam for Option<ResMut<'a, T>> {
                     changed: value.ticks.changed,
                     last_run: system_meta.last_run,
                     this_run: change_tick,
+                    changed_entities: None,
                 },
                 changed_by: value.changed_by,
             })
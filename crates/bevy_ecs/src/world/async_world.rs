@@ -0,0 +1,375 @@
+//! Running async code that accesses a [`World`] with [`AsyncWorld`].
+
+use crate::{
+    component::Component,
+    error::HandleError,
+    resource::Resource,
+    system::{Command, Commands, EntityCommands},
+    world::{EntityWorldMut, World},
+};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform_support::sync::{Arc, Mutex, PoisonError};
+use bevy_tasks::{AsyncComputeTaskPool, Task, TaskPool};
+use concurrent_queue::ConcurrentQueue;
+use core::{
+    future::{poll_fn, Future},
+    task::{Poll, Waker},
+};
+
+type AsyncCommand = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle that async code uses to access a [`World`].
+///
+/// Async code can't borrow the world across an `.await`, so it sends work to the world instead:
+/// [`queue`](Self::queue) queues a [`Command`], and [`run`](Self::run) runs a closure with
+/// exclusive access to the world and returns a future of its result. Both are applied at the next
+/// sync point, when [`World::apply_async_commands`] runs. `bevy_app` runs it once per frame, at
+/// the start of the `Main` schedule.
+///
+/// Handles are obtained with [`World::async_world`], or passed to the tasks spawned with
+/// [`World::spawn_task`], [`Commands::spawn_task`] and [`EntityCommands::spawn_task`]. Handles
+/// can be cloned and sent to other threads. Commands sent to a world that has been dropped are
+/// never applied.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(0));
+/// world.spawn_task(|world| async move {
+///     let score = world.run(|world: &mut World| world.resource::<Score>().0).await;
+///     world.queue(move |world: &mut World| world.insert_resource(Score(score + 1)));
+/// });
+/// ```
+#[derive(Clone)]
+pub struct AsyncWorld {
+    queue: Arc<ConcurrentQueue<AsyncCommand>>,
+}
+
+impl AsyncWorld {
+    fn new() -> Self {
+        Self {
+            queue: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+
+    /// Queues `command` to be applied to the world at the next sync point.
+    ///
+    /// If the command returns an error, it is handled by the default error handler, like the
+    /// commands queued with [`Commands::queue`].
+    pub fn queue<C: Command<T> + HandleError<T>, T>(&self, command: C) {
+        let command = command.handle_error();
+        self.push(Box::new(move |world: &mut World| command.apply(world)));
+    }
+
+    /// Runs `f` with exclusive access to the world at the next sync point, and returns a future
+    /// that resolves to its result.
+    ///
+    /// The closure is queued when this method is called, not when the future is first polled.
+    /// If the world is dropped before the closure runs, the future never resolves.
+    pub fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> impl Future<Output = R> + Send + 'static {
+        let slot = Arc::new(Mutex::new(ResultSlot {
+            value: None,
+            waker: None,
+        }));
+
+        let sender = slot.clone();
+        self.push(Box::new(move |world: &mut World| {
+            let value = f(world);
+            let mut slot = sender.lock().unwrap_or_else(PoisonError::into_inner);
+            slot.value = Some(value);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        }));
+
+        poll_fn(move |cx| {
+            let mut slot = slot.lock().unwrap_or_else(PoisonError::into_inner);
+            match slot.value.take() {
+                Some(value) => Poll::Ready(value),
+                None => {
+                    slot.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+    }
+
+    fn push(&self, command: AsyncCommand) {
+        // The queue is unbounded and never closed, so pushing can't fail.
+        self.queue.push(command).ok();
+    }
+
+    fn spawn<F, Fut>(&self, task: F) -> Task<()>
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(task(self.clone()))
+    }
+}
+
+/// The result of an [`AsyncWorld::run`] closure, and the task waiting for it.
+struct ResultSlot<R> {
+    value: Option<R>,
+    waker: Option<Waker>,
+}
+
+/// The [`AsyncWorld`] of a [`World`], and the tasks spawned with [`World::spawn_task`] and
+/// [`Commands::spawn_task`].
+#[derive(Resource)]
+struct AsyncWorldTasks {
+    world: AsyncWorld,
+    tasks: Vec<Task<()>>,
+}
+
+impl Default for AsyncWorldTasks {
+    fn default() -> Self {
+        Self {
+            world: AsyncWorld::new(),
+            tasks: Vec::new(),
+        }
+    }
+}
+
+/// The unfinished tasks spawned on an entity with [`EntityCommands::spawn_task`] or
+/// [`EntityWorldMut::spawn_task`].
+///
+/// The tasks are cancelled when this component is removed, including when the entity is
+/// despawned.
+#[derive(Component, Default)]
+#[component(storage = "SparseSet")]
+pub struct EntityTasks {
+    tasks: Vec<Task<()>>,
+}
+
+impl EntityTasks {
+    /// Returns the number of unfinished tasks, as of the last sync point.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if all tasks had finished as of the last sync point.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+impl World {
+    /// Returns the [`AsyncWorld`] handle of this world.
+    pub fn async_world(&mut self) -> AsyncWorld {
+        self.get_resource_or_init::<AsyncWorldTasks>().world.clone()
+    }
+
+    /// Spawns the future returned by `task` on the [`AsyncComputeTaskPool`], passing it the
+    /// [`AsyncWorld`] handle of this world.
+    ///
+    /// The task runs until it finishes or the world is dropped. Use
+    /// [`EntityWorldMut::spawn_task`] to spawn a task that is cancelled when an entity is
+    /// despawned.
+    pub fn spawn_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut tasks = self.get_resource_or_init::<AsyncWorldTasks>();
+        let task = tasks.world.spawn(task);
+        tasks.tasks.push(task);
+    }
+
+    /// Applies the commands queued by [`AsyncWorld`] handles, and drops the tasks that have
+    /// finished.
+    ///
+    /// This is the sync point at which async code accesses the world. Commands queued while it
+    /// runs are applied at the next sync point.
+    pub fn apply_async_commands(&mut self) {
+        let Some(tasks) = self.get_resource::<AsyncWorldTasks>() else {
+            return;
+        };
+        let queue = tasks.world.queue.clone();
+        for _ in 0..queue.len() {
+            let Ok(command) = queue.pop() else {
+                break;
+            };
+            command(self);
+        }
+        self.flush();
+
+        // Only touch the tasks when some have finished, to avoid triggering change detection.
+        if let Some(mut tasks) = self.get_resource_mut::<AsyncWorldTasks>() {
+            if tasks.tasks.iter().any(Task::is_finished) {
+                tasks.tasks.retain(|task| !task.is_finished());
+            }
+        }
+        let mut query = self.query::<&mut EntityTasks>();
+        for mut tasks in query.iter_mut(self) {
+            if tasks.tasks.iter().any(Task::is_finished) {
+                tasks.tasks.retain(|task| !task.is_finished());
+            }
+        }
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Spawns the future returned by `task` on the [`AsyncComputeTaskPool`], passing it the
+    /// [`AsyncWorld`] handle of the world. The task is cancelled when the entity is despawned.
+    ///
+    /// The task is stored in the [`EntityTasks`] component of the entity.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> &mut Self
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.assert_not_despawned();
+        let task = self.world_scope(|world| world.async_world().spawn(task));
+        match self.get_mut::<EntityTasks>() {
+            Some(mut tasks) => tasks.tasks.push(task),
+            None => {
+                self.insert(EntityTasks {
+                    tasks: alloc::vec![task],
+                });
+            }
+        }
+        self
+    }
+}
+
+impl<'w, 's> Commands<'w, 's> {
+    /// Spawns the future returned by `task` on the [`AsyncComputeTaskPool`] when the command is
+    /// applied, passing it the [`AsyncWorld`] handle of the world.
+    ///
+    /// See [`World::spawn_task`].
+    pub fn spawn_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.queue(move |world: &mut World| world.spawn_task(task));
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Spawns the future returned by `task` on the [`AsyncComputeTaskPool`] when the command is
+    /// applied, passing it the [`AsyncWorld`] handle of the world. The task is cancelled when the
+    /// entity is despawned.
+    ///
+    /// See [`EntityWorldMut::spawn_task`].
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> &mut Self
+    where
+        F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.spawn_task(task);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityTasks;
+    use crate::prelude::*;
+    use alloc::sync::Arc;
+    use bevy_tasks::{
+        tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool,
+        TaskPool,
+    };
+    use core::{
+        future::pending,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    /// Sets its flag when dropped, which happens when the task that owns it is cancelled.
+    struct SetOnDrop(Arc<AtomicBool>);
+
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Runs sync points until `done` returns `true`.
+    fn run_until(world: &mut World, mut done: impl FnMut(&mut World) -> bool) {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        IoTaskPool::get_or_init(TaskPool::default);
+        for _ in 0..1000 {
+            tick_global_task_pools_on_main_thread();
+            world.apply_async_commands();
+            if done(world) {
+                return;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+        panic!("condition not reached");
+    }
+
+    #[test]
+    fn async_world_run_and_queue() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.spawn_task(|world| async move {
+            for _ in 0..3 {
+                let count = world
+                    .run(|world: &mut World| world.resource::<Counter>().0)
+                    .await;
+                world
+                    .run(move |world: &mut World| world.resource_mut::<Counter>().0 = count + 1)
+                    .await;
+            }
+            world.queue(|world: &mut World| world.resource_mut::<Counter>().0 *= 10);
+        });
+
+        run_until(&mut world, |world| world.resource::<Counter>().0 == 30);
+    }
+
+    #[test]
+    fn entity_tasks() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let finished = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(finished)
+            .spawn_task(|world| async move {
+                world.queue(|world: &mut World| world.resource_mut::<Counter>().0 += 1);
+            });
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = SetOnDrop(dropped.clone());
+        let cancelled = world
+            .spawn_empty()
+            .spawn_task(|world| async move {
+                let _guard = guard;
+                pending::<()>().await;
+                world.queue(|world: &mut World| world.resource_mut::<Counter>().0 += 10);
+            })
+            .id();
+        world.flush();
+        assert_eq!(world.get::<EntityTasks>(cancelled).unwrap().len(), 1);
+
+        run_until(&mut world, |world| {
+            world.get::<EntityTasks>(finished).unwrap().is_empty()
+        });
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert_eq!(world.get::<EntityTasks>(cancelled).unwrap().len(), 1);
+        assert!(!dropped.load(Ordering::Relaxed));
+
+        world.despawn(cancelled);
+        run_until(&mut world, |_| dropped.load(Ordering::Relaxed));
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+}
@@ -0,0 +1,265 @@
+//! Recording the structural changes made to a [`World`] with [`WorldChangeLog`].
+
+use crate::{
+    component::{ComponentId, Tick},
+    entity::Entity,
+    resource::Resource,
+    world::World,
+};
+use alloc::vec::{Drain, Vec};
+
+/// Resource that records every structural change made to the [`World`]: spawns, despawns,
+/// component insertions and removals, and resource insertions and removals.
+///
+/// Recording is opt-in, and starts with [`World::enable_change_log`]. While it is disabled,
+/// structural changes don't pay for it beyond a single branch. Unlike [`RemovedComponents`] and
+/// change detection, which are tracked per component type, the log is a single stream of
+/// changes in the order in which they were applied, which makes it suitable for debugging and
+/// network replication.
+///
+/// Changes accumulate until they are drained, usually by a system once per frame:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::{WorldChange, WorldChangeLog};
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// world.enable_change_log();
+/// let entity = world.spawn(Position(0.0)).id();
+///
+/// fn replicate(mut log: ResMut<WorldChangeLog>) {
+///     for record in log.drain() {
+///         match record.change {
+///             WorldChange::Spawned(entity) => println!("spawned {entity} at {:?}", record.tick),
+///             WorldChange::Inserted { entity, component } => {
+///                 println!("inserted {component:?} on {entity}");
+///             }
+///             _ => {}
+///         }
+///     }
+/// }
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(replicate);
+/// schedule.run(&mut world);
+/// assert!(world.resource::<WorldChangeLog>().is_empty());
+/// ```
+///
+/// Mutations of component and resource values are not structural changes, and are not
+/// recorded. Use change detection to find them.
+///
+/// [`RemovedComponents`]: crate::removal_detection::RemovedComponents
+#[derive(Resource, Default, Debug)]
+pub struct WorldChangeLog {
+    changes: Vec<WorldChangeRecord>,
+}
+
+/// A structural change recorded in the [`WorldChangeLog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorldChangeRecord {
+    /// The [`World::change_tick`] when the change was made.
+    pub tick: Tick,
+    /// The change.
+    pub change: WorldChange,
+}
+
+/// A structural change made to a [`World`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldChange {
+    /// The entity was spawned. Its initial components are recorded as separate insertions.
+    Spawned(Entity),
+    /// The entity was despawned, along with all of its components.
+    Despawned(Entity),
+    /// The component was inserted on the entity, either because it was added or because its
+    /// value was replaced.
+    Inserted {
+        /// The entity the component was inserted on.
+        entity: Entity,
+        /// The id of the component.
+        component: ComponentId,
+    },
+    /// The component was removed from the entity.
+    Removed {
+        /// The entity the component was removed from.
+        entity: Entity,
+        /// The id of the component.
+        component: ComponentId,
+    },
+    /// The resource was inserted, either because it was added or because its value was replaced.
+    ResourceInserted(ComponentId),
+    /// The resource was removed.
+    ResourceRemoved(ComponentId),
+}
+
+impl WorldChangeLog {
+    /// Returns the number of recorded changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if there are no recorded changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns an iterator over the recorded changes, in the order in which they were made.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &WorldChangeRecord> {
+        self.changes.iter()
+    }
+
+    /// Removes all recorded changes and returns them as an iterator, in the order in which they
+    /// were made.
+    pub fn drain(&mut self) -> Drain<'_, WorldChangeRecord> {
+        self.changes.drain(..)
+    }
+
+    /// Discards all recorded changes.
+    pub fn clear(&mut self) {
+        self.changes.clear();
+    }
+
+    pub(crate) fn record(&mut self, tick: Tick, changes: impl IntoIterator<Item = WorldChange>) {
+        self.changes.extend(
+            changes
+                .into_iter()
+                .map(|change| WorldChangeRecord { tick, change }),
+        );
+    }
+}
+
+impl World {
+    /// Starts recording structural changes in the [`WorldChangeLog`] resource, inserting it if
+    /// it doesn't exist.
+    ///
+    /// Removing the resource pauses recording until it is inserted again.
+    pub fn enable_change_log(&mut self) {
+        if self.change_log.is_none() {
+            self.change_log = Some(self.init_resource::<WorldChangeLog>());
+        }
+    }
+
+    /// Stops recording structural changes, and removes the [`WorldChangeLog`] resource along
+    /// with the changes it still contains.
+    pub fn disable_change_log(&mut self) -> Option<WorldChangeLog> {
+        self.change_log = None;
+        self.remove_resource::<WorldChangeLog>()
+    }
+
+    /// Returns `true` if structural changes are recorded in the [`WorldChangeLog`].
+    #[inline]
+    pub fn is_change_log_enabled(&self) -> bool {
+        self.change_log.is_some()
+    }
+
+    /// Records `changes` in the [`WorldChangeLog`], if it is enabled.
+    #[inline]
+    pub(crate) fn record_changes(&mut self, changes: impl IntoIterator<Item = WorldChange>) {
+        if self.change_log.is_some() {
+            crate::world::DeferredWorld::from(self).record_changes(changes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorldChange, WorldChangeLog};
+    use crate::prelude::*;
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct B;
+
+    #[derive(Resource)]
+    struct R;
+
+    fn drain(world: &mut World) -> Vec<WorldChange> {
+        world
+            .resource_mut::<WorldChangeLog>()
+            .drain()
+            .map(|record| record.change)
+            .collect()
+    }
+
+    #[test]
+    fn change_log_entities() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.enable_change_log();
+        let a = world.register_component::<A>();
+        let b = world.register_component::<B>();
+
+        let entity = world.spawn(A).id();
+        world.entity_mut(entity).insert((A, B));
+        world.entity_mut(entity).remove::<A>();
+        assert!(world.entity_mut(entity).take::<B>().is_some());
+        let empty = world.spawn_empty().id();
+        world.despawn(entity);
+
+        assert_eq!(
+            drain(&mut world),
+            vec![
+                WorldChange::Spawned(entity),
+                WorldChange::Inserted {
+                    entity,
+                    component: a
+                },
+                WorldChange::Inserted {
+                    entity,
+                    component: b
+                },
+                WorldChange::Inserted {
+                    entity,
+                    component: a
+                },
+                WorldChange::Removed {
+                    entity,
+                    component: a
+                },
+                WorldChange::Removed {
+                    entity,
+                    component: b
+                },
+                WorldChange::Spawned(empty),
+                WorldChange::Despawned(entity),
+            ]
+        );
+    }
+
+    #[test]
+    fn change_log_commands_and_resources() {
+        let mut world = World::new();
+        world.enable_change_log();
+        world.enable_change_log();
+        let tick = world.change_tick();
+
+        let entity = world.commands().spawn_empty().id();
+        world.flush();
+        world.insert_resource(R);
+        let r = world.resource_id::<R>().unwrap();
+        world.remove_resource::<R>();
+        world.remove_resource::<R>();
+
+        let log = world.resource::<WorldChangeLog>();
+        assert!(log.iter().all(|record| record.tick == tick));
+        assert_eq!(
+            drain(&mut world),
+            vec![
+                WorldChange::Spawned(entity),
+                WorldChange::ResourceInserted(r),
+                WorldChange::ResourceRemoved(r),
+            ]
+        );
+
+        let log = world.disable_change_log().unwrap();
+        assert!(log.is_empty());
+        world.spawn(A);
+        assert!(!world.is_change_log_enabled());
+        assert!(!world.contains_resource::<WorldChangeLog>());
+    }
+}
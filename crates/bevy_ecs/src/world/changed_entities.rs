@@ -0,0 +1,287 @@
+//! Logging the entities whose components changed, for queries that filter on [`Changed`] or
+//! [`Added`] with [`World::track_changed_entities`].
+//!
+//! [`Changed`]: crate::query::Changed
+//! [`Added`]: crate::query::Added
+
+use crate::{
+    component::{Component, ComponentId, Components, Tick},
+    entity::Entity,
+    world::World,
+};
+use alloc::vec::Vec;
+use bevy_platform_support::sync::{Mutex, PoisonError};
+
+/// The entities whose value of a component was inserted or mutably accessed during the current
+/// and the previous frame.
+///
+/// Only components that opted in with [`World::track_changed_entities`] have one.
+#[derive(Debug, Default)]
+pub(crate) struct ChangedEntities {
+    log: Mutex<ChangedEntitiesLog>,
+}
+
+#[derive(Debug, Default)]
+struct ChangedEntitiesLog {
+    entities: Vec<Entity>,
+    /// The position of `entities[0]` in the log, counting the entities that were already dropped.
+    start: usize,
+    /// The position of the first entity recorded since the last call to
+    /// [`World::clear_trackers`].
+    frame_start: usize,
+}
+
+impl ChangedEntities {
+    /// Records that the value of the component on `entity` was changed.
+    #[inline]
+    pub(crate) fn push(&self, entity: Entity) {
+        self.log
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entities
+            .push(entity);
+    }
+
+    /// Drops the entities that were recorded before the previous frame.
+    fn clear_stale(&self) {
+        let log = &mut *self.log.lock().unwrap_or_else(PoisonError::into_inner);
+        log.entities.drain(..log.frame_start - log.start);
+        log.start = log.frame_start;
+        log.frame_start = log.start + log.entities.len();
+    }
+}
+
+/// The entities that a query filtering on [`Changed`] or [`Added`] needs to check, collected from
+/// the [`ChangedEntities`] of one of the filtered components.
+///
+/// The cursor remembers how far it has read the log. As long as its system runs at least once
+/// every other frame, the entities recorded since its last run are still in the log, and only
+/// those can match the filter. Otherwise, the query falls back to checking every entity.
+///
+/// [`Changed`]: crate::query::Changed
+/// [`Added`]: crate::query::Added
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChangedEntitiesCursor {
+    component: Option<ComponentId>,
+    /// The position in the log up to which the entities have been read.
+    position: usize,
+    /// The ticks of the last update.
+    ticks: Option<(Tick, Tick)>,
+    /// Whether `entities` contains every entity changed since `ticks.0`.
+    complete: bool,
+    entities: Vec<Entity>,
+}
+
+impl ChangedEntitiesCursor {
+    /// Reads the entities recorded in the log of `component` since the last update, and makes
+    /// them available to queries that run with `last_run` and `this_run`.
+    pub(crate) fn update(
+        &mut self,
+        components: &Components,
+        component: Option<ComponentId>,
+        last_run: Tick,
+        this_run: Tick,
+    ) {
+        let log = component.and_then(|id| components.changed_entities(id));
+        let Some(log) = log else {
+            self.component = None;
+            self.ticks = None;
+            self.complete = false;
+            self.entities.clear();
+            return;
+        };
+        let log = log.log.lock().unwrap_or_else(PoisonError::into_inner);
+        let readable = self.component == component && self.position >= log.start;
+        self.complete = readable
+            && match self.ticks {
+                // The query is fetched again during the same system run, e.g. by a `ParamSet`.
+                Some(ticks) if ticks == (last_run, this_run) => self.complete,
+                // The previous run read the log up to the point where this run starts.
+                Some((_, previous_run)) if previous_run == last_run => {
+                    self.entities.clear();
+                    true
+                }
+                _ => false,
+            };
+        if self.complete {
+            self.entities
+                .extend_from_slice(&log.entities[self.position - log.start..]);
+            self.entities.sort_unstable();
+            self.entities.dedup();
+        } else {
+            self.entities.clear();
+        }
+        self.component = component;
+        self.position = log.start + log.entities.len();
+        self.ticks = Some((last_run, this_run));
+    }
+
+    /// Returns the entities that can match the query when it runs with `last_run` and
+    /// `this_run`, in no particular order and without duplicates, or `None` if every entity
+    /// needs to be checked.
+    #[inline]
+    pub(crate) fn entities(&self, last_run: Tick, this_run: Tick) -> Option<&[Entity]> {
+        (self.complete && self.ticks == Some((last_run, this_run)))
+            .then_some(self.entities.as_slice())
+    }
+}
+
+impl World {
+    /// Starts recording the entities on which the component `T` is inserted or mutably accessed,
+    /// so that queries in systems that filter on [`Changed<T>`] or [`Added<T>`] only check
+    /// those entities rather than every entity that has `T`.
+    ///
+    /// This pays off for components that are stored on many entities, of which only a handful
+    /// change every frame. Each change costs an extra write to the log, and the log keeps the
+    /// changes of the current and the previous frame, which are discarded by
+    /// [`World::clear_trackers`]. Systems that don't run for longer than that, as well as queries
+    /// that run outside of systems, check every entity as before.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.track_changed_entities::<Health>();
+    /// world.spawn_batch((0..1000).map(|_| Health(100)));
+    ///
+    /// fn heal(query: Query<&Health, Changed<Health>>) {
+    ///     for health in &query {
+    ///         // Only the entities whose `Health` changed since the last run are checked.
+    ///     }
+    /// }
+    /// # let mut schedule = Schedule::default();
+    /// # schedule.add_systems(heal);
+    /// # schedule.run(&mut world);
+    /// ```
+    ///
+    /// [`Changed<T>`]: crate::query::Changed
+    /// [`Added<T>`]: crate::query::Added
+    pub fn track_changed_entities<T: Component>(&mut self) {
+        let id = self.register_component::<T>();
+        self.track_changed_entities_by_id(id);
+    }
+
+    /// Starts recording the entities on which the component with the given id is inserted or
+    /// mutably accessed.
+    ///
+    /// See [`World::track_changed_entities`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not the id of a registered component.
+    pub fn track_changed_entities_by_id(&mut self, id: ComponentId) {
+        self.components.track_changed_entities(id);
+    }
+
+    /// Returns `true` if the entities on which the component with the given id is inserted or
+    /// mutably accessed are recorded, see [`World::track_changed_entities`].
+    pub fn is_tracking_changed_entities(&self, id: ComponentId) -> bool {
+        self.components.changed_entities(id).is_some()
+    }
+
+    /// Drops the changed entities recorded before the previous frame.
+    pub(crate) fn clear_stale_changed_entities(&mut self) {
+        for &id in self.components.tracked_changed_entities() {
+            if let Some(log) = self.components.changed_entities(id) {
+                log.clear_stale();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, system::SystemState};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, PartialEq, Debug)]
+    struct A(u32);
+
+    #[derive(Component, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct B(u32);
+
+    #[derive(Resource, Default)]
+    struct Seen(Vec<u32>);
+
+    fn collect_changed(query: Query<&A, Changed<A>>, mut seen: ResMut<Seen>) {
+        let mut values: Vec<u32> = query.iter().map(|a| a.0).collect();
+        values.sort_unstable();
+        seen.0 = values;
+    }
+
+    fn run(world: &mut World, schedule: &mut Schedule) -> Vec<u32> {
+        schedule.run(world);
+        world.clear_trackers();
+        core::mem::take(&mut world.resource_mut::<Seen>().0)
+    }
+
+    #[test]
+    fn changed_query_visits_recorded_entities() {
+        let mut world = World::new();
+        world.track_changed_entities::<A>();
+        world.init_resource::<Seen>();
+        let entities: Vec<Entity> = world.spawn_batch((0..10).map(A)).collect();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(collect_changed);
+
+        // The first run checks every entity.
+        assert_eq!(run(&mut world, &mut schedule), (0..10).collect::<Vec<_>>());
+        assert!(run(&mut world, &mut schedule).is_empty());
+
+        world.get_mut::<A>(entities[3]).unwrap().0 = 30;
+        world.get_mut::<A>(entities[3]).unwrap().0 = 31;
+        world.entity_mut(entities[5]).insert(A(50));
+        world.spawn(A(100));
+        assert_eq!(run(&mut world, &mut schedule), vec![31, 50, 100]);
+
+        let mut state = SystemState::<Query<&mut A>>::new(&mut world);
+        for mut a in state.get_mut(&mut world).iter_mut() {
+            if a.0 == 7 {
+                a.0 = 70;
+            }
+        }
+        world.despawn(entities[5]);
+        world.entity_mut(entities[3]).remove::<A>();
+        assert_eq!(run(&mut world, &mut schedule), vec![70]);
+        assert!(run(&mut world, &mut schedule).is_empty());
+    }
+
+    #[test]
+    fn changed_query_falls_back_after_skipped_frames() {
+        let mut world = World::new();
+        world.track_changed_entities::<A>();
+        world.init_resource::<Seen>();
+        let entity = world.spawn(A(1)).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(collect_changed);
+        assert_eq!(run(&mut world, &mut schedule), vec![1]);
+
+        world.get_mut::<A>(entity).unwrap().0 = 2;
+        // The change is dropped from the log before the system runs again.
+        world.clear_trackers();
+        world.clear_trackers();
+        assert_eq!(run(&mut world, &mut schedule), vec![2]);
+    }
+
+    #[test]
+    fn changed_query_in_param_set() {
+        let mut world = World::new();
+        world.track_changed_entities::<B>();
+        let entities: Vec<Entity> = world.spawn_batch((0..4).map(B)).collect();
+
+        let mut state =
+            SystemState::<ParamSet<(Query<&mut B>, Query<Entity, Changed<B>>)>>::new(&mut world);
+        state.get_mut(&mut world);
+        world.clear_trackers();
+
+        let mut set = state.get_mut(&mut world);
+        assert_eq!(set.p1().iter().count(), 0);
+        set.p0().get_mut(entities[2]).unwrap().0 = 20;
+        assert_eq!(set.p1().iter().collect::<Vec<_>>(), vec![entities[2]]);
+    }
+}
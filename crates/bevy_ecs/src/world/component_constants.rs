@@ -47,6 +47,19 @@ pub struct OnReplace;
 #[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
 pub struct OnRemove;
 
+/// Trigger emitted for a resource that was changed since the previous check, as reported by
+/// [`DetectChanges::is_changed`](crate::change_detection::DetectChanges::is_changed). Changes are
+/// checked at the end of every [`Schedule`](crate::schedule::Schedule) run and by
+/// [`World::trigger_resource_changes`], so this is emitted once per check no matter how often the
+/// resource was mutated in between. Inserting a resource also counts as a change.
+///
+/// Only observers that watch the resource with
+/// [`Observer::with_resource`](crate::observer::Observer::with_resource) run.
+#[derive(Event, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
+pub struct OnResourceChange;
+
 /// Trigger emitted for each component on an entity when it is despawned.
 /// See [`crate::component::ComponentHooks::on_despawn`] for more information.
 #[derive(Event, Debug)]
@@ -1,4 +1,5 @@
 use core::ops::Deref;
+use smallvec::SmallVec;
 
 use crate::{
     archetype::Archetype,
@@ -6,14 +7,17 @@ use crate::{
     component::{ComponentId, HookContext, Mutable},
     entity::Entity,
     event::{Event, EventId, Events, SendBatchIds},
-    observer::{Observers, TriggerTargets},
+    observer::{Observers, PropagationPhase, TriggerTargets},
     prelude::{Component, QueryState},
     query::{QueryData, QueryFilter},
     relationship::RelationshipHookMode,
     resource::Resource,
     system::{Commands, Query},
     traversal::Traversal,
-    world::{error::EntityMutableFetchError, EntityFetcher, WorldEntityFetch},
+    world::{
+        error::EntityMutableFetchError, EntityFetcher, WorldChange, WorldChangeLog,
+        WorldEntityFetch,
+    },
 };
 
 use super::{unsafe_world_cell::UnsafeWorldCell, Mut, World, ON_INSERT, ON_REPLACE};
@@ -573,6 +577,19 @@ impl<'w> DeferredWorld<'w> {
             .ok()
     }
 
+    /// Records `changes` in the [`WorldChangeLog`], if it is enabled.
+    #[inline]
+    pub(crate) fn record_changes(&mut self, changes: impl IntoIterator<Item = WorldChange>) {
+        let Some(change_log) = self.change_log else {
+            return;
+        };
+        let tick = self.read_change_tick();
+        if let Some(log) = self.get_resource_mut_by_id(change_log) {
+            // SAFETY: `change_log` is the id of `WorldChangeLog`, see `World::enable_change_log`
+            unsafe { log.with_type::<WorldChangeLog>() }.record(tick, changes);
+        }
+    }
+
     /// Triggers all `on_add` hooks for [`ComponentId`] in target.
     ///
     /// # Safety
@@ -750,6 +767,7 @@ impl<'w> DeferredWorld<'w> {
             &mut (),
             &mut false,
             caller,
+            PropagationPhase::Target,
         );
     }
 
@@ -769,6 +787,37 @@ impl<'w> DeferredWorld<'w> {
     ) where
         T: Traversal<E>,
     {
+        if target != Entity::PLACEHOLDER && self.world.observers().has_capturing_observers(event) {
+            // Collect the propagation path first, so the capture phase can run it from the root down.
+            let mut path: SmallVec<[Entity; 8]> = SmallVec::new();
+            let mut current = target;
+            while let Some(next) = self.traverse::<E, T>(current, data) {
+                if next == target || path.contains(&next) {
+                    break;
+                }
+                path.push(next);
+                current = next;
+            }
+
+            let mut capture = true;
+            for &ancestor in path.iter().rev() {
+                Observers::invoke::<_>(
+                    self.reborrow(),
+                    event,
+                    ancestor,
+                    components.clone(),
+                    data,
+                    &mut capture,
+                    caller,
+                    PropagationPhase::Capture,
+                );
+                if !capture {
+                    return;
+                }
+            }
+        }
+
+        let mut phase = PropagationPhase::Target;
         loop {
             Observers::invoke::<_>(
                 self.reborrow(),
@@ -778,23 +827,28 @@ impl<'w> DeferredWorld<'w> {
                 data,
                 &mut propagate,
                 caller,
+                phase,
             );
             if !propagate {
                 break;
             }
-            if let Some(traverse_to) = self
-                .get_entity(target)
-                .ok()
-                .and_then(|entity| entity.get_components::<T>())
-                .and_then(|item| T::traverse(item, data))
-            {
+            if let Some(traverse_to) = self.traverse::<E, T>(target, data) {
                 target = traverse_to;
+                phase = PropagationPhase::Bubble;
             } else {
                 break;
             }
         }
     }
 
+    /// Returns the next entity on the propagation path of an event, starting at `entity`.
+    fn traverse<E, T: Traversal<E>>(&self, entity: Entity, data: &E) -> Option<Entity> {
+        self.get_entity(entity)
+            .ok()
+            .and_then(|entity| entity.get_components::<T>())
+            .and_then(|item| T::traverse(item, data))
+    }
+
     /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets.
     pub fn trigger(&mut self, trigger: impl Event) {
         self.commands().trigger(trigger);
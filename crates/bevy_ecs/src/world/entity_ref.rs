@@ -23,7 +23,7 @@ use crate::{
     system::IntoObserverSystem,
     world::{
         error::EntityComponentError, unsafe_world_cell::UnsafeEntityCell, DeferredWorld, Mut, Ref,
-        World, ON_DESPAWN, ON_REMOVE, ON_REPLACE,
+        World, WorldChange, ON_DESPAWN, ON_REMOVE, ON_REPLACE,
     },
 };
 use alloc::vec::Vec;
@@ -1928,7 +1928,12 @@ impl<'w> EntityWorldMut<'w> {
         component_ids: &[ComponentId],
         iter_components: I,
     ) -> &mut Self {
-        self.insert_by_ids_internal(component_ids, iter_components, RelationshipHookMode::Run)
+        self.insert_by_ids_internal(
+            component_ids,
+            iter_components,
+            InsertMode::Replace,
+            RelationshipHookMode::Run,
+        )
     }
 
     #[track_caller]
@@ -1936,6 +1941,7 @@ impl<'w> EntityWorldMut<'w> {
         &mut self,
         component_ids: &[ComponentId],
         iter_components: I,
+        insert_mode: InsertMode,
         relationship_hook_insert_mode: RelationshipHookMode,
     ) -> &mut Self {
         self.assert_not_despawned();
@@ -1960,7 +1966,7 @@ impl<'w> EntityWorldMut<'w> {
             self.location,
             iter_components,
             (*storage_types).iter().cloned(),
-            InsertMode::Replace,
+            insert_mode,
             MaybeLocation::caller(),
             relationship_hook_insert_mode,
         );
@@ -2082,6 +2088,101 @@ impl<'w> EntityWorldMut<'w> {
         Some(result)
     }
 
+    /// Removes the components with the given ids from the entity without dropping them, and
+    /// passes their ids and data to `f`, which takes ownership of the data.
+    ///
+    /// # Panics
+    ///
+    /// If the entity doesn't have all of the components, or if it has been despawned while this
+    /// `EntityWorldMut` is still alive.
+    pub(crate) fn take_by_ids_with(
+        &mut self,
+        component_ids: &[ComponentId],
+        mut f: impl FnMut(ComponentId, OwningPtr<'_>),
+    ) {
+        self.assert_not_despawned();
+        let world = &mut self.world;
+        let bundle_id =
+            world
+                .bundles
+                .init_dynamic_info(&mut world.storages, &world.components, component_ids);
+        // SAFETY: We just ensured this bundle exists
+        let bundle_info = unsafe { world.bundles.get_unchecked(bundle_id) };
+        let old_location = self.location;
+        // SAFETY: `archetype_id` exists because it is referenced in the old `EntityLocation` which is valid,
+        // components exist in `bundle_info` because `Bundles::init_dynamic_info` only accepts registered components
+        let new_archetype_id = unsafe {
+            bundle_info.remove_bundle_from_archetype(
+                &mut world.archetypes,
+                &mut world.storages,
+                &world.components,
+                &world.observers,
+                old_location.archetype_id,
+                false,
+            )
+        }
+        .expect("the entity must have all of the taken components");
+
+        let entity = self.entity;
+        // SAFETY: Archetypes and Bundles cannot be mutably aliased through DeferredWorld
+        let (old_archetype, bundle_info, mut deferred_world) = unsafe {
+            let bundle_info: *const BundleInfo = bundle_info;
+            let world = world.as_unsafe_world_cell();
+            (
+                &world.archetypes()[old_location.archetype_id],
+                &*bundle_info,
+                world.into_deferred(),
+            )
+        };
+
+        // SAFETY: all bundle components exist in World
+        unsafe {
+            trigger_on_replace_and_on_remove_hooks_and_observers(
+                &mut deferred_world,
+                old_archetype,
+                entity,
+                bundle_info,
+                MaybeLocation::caller(),
+            );
+        }
+
+        for component_id in bundle_info.iter_explicit_components() {
+            // SAFETY:
+            // - entity location is valid
+            // - table row is removed below, without dropping the contents
+            // - `components` comes from the same world as `storages`
+            // - the component exists on the entity
+            let ptr = unsafe {
+                take_component(
+                    &mut world.storages,
+                    &world.components,
+                    &mut world.removed_components,
+                    component_id,
+                    entity,
+                    old_location,
+                )
+            };
+            f(component_id, ptr);
+        }
+
+        // SAFETY: `new_archetype_id` has a subset of the components of the old archetype, and
+        // the taken components have been moved out above.
+        unsafe {
+            Self::move_entity_from_remove::<false>(
+                entity,
+                &mut self.location,
+                old_location.archetype_id,
+                old_location,
+                &mut world.entities,
+                &mut world.archetypes,
+                &mut world.storages,
+                new_archetype_id,
+            );
+        }
+        self.world.flush();
+        self.update_location();
+    }
+
     /// # Safety
     ///
     /// `new_archetype_id` must have the same or a subset of the components
@@ -2091,7 +2192,7 @@ impl<'w> EntityWorldMut<'w> {
     /// when DROP is true removed components will be dropped otherwise they will be forgotten
     // We use a const generic here so that we are less reliant on
     // inlining for rustc to optimize out the `match DROP`
-    unsafe fn move_entity_from_remove<const DROP: bool>(
+    pub(crate) unsafe fn move_entity_from_remove<const DROP: bool>(
         entity: Entity,
         self_location: &mut EntityLocation,
         old_archetype_id: ArchetypeId,
@@ -2494,6 +2595,8 @@ impl<'w> EntityWorldMut<'w> {
             (&*archetype, world.into_deferred())
         };
 
+        deferred_world.record_changes([WorldChange::Despawned(self.entity)]);
+
         // SAFETY: All components in the archetype exist in world
         unsafe {
             if archetype.has_despawn_observer() {
@@ -2968,6 +3071,10 @@ unsafe fn trigger_on_replace_and_on_remove_hooks_and_observers(
             .iter_explicit_components()
             .filter(|component_id| archetype.contains(*component_id))
     };
+    deferred_world.record_changes(
+        bundle_components_in_archetype()
+            .map(|component| WorldChange::Removed { entity, component }),
+    );
     if archetype.has_replace_observer() {
         deferred_world.trigger_observers(
             ON_REPLACE,
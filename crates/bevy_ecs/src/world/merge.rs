@@ -0,0 +1,209 @@
+//! Moving entities between worlds with [`World::merge_from`].
+
+use crate::{
+    bundle::InsertMode,
+    component::ComponentId,
+    entity::{hash_map::EntityHashMap, CrossWorldMapper, Entity, EntityCloner},
+    observer::ObservedBy,
+    world::{EntityRef, World},
+};
+use alloc::vec::Vec;
+
+impl World {
+    /// Moves the entities of `other` for which `filter` returns `true` into this world, and
+    /// returns a map from their ids in `other` to their new ids in this world.
+    ///
+    /// Entities are moved by cloning all of their components into newly spawned entities with
+    /// the default [`EntityCloner`] configuration, and then despawning them from `other`.
+    /// Components that can't be cloned are moved as they are instead, so entity references in
+    /// them are not mapped. Component ids are mapped between the worlds as described in
+    /// [`EntityCloner::clone_entity_into_world`], and components that don't correspond to a Rust
+    /// type are dropped along with the entity.
+    ///
+    /// Entity references in components, including relationships, are mapped to the new entities.
+    /// References to entities that are not moved are mapped to [`Entity::PLACEHOLDER`], and
+    /// relationships to them are removed. Despawning a moved entity in `other` despawns the
+    /// entities linked to it through relationships like [`Children`], even if they aren't moved.
+    ///
+    /// Resources and observers are not moved.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone)]
+    /// struct Simulated;
+    ///
+    /// let mut simulation = World::new();
+    /// let parent = simulation.spawn(Simulated).id();
+    /// simulation.spawn((Simulated, ChildOf(parent)));
+    /// simulation.spawn_empty();
+    ///
+    /// let mut world = World::new();
+    /// let moved = world.merge_from(&mut simulation, |entity| entity.contains::<Simulated>());
+    ///
+    /// assert_eq!(moved.len(), 2);
+    /// assert_eq!(simulation.entities().len(), 1);
+    /// let parent = moved[&parent];
+    /// assert_eq!(world.get::<Children>(parent).unwrap().len(), 1);
+    /// ```
+    ///
+    /// [`Children`]: crate::hierarchy::Children
+    pub fn merge_from(
+        &mut self,
+        other: &mut World,
+        mut filter: impl FnMut(EntityRef) -> bool,
+    ) -> EntityHashMap<Entity> {
+        other.flush();
+        let mut sources: Vec<Entity> = other
+            .iter_entities()
+            .filter(|&entity| filter(entity))
+            .map(|entity| entity.id())
+            .collect();
+        // Clone entities in the order in which they were allocated, which is usually the order
+        // in which they were added to relationships.
+        sources.sort_unstable();
+
+        let mut map = EntityHashMap::default();
+        for &source in &sources {
+            map.insert(source, self.spawn_empty().id());
+        }
+
+        // Relationships are rebuilt by the hooks of the cloned `Relationship` components, so
+        // the components they insert must not be replaced by the cloned `RelationshipTarget`s.
+        let mut cloner = EntityCloner::default();
+        let observed_by = other.component_id::<ObservedBy>();
+        let mut uncloned = Vec::new();
+        for &source in &sources {
+            let target = cloner.clone_entity_into_world_with(
+                other,
+                source,
+                self,
+                &mut CrossWorldMapper(&mut map),
+                InsertMode::Keep,
+            );
+
+            // `other` is consumed, so the components that weren't cloned are moved instead of
+            // being dropped.
+            uncloned.clear();
+            uncloned.extend(
+                cloner
+                    .uncloned_components()
+                    .iter()
+                    .filter(|&&(component, _)| Some(component) != observed_by),
+            );
+            if uncloned.is_empty() {
+                continue;
+            }
+            let source_ids: Vec<ComponentId> = uncloned.iter().map(|&(id, _)| id).collect();
+            let mut target = self.entity_mut(target);
+            other
+                .entity_mut(source)
+                .take_by_ids_with(&source_ids, |component, ptr| {
+                    let &(_, target_component) =
+                        uncloned.iter().find(|&&(id, _)| id == component).unwrap();
+                    // SAFETY: `target_component` was registered in this world from the info of
+                    // `component`, so they have the same Rust type.
+                    unsafe {
+                        target.insert_by_id(target_component, ptr);
+                    }
+                });
+        }
+
+        for source in sources {
+            if let Ok(entity) = other.get_entity_mut(source) {
+                entity.despawn();
+            }
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::Entity, prelude::*};
+    use alloc::{sync::Arc, vec::Vec};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct A(u32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Target(#[entities] Entity);
+
+    #[derive(Component, PartialEq, Debug)]
+    struct NotClone(Entity);
+
+    #[test]
+    fn merge_worlds() {
+        let mut other = World::new();
+        let a = other.spawn(A(1)).id();
+        let b = other.spawn((A(2), Target(a), NotClone(a))).id();
+        let parent = other.spawn(A(3)).id();
+        let children: Vec<_> = (0..3)
+            .map(|i| other.spawn((A(i), ChildOf(parent))).id())
+            .collect();
+        let kept = other.spawn(Target(a)).id();
+
+        let mut world = World::new();
+        let existing = world.spawn(A(0)).id();
+        let moved = world.merge_from(&mut other, |entity| entity.contains::<A>());
+
+        assert_eq!(moved.len(), 6);
+        assert_eq!(world.get::<A>(moved[&a]), Some(&A(1)));
+        assert_eq!(world.get::<Target>(moved[&b]), Some(&Target(moved[&a])));
+        assert_eq!(world.get::<NotClone>(moved[&b]), Some(&NotClone(a)));
+        assert_eq!(world.get::<A>(existing), Some(&A(0)));
+
+        let new_children: Vec<_> = children.iter().map(|child| moved[child]).collect();
+        let parent = moved[&parent];
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &new_children[..]);
+        for child in new_children {
+            assert_eq!(world.get::<ChildOf>(child), Some(&ChildOf(parent)));
+        }
+
+        assert_eq!(other.entities().len(), 1);
+        assert_eq!(other.get::<Target>(kept), Some(&Target(a)));
+    }
+
+    #[test]
+    fn merge_worlds_moves_uncloneable_components() {
+        #[derive(Component)]
+        struct DropCounter(Arc<AtomicUsize>);
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let mut other = World::new();
+        let source = other.spawn((A(0), DropCounter(drops.clone()))).id();
+
+        let mut world = World::new();
+        let moved = world.merge_from(&mut other, |_| true);
+        assert_eq!(drops.load(Ordering::Relaxed), 0);
+        assert!(world.entity(moved[&source]).contains::<DropCounter>());
+
+        drop(other);
+        assert_eq!(drops.load(Ordering::Relaxed), 0);
+        world.despawn(moved[&source]);
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn merge_worlds_unmoved_references() {
+        let mut other = World::new();
+        let parent = other.spawn_empty().id();
+        let child = other.spawn((A(0), ChildOf(parent), Target(parent))).id();
+
+        let mut world = World::new();
+        let moved = world.merge_from(&mut other, |entity| entity.contains::<A>());
+        let child = moved[&child];
+        assert!(!world.entity(child).contains::<ChildOf>());
+        assert_eq!(
+            world.get::<Target>(child),
+            Some(&Target(Entity::PLACEHOLDER))
+        );
+        assert!(other.get_entity(parent).is_ok());
+    }
+}
@@ -1,5 +1,9 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+#[cfg(feature = "std")]
+mod async_world;
+mod change_log;
+mod changed_entities;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
@@ -8,6 +12,8 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+mod merge;
+mod snapshot;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -18,7 +24,11 @@ pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+#[cfg(feature = "std")]
+pub use async_world::{AsyncWorld, EntityTasks};
 pub use bevy_ecs_macros::FromWorld;
+pub use change_log::{WorldChange, WorldChangeLog, WorldChangeRecord};
+pub(crate) use changed_entities::{ChangedEntities, ChangedEntitiesCursor};
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_fetch::{EntityFetcher, WorldEntityFetch};
@@ -28,6 +38,7 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use snapshot::{SnapshotRegistry, WorldSnapshot};
 pub use spawn_batch::*;
 
 #[expect(
@@ -47,7 +58,8 @@ use crate::{
         RequiredComponents, RequiredComponentsError, Tick,
     },
     entity::{
-        AllocAtWithoutReplacement, Entities, Entity, EntityDoesNotExistError, EntityLocation,
+        AllocAtError, AllocAtWithoutReplacement, Entities, Entity, EntityAllocationMode,
+        EntityDoesNotExistError, EntityLocation,
     },
     entity_disabling::DefaultQueryFilters,
     event::{Event, EventId, Events, SendBatchIds},
@@ -69,7 +81,7 @@ use crate::{
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform_support::sync::atomic::{AtomicU32, Ordering};
 use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
-use core::{any::TypeId, fmt};
+use core::{any::TypeId, fmt, ops::Range};
 use log::warn;
 use unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell};
 
@@ -104,8 +116,11 @@ pub struct World {
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    /// The tick [`World::trigger_resource_changes`] last checked resource changes at.
+    pub(crate) last_resource_change_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    pub(crate) change_log: Option<ComponentId>,
 }
 
 impl Default for World {
@@ -124,9 +139,11 @@ impl Default for World {
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            last_resource_change_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
             component_ids: ComponentIds::default(),
+            change_log: None,
         };
         world.bootstrap();
         world
@@ -216,6 +233,23 @@ impl World {
         &mut self.entities
     }
 
+    /// Changes the [`EntityAllocationMode`] this world uses to pick the id of new entities.
+    ///
+    /// See [`Entities::set_allocation_mode`].
+    pub fn set_entity_allocation_mode(&mut self, mode: EntityAllocationMode) {
+        self.flush();
+        self.entities.set_allocation_mode(mode);
+    }
+
+    /// Adds a range of entity indices that new entities can be spawned with in
+    /// [`EntityAllocationMode::Ranges`].
+    ///
+    /// See [`Entities::add_allocation_range`].
+    pub fn add_entity_allocation_range(&mut self, range: Range<u32>) {
+        self.flush();
+        self.entities.add_allocation_range(range);
+    }
+
     /// Retrieves this world's [`Archetypes`] collection.
     #[inline]
     pub fn archetypes(&self) -> &Archetypes {
@@ -1165,8 +1199,53 @@ impl World {
         caller: MaybeLocation,
     ) -> EntityWorldMut {
         self.flush();
-        let change_tick = self.change_tick();
         let entity = self.entities.alloc();
+        // SAFETY: entity was just allocated
+        unsafe { self.spawn_allocated_with_caller(entity, bundle, caller) }
+    }
+
+    /// Spawns a new [`Entity`] with the given `entity` id and [`Bundle`] of
+    /// [components](`Component`), and returns a corresponding [`EntityWorldMut`].
+    ///
+    /// This fails if the index of `entity` is in use, or if `entity` is older than the next
+    /// generation of its index, so it can never replace or alias another entity.
+    /// Together with [`World::set_entity_allocation_mode`], this makes it possible to keep entity
+    /// ids in sync between worlds, for example when replicating entities from a server.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, entity::Entity, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Num(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = Entity::from_raw(42);
+    /// world.spawn_at(entity, Num(1)).unwrap();
+    ///
+    /// assert_eq!(world.get::<Num>(entity).unwrap().0, 1);
+    /// assert!(world.spawn_at(entity, Num(2)).is_err());
+    /// ```
+    #[track_caller]
+    pub fn spawn_at<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+    ) -> Result<EntityWorldMut, AllocAtError> {
+        self.flush();
+        self.entities.try_alloc_at(entity)?;
+        // SAFETY: entity was just allocated
+        Ok(unsafe { self.spawn_allocated_with_caller(entity, bundle, MaybeLocation::caller()) })
+    }
+
+    /// # Safety
+    /// must be called on an entity that was just allocated
+    unsafe fn spawn_allocated_with_caller<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+        caller: MaybeLocation,
+    ) -> EntityWorldMut {
+        let change_tick = self.change_tick();
         let mut bundle_spawner = BundleSpawner::new::<B>(self, change_tick);
         // SAFETY: bundle's type matches `bundle_info`, entity is allocated but non-existent
         let (mut entity_location, after_effect) =
@@ -1207,6 +1286,7 @@ impl World {
 
         self.entities
             .set_spawned_or_despawned_by(entity.index(), caller);
+        self.record_changes([WorldChange::Spawned(entity)]);
 
         EntityWorldMut::new(self, entity, location)
     }
@@ -1466,6 +1546,7 @@ impl World {
     /// [`RemovedComponents`]: crate::removal_detection::RemovedComponents
     pub fn clear_trackers(&mut self) {
         self.removed_components.update();
+        self.clear_stale_changed_entities();
         self.last_change_tick = self.increment_change_tick();
     }
 
@@ -1710,6 +1791,10 @@ impl World {
     /// Resources are "unique" data of a given type.
     /// If you insert a resource of a type that already exists,
     /// you will overwrite any existing data.
+    ///
+    /// Runs the [`OnReplace`] observers of the resource before overwriting it, and its [`OnAdd`]
+    /// and [`OnInsert`] observers after inserting it. See
+    /// [`Observer::with_resource`](crate::observer::Observer::with_resource).
     #[inline]
     #[track_caller]
     pub fn insert_resource<R: Resource>(&mut self, value: R) {
@@ -1789,12 +1874,28 @@ impl World {
     }
 
     /// Removes the resource of a given type and returns it, if it exists. Otherwise returns `None`.
+    ///
+    /// Runs the [`OnReplace`] and [`OnRemove`] observers of the resource before removing it.
     #[inline]
+    #[track_caller]
     pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
         let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
+        if !self.storages.resources.get(component_id)?.is_present() {
+            return None;
+        }
+        let triggered = self.trigger_resource_observers(
+            &[ON_REPLACE, ON_REMOVE],
+            component_id,
+            MaybeLocation::caller(),
+        );
         let (ptr, _, _) = self.storages.resources.get_mut(component_id)?.remove()?;
         // SAFETY: `component_id` was gotten via looking up the `R` type
-        unsafe { Some(ptr.read::<R>()) }
+        let resource = unsafe { ptr.read::<R>() };
+        self.record_changes([WorldChange::ResourceRemoved(component_id)]);
+        if triggered {
+            self.flush();
+        }
+        Some(resource)
     }
 
     /// Removes a `!Send` resource from the world and returns it, if present.
@@ -1809,15 +1910,34 @@ impl World {
     /// If a value is present, this function will panic if called from a different
     /// thread than where the value was inserted from.
     #[inline]
+    #[track_caller]
     pub fn remove_non_send_resource<R: 'static>(&mut self) -> Option<R> {
         let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
+        if !self
+            .storages
+            .non_send_resources
+            .get(component_id)?
+            .is_present()
+        {
+            return None;
+        }
+        let triggered = self.trigger_resource_observers(
+            &[ON_REPLACE, ON_REMOVE],
+            component_id,
+            MaybeLocation::caller(),
+        );
         let (ptr, _, _) = self
             .storages
             .non_send_resources
             .get_mut(component_id)?
             .remove()?;
         // SAFETY: `component_id` was gotten via looking up the `R` type
-        unsafe { Some(ptr.read::<R>()) }
+        let resource = unsafe { ptr.read::<R>() };
+        self.record_changes([WorldChange::ResourceRemoved(component_id)]);
+        if triggered {
+            self.flush();
+        }
+        Some(resource)
     }
 
     /// Returns `true` if a resource of type `R` exists. Otherwise returns `false`.
@@ -2063,21 +2183,25 @@ impl World {
         let last_change_tick = self.last_change_tick();
 
         let component_id = self.components_registrator().register_resource::<R>();
-        let data = self.initialize_resource_internal(component_id);
-        if !data.is_present() {
+        if !self.initialize_resource_internal(component_id).is_present() {
             OwningPtr::make(func(), |ptr| {
                 // SAFETY: component_id was just initialized and corresponds to resource of type R.
                 unsafe {
-                    data.insert(ptr, change_tick, caller);
+                    self.insert_resource_by_id(component_id, ptr, caller);
                 }
             });
         }
 
-        // SAFETY: The resource must be present, as we would have inserted it if it was empty.
+        // SAFETY: The resource was initialized above.
         let data = unsafe {
-            data.get_mut(last_change_tick, change_tick)
+            self.storages
+                .resources
+                .get_mut(component_id)
                 .debug_checked_unwrap()
         };
+        let data = data
+            .get_mut(last_change_tick, change_tick)
+            .unwrap_or_else(|| resource_removed_by_observer::<R>());
         // SAFETY: The underlying type of the resource is `R`.
         unsafe { data.with_type::<R>() }
     }
@@ -2143,11 +2267,9 @@ impl World {
                 .get_mut(component_id)
                 .debug_checked_unwrap()
         };
-        // SAFETY: The resource must be present, as we would have inserted it if it was empty.
-        let data = unsafe {
-            data.get_mut(last_change_tick, change_tick)
-                .debug_checked_unwrap()
-        };
+        let data = data
+            .get_mut(last_change_tick, change_tick)
+            .unwrap_or_else(|| resource_removed_by_observer::<R>());
         // SAFETY: The underlying type of the resource is `R`.
         unsafe { data.with_type::<R>() }
     }
@@ -2763,6 +2885,7 @@ impl World {
                 changed: &mut ticks.changed,
                 last_run: last_change_tick,
                 this_run: change_tick,
+                changed_entities: None,
             },
             changed_by: caller.as_mut(),
         };
@@ -2835,11 +2958,29 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let present = self
+            .storages
+            .resources
+            .get(component_id)
+            .is_some_and(ResourceData::is_present);
+        let replaced =
+            present && self.trigger_resource_observers(&[ON_REPLACE], component_id, caller);
+
         let resource = self.initialize_resource_internal(component_id);
         // SAFETY: `value` is valid for `component_id`, ensured by caller
         unsafe {
             resource.insert(value, change_tick, caller);
         }
+        self.record_changes([WorldChange::ResourceInserted(component_id)]);
+
+        let events: &[_] = if present {
+            &[ON_INSERT]
+        } else {
+            &[ON_ADD, ON_INSERT]
+        };
+        if self.trigger_resource_observers(events, component_id, caller) || replaced {
+            self.flush();
+        }
     }
 
     /// Inserts a new `!Send` resource with the given `value`. Will replace the value if it already
@@ -2864,11 +3005,29 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let present = self
+            .storages
+            .non_send_resources
+            .get(component_id)
+            .is_some_and(ResourceData::is_present);
+        let replaced =
+            present && self.trigger_resource_observers(&[ON_REPLACE], component_id, caller);
+
         let resource = self.initialize_non_send_internal(component_id);
         // SAFETY: `value` is valid for `component_id`, ensured by caller
         unsafe {
             resource.insert(value, change_tick, caller);
         }
+        self.record_changes([WorldChange::ResourceInserted(component_id)]);
+
+        let events: &[_] = if present {
+            &[ON_INSERT]
+        } else {
+            &[ON_ADD, ON_INSERT]
+        };
+        if self.trigger_resource_observers(events, component_id, caller) || replaced {
+            self.flush();
+        }
     }
 
     /// # Panics
@@ -2909,6 +3068,8 @@ impl World {
     pub(crate) fn flush_entities(&mut self) {
         let empty_archetype = self.archetypes.empty_mut();
         let table = &mut self.storages.tables[empty_archetype.table_id()];
+        let mut spawned = Vec::new();
+        let record_spawned = self.change_log.is_some();
         // PERF: consider pre-allocating space for flushed entities
         // SAFETY: entity is set to a valid location
         unsafe {
@@ -2916,8 +3077,12 @@ impl World {
                 // SAFETY: no components are allocated by archetype.allocate() because the archetype
                 // is empty
                 *location = empty_archetype.allocate(entity, table.allocate(entity));
+                if record_spawned {
+                    spawned.push(entity);
+                }
             });
         }
+        self.record_changes(spawned.into_iter().map(WorldChange::Spawned));
     }
 
     /// Applies any commands in the world's internal [`CommandQueue`].
@@ -3150,6 +3315,7 @@ impl World {
             schedules.check_change_ticks(change_tick);
         }
 
+        self.last_resource_change_tick.check_tick(change_tick);
         self.last_check_tick = change_tick;
     }
 
@@ -3504,11 +3670,23 @@ impl World {
     ///
     /// **You should prefer to use the typed API [`World::remove_resource`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
+    #[track_caller]
     pub fn remove_resource_by_id(&mut self, component_id: ComponentId) -> Option<()> {
-        self.storages
-            .resources
-            .get_mut(component_id)?
-            .remove_and_drop();
+        if !self.storages.resources.get(component_id)?.is_present() {
+            return Some(());
+        }
+        let triggered = self.trigger_resource_observers(
+            &[ON_REPLACE, ON_REMOVE],
+            component_id,
+            MaybeLocation::caller(),
+        );
+        if let Some(resource) = self.storages.resources.get_mut(component_id) {
+            resource.remove_and_drop();
+        }
+        self.record_changes([WorldChange::ResourceRemoved(component_id)]);
+        if triggered {
+            self.flush();
+        }
         Some(())
     }
 
@@ -3519,11 +3697,28 @@ impl World {
     ///
     /// # Panics
     /// This function will panic if it isn't called from the same thread that the resource was inserted from.
+    #[track_caller]
     pub fn remove_non_send_by_id(&mut self, component_id: ComponentId) -> Option<()> {
-        self.storages
+        if !self
+            .storages
             .non_send_resources
-            .get_mut(component_id)?
-            .remove_and_drop();
+            .get(component_id)?
+            .is_present()
+        {
+            return Some(());
+        }
+        let triggered = self.trigger_resource_observers(
+            &[ON_REPLACE, ON_REMOVE],
+            component_id,
+            MaybeLocation::caller(),
+        );
+        if let Some(resource) = self.storages.non_send_resources.get_mut(component_id) {
+            resource.remove_and_drop();
+        }
+        self.record_changes([WorldChange::ResourceRemoved(component_id)]);
+        if triggered {
+            self.flush();
+        }
         Some(())
     }
 
@@ -3693,6 +3888,16 @@ impl World {
     }
 }
 
+/// Panics because the resource `R` was removed by one of its observers while it was inserted.
+#[cold]
+#[track_caller]
+fn resource_removed_by_observer<R: Resource>() -> ! {
+    panic!(
+        "Resource `{}` was removed by an observer while it was being inserted.",
+        core::any::type_name::<R>()
+    )
+}
+
 impl fmt::Debug for World {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // SAFETY: `UnsafeWorldCell` requires that this must only access metadata.
@@ -3767,7 +3972,7 @@ mod tests {
     use crate::{
         change_detection::{DetectChangesMut, MaybeLocation},
         component::{ComponentCloneBehavior, ComponentDescriptor, ComponentInfo, StorageType},
-        entity::EntityHashSet,
+        entity::{AllocAtError, Entity, EntityAllocationMode, EntityHashSet},
         entity_disabling::{DefaultQueryFilters, Disabled},
         ptr::OwningPtr,
         resource::Resource,
@@ -4316,6 +4521,42 @@ mod tests {
         world.spawn(());
     }
 
+    #[test]
+    fn sequential_entity_allocation() {
+        #[derive(Component, Debug, PartialEq)]
+        struct Num(u32);
+
+        let spawn_after_despawns = |despawn_order: [usize; 3]| {
+            let mut world = World::new();
+            world.set_entity_allocation_mode(EntityAllocationMode::Sequential);
+            let entities = [(); 3].map(|_| world.spawn_empty().id());
+            for i in despawn_order {
+                world.despawn(entities[i]);
+            }
+            let reserved = world.commands().spawn(Num(0)).id();
+            world.flush();
+            let spawned = world.spawn(Num(1)).id();
+            (world, [reserved, spawned])
+        };
+
+        let (_, ids) = spawn_after_despawns([0, 1, 2]);
+        let (mut world, other_ids) = spawn_after_despawns([2, 1, 0]);
+        assert_eq!(ids, other_ids);
+
+        let replicated = Entity::from_raw(10);
+        assert_eq!(world.spawn_at(replicated, Num(2)).unwrap().id(), replicated);
+        assert_eq!(world.get::<Num>(replicated), Some(&Num(2)));
+        assert_eq!(
+            world.spawn_at(ids[0], Num(3)).err(),
+            Some(AllocAtError::InUse {
+                requested: ids[0],
+                current: ids[0]
+            })
+        );
+        assert!(world.spawn_at(Entity::from_raw(0), Num(4)).is_err());
+        assert_eq!(world.entities().len(), 3);
+    }
+
     #[test]
     fn get_entity() {
         let mut world = World::new();
@@ -0,0 +1,714 @@
+//! Capturing and restoring the state of a [`World`] with [`WorldSnapshot`].
+
+use crate::{
+    archetype::{Archetype, ArchetypeEntity},
+    change_detection::MaybeLocation,
+    component::{Component, ComponentId, ComponentTicks, StorageType},
+    entity::{EntitiesAllocatorState, Entity},
+    query::DebugCheckedUnwrap,
+    resource::Resource,
+    world::{EntityWorldMut, World, WorldId},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use bevy_ptr::{OwningPtr, UnsafeCellDeref};
+use fixedbitset::FixedBitSet;
+
+/// Records which components and resources are captured by [`World::take_snapshot`].
+///
+/// Components are opted in with [`World::register_snapshot`], and resources with
+/// [`World::register_snapshot_resource`].
+#[derive(Resource, Default)]
+pub struct SnapshotRegistry {
+    components: HashMap<ComponentId, CaptureComponentFn>,
+    resources: HashMap<ComponentId, CaptureResourceFn>,
+}
+
+impl SnapshotRegistry {
+    /// Returns `true` if the component with the given id is captured by snapshots.
+    pub fn contains_component(&self, id: ComponentId) -> bool {
+        self.components.contains_key(&id)
+    }
+
+    /// Returns `true` if the resource with the given id is captured by snapshots.
+    pub fn contains_resource(&self, id: ComponentId) -> bool {
+        self.resources.contains_key(&id)
+    }
+}
+
+type CaptureComponentFn = fn(&World, &Archetype, ComponentId) -> SnapshotColumn;
+type CaptureResourceFn = fn(&World, ComponentId) -> Option<SnapshotColumn>;
+
+/// An owned copy of the entities of a [`World`] along with the values and change ticks of its
+/// snapshot components and resources.
+///
+/// Snapshots are created with [`World::take_snapshot`] and applied with
+/// [`World::restore_snapshot`]. Unlike a [`DynamicScene`], a snapshot clones storage directly
+/// instead of going through reflection, and preserves entity ids and generations as well as the
+/// order in which freed ids are reused.
+///
+/// A snapshot can be restored any number of times, but only to the world it was taken from.
+///
+/// [`DynamicScene`]: https://docs.rs/bevy/latest/bevy/scene/struct.DynamicScene.html
+pub struct WorldSnapshot {
+    world_id: WorldId,
+    entities: EntitiesAllocatorState,
+    /// The components registered when the snapshot was taken, sorted.
+    registered: Vec<ComponentId>,
+    archetypes: Vec<ArchetypeSnapshot>,
+    resources: Vec<(ComponentId, Option<SnapshotColumn>)>,
+}
+
+impl WorldSnapshot {
+    /// Returns the id of the [`World`] this snapshot was taken from.
+    #[inline]
+    pub fn world_id(&self) -> WorldId {
+        self.world_id
+    }
+
+    /// Returns `true` if `entity` was alive when this snapshot was taken.
+    #[inline]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(entity)
+    }
+
+    /// Iterates over the entities that were alive when this snapshot was taken.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter_alive()
+    }
+}
+
+/// The snapshot components of the entities of a single [`Archetype`].
+struct ArchetypeSnapshot {
+    entities: Vec<Entity>,
+    components: Vec<(ComponentId, StorageType)>,
+    /// One column per entry of `components`, each with one value per entry of `entities`.
+    columns: Vec<SnapshotColumn>,
+}
+
+/// The cloned values of a component or resource and their change ticks.
+struct SnapshotColumn {
+    ticks: Vec<ComponentTicks>,
+    values: Box<dyn SnapshotValues>,
+}
+
+/// Type-erased storage for the values of a [`SnapshotColumn`].
+trait SnapshotValues: Send + Sync {
+    /// Clones the value at `index` and passes ownership of the clone to `f`.
+    fn clone_value(&self, index: usize, f: &mut dyn FnMut(OwningPtr<'_>));
+}
+
+impl<T: Clone + Send + Sync + 'static> SnapshotValues for Vec<T> {
+    fn clone_value(&self, index: usize, f: &mut dyn FnMut(OwningPtr<'_>)) {
+        OwningPtr::make(self[index].clone(), f);
+    }
+}
+
+fn capture_component<C: Component + Clone>(
+    world: &World,
+    archetype: &Archetype,
+    component_id: ComponentId,
+) -> SnapshotColumn {
+    let mut values = Vec::<C>::with_capacity(archetype.len());
+    let mut ticks = Vec::with_capacity(archetype.len());
+    match C::STORAGE_TYPE {
+        StorageType::Table => {
+            let table = &world.storages.tables[archetype.table_id()];
+            for archetype_entity in archetype.entities() {
+                let row = archetype_entity.table_row();
+                // SAFETY:
+                // - `row` is the row of an entity of `archetype`, so it is in bounds of its table.
+                // - The table stores `C` for `component_id`, since `archetype` contains it.
+                unsafe {
+                    let value = table
+                        .get_component(component_id, row)
+                        .debug_checked_unwrap();
+                    values.push(value.deref::<C>().clone());
+                    ticks.push(
+                        table
+                            .get_ticks_unchecked(component_id, row)
+                            .debug_checked_unwrap(),
+                    );
+                }
+            }
+        }
+        StorageType::SparseSet => {
+            // SAFETY: `archetype` contains `component_id` as a sparse set component.
+            let sparse_set = unsafe {
+                world
+                    .storages
+                    .sparse_sets
+                    .get(component_id)
+                    .debug_checked_unwrap()
+            };
+            for archetype_entity in archetype.entities() {
+                // SAFETY:
+                // - Every entity of `archetype` has a value in the sparse set.
+                // - The sparse set stores `C` for `component_id`.
+                // - `world` is borrowed immutably, so no mutable references to the ticks exist.
+                unsafe {
+                    let (value, tick_cells, _) = sparse_set
+                        .get_with_ticks(archetype_entity.id())
+                        .debug_checked_unwrap();
+                    values.push(value.deref::<C>().clone());
+                    ticks.push(tick_cells.read());
+                }
+            }
+        }
+    }
+    SnapshotColumn {
+        ticks,
+        values: Box::new(values),
+    }
+}
+
+fn capture_resource<R: Resource + Clone>(
+    world: &World,
+    component_id: ComponentId,
+) -> Option<SnapshotColumn> {
+    let resource = world.storages.resources.get(component_id)?;
+    let ticks = resource.get_ticks()?;
+    // SAFETY: The resource with id `component_id` has type `R`.
+    let value = unsafe { resource.get_data()?.deref::<R>() }.clone();
+    Some(SnapshotColumn {
+        ticks: vec![ticks],
+        values: Box::new(vec![value]),
+    })
+}
+
+impl World {
+    /// Opts the component `C` into the [`WorldSnapshot`]s taken with [`World::take_snapshot`],
+    /// returning its [`ComponentId`].
+    ///
+    /// [`World::restore_snapshot`] leaves components that are not registered untouched on
+    /// entities that are still alive. They are lost for entities that have been despawned since
+    /// the snapshot was taken.
+    pub fn register_snapshot<C: Component + Clone>(&mut self) -> ComponentId {
+        let component_id = self.register_component::<C>();
+        self.get_resource_or_init::<SnapshotRegistry>()
+            .components
+            .insert(component_id, capture_component::<C>);
+        component_id
+    }
+
+    /// Opts the resource `R` into the [`WorldSnapshot`]s taken with [`World::take_snapshot`],
+    /// returning its [`ComponentId`].
+    pub fn register_snapshot_resource<R: Resource + Clone>(&mut self) -> ComponentId {
+        let component_id = self.register_resource::<R>();
+        self.get_resource_or_init::<SnapshotRegistry>()
+            .resources
+            .insert(component_id, capture_resource::<R>);
+        component_id
+    }
+
+    /// Captures the entities of this world, along with the values and change ticks of every
+    /// component and resource registered with [`World::register_snapshot`] and
+    /// [`World::register_snapshot_resource`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_snapshot::<Health>();
+    /// let entity = world.spawn(Health(10)).id();
+    ///
+    /// let snapshot = world.take_snapshot();
+    /// world.get_mut::<Health>(entity).unwrap().0 = 0;
+    /// world.despawn(entity);
+    ///
+    /// world.restore_snapshot(&snapshot);
+    /// assert_eq!(world.get::<Health>(entity), Some(&Health(10)));
+    /// ```
+    pub fn take_snapshot(&mut self) -> WorldSnapshot {
+        self.flush();
+
+        let default_registry = SnapshotRegistry::default();
+        let registry = self
+            .get_resource::<SnapshotRegistry>()
+            .unwrap_or(&default_registry);
+
+        let mut archetypes = Vec::new();
+        for archetype in self.archetypes.iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            let mut components = Vec::new();
+            let mut columns = Vec::new();
+            for component_id in archetype.components() {
+                let Some(capture) = registry.components.get(&component_id) else {
+                    continue;
+                };
+                // SAFETY: `component_id` is a component of `archetype`.
+                let storage_type = unsafe {
+                    archetype
+                        .get_storage_type(component_id)
+                        .debug_checked_unwrap()
+                };
+                components.push((component_id, storage_type));
+                columns.push(capture(self, archetype, component_id));
+            }
+            if !components.is_empty() {
+                archetypes.push(ArchetypeSnapshot {
+                    entities: archetype
+                        .entities()
+                        .iter()
+                        .map(ArchetypeEntity::id)
+                        .collect(),
+                    components,
+                    columns,
+                });
+            }
+        }
+
+        let mut registered: Vec<_> = registry.components.keys().copied().collect();
+        registered.sort_unstable();
+
+        WorldSnapshot {
+            world_id: self.id(),
+            entities: self.entities.allocator_state(),
+            registered,
+            archetypes,
+            resources: registry
+                .resources
+                .iter()
+                .map(|(&component_id, capture)| (component_id, capture(self, component_id)))
+                .collect(),
+        }
+    }
+
+    /// Restores the entities, snapshot components and snapshot resources captured by
+    /// [`World::take_snapshot`], along with their change ticks.
+    ///
+    /// After this call, the entities that were alive when the snapshot was taken are alive again
+    /// with the same ids, and future allocations return the same ids they would have returned
+    /// right after the snapshot was taken.
+    ///
+    /// - Entities spawned since the snapshot was taken are despawned. Their snapshot components
+    ///   are dropped first without running hooks or observers, then the entity is despawned
+    ///   normally.
+    /// - Snapshot components and resources are written directly to storage: no hooks or
+    ///   observers run, and no [`RemovedComponents`](crate::removal_detection::RemovedComponents)
+    ///   events are sent.
+    /// - Components that are not registered for snapshots are left untouched on entities that
+    ///   are still alive, and are not restored on entities that have been despawned since.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `snapshot` was taken from a different world.
+    #[track_caller]
+    pub fn restore_snapshot(&mut self, snapshot: &WorldSnapshot) {
+        assert_eq!(
+            self.id(),
+            snapshot.world_id,
+            "Attempted to restore a `WorldSnapshot` that was taken from a different `World`."
+        );
+        let caller = MaybeLocation::caller();
+        self.flush();
+
+        // Snapshot components are dropped before despawning, so that the hooks of relationships
+        // cannot reach entities that are about to be restored.
+        loop {
+            let spawned: Vec<Entity> = self
+                .archetypes
+                .iter()
+                .flat_map(Archetype::entities)
+                .map(ArchetypeEntity::id)
+                .filter(|&entity| !snapshot.entities.contains(entity))
+                .collect();
+            if spawned.is_empty() {
+                break;
+            }
+            for entity in spawned {
+                // The hooks of a previously despawned entity may have despawned this one.
+                if self.entities.get(entity).is_some() {
+                    // SAFETY: `entity` is alive.
+                    unsafe {
+                        self.write_snapshot_components(entity, &snapshot.registered, None, caller);
+                    }
+                    self.despawn(entity);
+                }
+            }
+            self.flush();
+        }
+
+        self.entities.restore_allocator_state(&snapshot.entities);
+        for entity in snapshot.entities.iter_alive() {
+            if self.entities.get(entity).is_none() {
+                // SAFETY: `entity` was allocated when restoring the allocator state above, and has
+                // no location yet.
+                unsafe {
+                    self.spawn_at_empty_internal(entity, caller);
+                }
+            }
+        }
+
+        let mut restored = FixedBitSet::with_capacity(self.entities.total_count());
+        for archetype in &snapshot.archetypes {
+            for (row, &entity) in archetype.entities.iter().enumerate() {
+                restored.insert(entity.index() as usize);
+                // SAFETY:
+                // - Every entity of the snapshot is alive.
+                // - `registered` contains every component captured in `archetype`.
+                unsafe {
+                    self.write_snapshot_components(
+                        entity,
+                        &snapshot.registered,
+                        Some((archetype, row)),
+                        caller,
+                    );
+                }
+            }
+        }
+        for entity in snapshot.entities.iter_alive() {
+            if !restored.contains(entity.index() as usize) {
+                // SAFETY: Every entity of the snapshot is alive.
+                unsafe {
+                    self.write_snapshot_components(entity, &snapshot.registered, None, caller);
+                }
+            }
+        }
+
+        for (component_id, column) in &snapshot.resources {
+            if let Some(column) = column {
+                let resource = self.initialize_resource_internal(*component_id);
+                column.values.clone_value(0, &mut |value| {
+                    // SAFETY: `value` was cloned from the resource with id `component_id`.
+                    unsafe { resource.insert_with_ticks(value, column.ticks[0], caller) };
+                });
+            } else if let Some(resource) = self.storages.resources.get_mut(*component_id) {
+                resource.remove_and_drop();
+            }
+        }
+    }
+
+    /// Moves `entity` to the archetype made of its components that are not `registered` and the
+    /// components captured in `source`, then writes the values and ticks of `source`.
+    ///
+    /// Other `registered` components of `entity` are dropped. No hooks or observers are run.
+    ///
+    /// # Safety
+    /// - `entity` must be alive.
+    /// - `registered` must be sorted and contain every component captured in `source`.
+    unsafe fn write_snapshot_components(
+        &mut self,
+        entity: Entity,
+        registered: &[ComponentId],
+        source: Option<(&ArchetypeSnapshot, usize)>,
+        caller: MaybeLocation,
+    ) {
+        // SAFETY: The caller ensures that `entity` is alive.
+        let location = unsafe { self.entities.get(entity).debug_checked_unwrap() };
+        let old_archetype = &self.archetypes[location.archetype_id];
+        let source_components = source.map_or(&[][..], |(archetype, _)| &archetype.components);
+
+        let mut table_components = Vec::new();
+        let mut sparse_set_components = Vec::new();
+        let mut dropped_sparse_set_components = Vec::new();
+        for component_id in old_archetype.components() {
+            let is_registered = registered.binary_search(&component_id).is_ok();
+            // SAFETY: `component_id` is a component of `old_archetype`.
+            match unsafe {
+                old_archetype
+                    .get_storage_type(component_id)
+                    .debug_checked_unwrap()
+            } {
+                StorageType::Table if !is_registered => table_components.push(component_id),
+                StorageType::SparseSet if !is_registered => {
+                    sparse_set_components.push(component_id);
+                }
+                StorageType::SparseSet
+                    if !source_components.iter().any(|&(id, _)| id == component_id) =>
+                {
+                    dropped_sparse_set_components.push(component_id);
+                }
+                _ => {}
+            }
+        }
+        for &(component_id, storage_type) in source_components {
+            match storage_type {
+                StorageType::Table => table_components.push(component_id),
+                StorageType::SparseSet => sparse_set_components.push(component_id),
+            }
+        }
+        table_components.sort_unstable();
+        sparse_set_components.sort_unstable();
+
+        // SAFETY: All components come from an existing archetype or from a snapshot of this world.
+        let new_archetype_id = unsafe {
+            let table_id = self
+                .storages
+                .tables
+                .get_id_or_insert(&table_components, &self.components);
+            for &component_id in &sparse_set_components {
+                self.storages
+                    .sparse_sets
+                    .get_or_insert(self.components.get_info_unchecked(component_id));
+            }
+            self.archetypes.get_id_or_insert(
+                &self.components,
+                &self.observers,
+                table_id,
+                table_components,
+                sparse_set_components,
+            )
+        };
+
+        for component_id in dropped_sparse_set_components {
+            // SAFETY: `component_id` is a sparse set component of the old archetype.
+            let sparse_set = unsafe {
+                self.storages
+                    .sparse_sets
+                    .get_mut(component_id)
+                    .debug_checked_unwrap()
+            };
+            sparse_set.remove(entity);
+        }
+
+        let old_table_id = location.table_id;
+        let mut new_location = location;
+        if new_archetype_id != location.archetype_id {
+            // SAFETY:
+            // - Table components missing from the new archetype are registered, and are dropped.
+            // - The table components of `source` that the entity did not have are the only ones
+            //   left uninitialized, and are initialized right below.
+            unsafe {
+                EntityWorldMut::move_entity_from_remove::<true>(
+                    entity,
+                    &mut new_location,
+                    location.archetype_id,
+                    location,
+                    &mut self.entities,
+                    &mut self.archetypes,
+                    &mut self.storages,
+                    new_archetype_id,
+                );
+            }
+        }
+
+        let Some((archetype, row)) = source else {
+            return;
+        };
+        for (&(component_id, storage_type), snapshot_column) in
+            archetype.components.iter().zip(&archetype.columns)
+        {
+            let ticks = snapshot_column.ticks[row];
+            match storage_type {
+                StorageType::Table => {
+                    let initialized = self.storages.tables[old_table_id].has_column(component_id);
+                    let table = &mut self.storages.tables[new_location.table_id];
+                    // SAFETY: The new archetype contains `component_id` as a table component.
+                    let column =
+                        unsafe { table.get_column_mut(component_id).debug_checked_unwrap() };
+                    snapshot_column.values.clone_value(row, &mut |value| {
+                        // SAFETY:
+                        // - `new_location.table_row` is the row of `entity` in its table.
+                        // - `value` was cloned from a component with id `component_id`.
+                        // - The value is initialized if and only if the old table had the column.
+                        unsafe {
+                            if initialized {
+                                column.replace(
+                                    new_location.table_row,
+                                    value,
+                                    ticks.changed,
+                                    caller,
+                                );
+                            } else {
+                                column.initialize(
+                                    new_location.table_row,
+                                    value,
+                                    ticks.changed,
+                                    caller,
+                                );
+                            }
+                        }
+                    });
+                    // SAFETY: The table is borrowed mutably, so no other reference to the tick exists.
+                    unsafe {
+                        *table
+                            .get_added_tick(component_id, new_location.table_row)
+                            .debug_checked_unwrap()
+                            .deref_mut() = ticks.added;
+                    }
+                }
+                StorageType::SparseSet => {
+                    // SAFETY: The sparse set was created when computing the new archetype.
+                    let sparse_set = unsafe {
+                        self.storages
+                            .sparse_sets
+                            .get_mut(component_id)
+                            .debug_checked_unwrap()
+                    };
+                    snapshot_column.values.clone_value(row, &mut |value| {
+                        // SAFETY: `value` was cloned from a component with id `component_id`.
+                        unsafe { sparse_set.insert(entity, value, ticks.changed, caller) };
+                    });
+                    // SAFETY: The sparse set is borrowed mutably, so no other reference to the
+                    // tick exists.
+                    unsafe {
+                        *sparse_set
+                            .get_added_tick(entity)
+                            .debug_checked_unwrap()
+                            .deref_mut() = ticks.added;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        change_detection::DetectChanges,
+        component::Component,
+        hierarchy::{ChildOf, Children},
+        resource::Resource,
+        world::World,
+    };
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct A(u32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct B(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct NotSnapshot(u32);
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct R(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.register_snapshot::<A>();
+        world.register_snapshot::<B>();
+        world.register_snapshot_resource::<R>();
+        world
+    }
+
+    #[test]
+    fn restore_values() {
+        let mut world = world();
+        let e1 = world.spawn((A(1), B(1))).id();
+        let e2 = world.spawn(A(2)).id();
+        let snapshot = world.take_snapshot();
+
+        world.get_mut::<A>(e1).unwrap().0 = 10;
+        world.entity_mut(e1).remove::<B>();
+        world.entity_mut(e2).insert(B(20));
+
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.get::<A>(e1), Some(&A(1)));
+        assert_eq!(world.get::<B>(e1), Some(&B(1)));
+        assert_eq!(world.get::<A>(e2), Some(&A(2)));
+        assert_eq!(world.get::<B>(e2), None);
+
+        // Snapshots can be restored more than once.
+        world.get_mut::<A>(e2).unwrap().0 = 30;
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.get::<A>(e2), Some(&A(2)));
+    }
+
+    #[test]
+    fn restore_entities() {
+        let mut world = world();
+        let e1 = world.spawn(A(1)).id();
+        let e2 = world.spawn(B(2)).id();
+        let e3 = world.spawn_empty().id();
+        world.despawn(e3);
+        let snapshot = world.take_snapshot();
+        let next = world.spawn_empty().id();
+        world.restore_snapshot(&snapshot);
+
+        world.despawn(e1);
+        let spawned = world.spawn(A(3)).id();
+        world.restore_snapshot(&snapshot);
+
+        assert!(!world.entities().contains(spawned));
+        assert_eq!(world.get::<A>(e1), Some(&A(1)));
+        assert_eq!(world.get::<B>(e2), Some(&B(2)));
+        assert_eq!(world.entities().len(), 2);
+        // Allocation continues exactly as it would have right after the snapshot.
+        assert_eq!(world.spawn_empty().id(), next);
+    }
+
+    #[test]
+    fn restore_ticks() {
+        let mut world = world();
+        let entity = world.spawn((A(1), B(1))).id();
+        world.insert_resource(R(1));
+        let added = world.change_tick();
+        world.increment_change_tick();
+        world.get_mut::<A>(entity).unwrap().0 = 2;
+        let changed = world.change_tick();
+        let snapshot = world.take_snapshot();
+
+        world.increment_change_tick();
+        world.entity_mut(entity).insert((A(3), B(3)));
+        world.resource_mut::<R>().0 = 3;
+        world.restore_snapshot(&snapshot);
+
+        let a = world.entity(entity).get_change_ticks::<A>().unwrap();
+        assert_eq!((a.added, a.changed), (added, changed));
+        let b = world.entity(entity).get_change_ticks::<B>().unwrap();
+        assert_eq!((b.added, b.changed), (added, added));
+        let r = world.resource_ref::<R>();
+        assert_eq!(*r, R(1));
+        assert_eq!(r.last_changed(), added);
+    }
+
+    #[test]
+    fn restore_resources() {
+        let mut world = world();
+        let snapshot = world.take_snapshot();
+        world.insert_resource(R(1));
+        world.restore_snapshot(&snapshot);
+        assert!(!world.contains_resource::<R>());
+
+        world.insert_resource(R(2));
+        let snapshot = world.take_snapshot();
+        world.remove_resource::<R>();
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.get_resource::<R>(), Some(&R(2)));
+    }
+
+    #[test]
+    fn unregistered_components_are_kept() {
+        let mut world = world();
+        let entity = world.spawn((A(1), NotSnapshot(1))).id();
+        let snapshot = world.take_snapshot();
+
+        world.entity_mut(entity).insert(B(2)).remove::<A>();
+        world.get_mut::<NotSnapshot>(entity).unwrap().0 = 2;
+        world.restore_snapshot(&snapshot);
+
+        assert_eq!(world.get::<A>(entity), Some(&A(1)));
+        assert_eq!(world.get::<B>(entity), None);
+        assert_eq!(world.get::<NotSnapshot>(entity), Some(&NotSnapshot(2)));
+    }
+
+    #[test]
+    fn restore_despawns_spawned_children() {
+        let mut world = world();
+        let parent = world.spawn(A(0)).id();
+        let snapshot = world.take_snapshot();
+
+        let child = world.spawn((A(1), ChildOf(parent))).id();
+        world.restore_snapshot(&snapshot);
+
+        assert!(world.get_entity(child).is_err());
+        assert_eq!(world.get::<A>(parent), Some(&A(0)));
+        assert!(world.get::<Children>(parent).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn restore_to_other_world() {
+        let mut world = world();
+        let snapshot = world.take_snapshot();
+        World::new().restore_snapshot(&snapshot);
+    }
+}
@@ -947,7 +947,11 @@ impl<'w> UnsafeEntityCell<'w> {
             .map(|(value, cells, caller)| Mut {
                 // SAFETY: returned component is of type T
                 value: value.assert_unique().deref_mut::<T>(),
-                ticks: TicksMut::from_tick_cells(cells, last_change_tick, change_tick),
+                ticks: TicksMut::from_tick_cells(cells, last_change_tick, change_tick)
+                    .with_changed_entities(
+                        self.world.components().changed_entities(component_id),
+                        self.entity,
+                    ),
                 changed_by: caller.map(|caller| caller.deref_mut()),
             })
         }
@@ -1074,6 +1078,10 @@ impl<'w> UnsafeEntityCell<'w> {
                     cells,
                     self.world.last_change_tick(),
                     self.world.change_tick(),
+                )
+                .with_changed_entities(
+                    self.world.components().changed_entities(component_id),
+                    self.entity,
                 ),
                 changed_by: caller.map(|caller| caller.deref_mut()),
             })
@@ -1122,6 +1130,10 @@ impl<'w> UnsafeEntityCell<'w> {
                     cells,
                     self.world.last_change_tick(),
                     self.world.change_tick(),
+                )
+                .with_changed_entities(
+                    self.world.components().changed_entities(component_id),
+                    self.entity,
                 ),
                 changed_by: caller.map(|caller| caller.deref_mut()),
             })
@@ -9,7 +9,7 @@ use bevy_reflect::TypePath;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
-use bevy_asset::{Asset, RenderAssetUsages};
+use bevy_asset::{Asset, AssetSize, RenderAssetUsages};
 use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, Xyza};
 use bevy_math::{AspectRatio, UVec2, UVec3, Vec2};
 use core::hash::Hash;
@@ -696,6 +696,14 @@ impl From<SamplerDescriptor<Option<&str>>> for ImageSamplerDescriptor {
     }
 }
 
+impl AssetSize for Image {
+    /// Returns the size of the pixel data stored on the CPU, which is zero once it has been
+    /// removed from the main world.
+    fn asset_size(&self) -> usize {
+        self.data.as_ref().map_or(0, Vec::len)
+    }
+}
+
 impl Default for Image {
     /// default is a 1x1x1 all '1.0' texture
     fn default() -> Self {
@@ -9,6 +9,7 @@ plugin_group! {
         bevy_app:::TaskPoolPlugin,
         bevy_diagnostic:::FrameCountPlugin,
         bevy_time:::TimePlugin,
+        bevy_app:::PrefabPlugin,
         bevy_transform:::TransformPlugin,
         bevy_diagnostic:::DiagnosticsPlugin,
         bevy_input:::InputPlugin,
@@ -22,6 +23,9 @@ plugin_group! {
         #[custom(cfg(any(unix, windows)))]
         bevy_app:::TerminalCtrlCHandlerPlugin,
         #[cfg(feature = "bevy_asset")]
+        #[custom(cfg(all(any(feature = "http", feature = "https"), not(target_arch = "wasm32"))))]
+        bevy_asset::io::web:::WebAssetPlugin,
+        #[cfg(feature = "bevy_asset")]
         bevy_asset:::AssetPlugin,
         #[cfg(feature = "bevy_scene")]
         bevy_scene:::ScenePlugin,
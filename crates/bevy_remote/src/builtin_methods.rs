@@ -1,6 +1,6 @@
 //! Built-in verbs for the Bevy Remote Protocol.
 
-use core::any::TypeId;
+use core::any::{type_name, TypeId};
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
@@ -11,8 +11,9 @@ use bevy_ecs::{
     query::QueryBuilder,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{InternedScheduleLabel, NodeId, Schedule, Schedules, Stepping, SystemSkips},
     system::{In, Local},
-    world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
+    world::{EntityRef, EntityWorldMut, FilteredEntityRef, Mut, World},
 };
 use bevy_platform_support::collections::HashMap;
 use bevy_reflect::{
@@ -31,6 +32,12 @@ use crate::{
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
 use {crate::schemas::open_rpc::ServerObject, bevy_utils::default};
 
+#[cfg(feature = "bevy_asset")]
+use bevy_asset::{
+    AssetDependencyNode, AssetPath, AssetServer, DependencyLoadState, LoadState,
+    RecursiveDependencyLoadState, UntypedAssetId,
+};
+
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
 
@@ -82,6 +89,37 @@ pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/stepping/schedules` request.
+pub const BRP_STEPPING_SCHEDULES_METHOD: &str = "bevy/stepping/schedules";
+
+/// The method path for a `bevy/stepping/enable` request.
+pub const BRP_STEPPING_ENABLE_METHOD: &str = "bevy/stepping/enable";
+
+/// The method path for a `bevy/stepping/disable` request.
+pub const BRP_STEPPING_DISABLE_METHOD: &str = "bevy/stepping/disable";
+
+/// The method path for a `bevy/stepping/set_breakpoint` request.
+pub const BRP_STEPPING_SET_BREAKPOINT_METHOD: &str = "bevy/stepping/set_breakpoint";
+
+/// The method path for a `bevy/stepping/clear_breakpoint` request.
+pub const BRP_STEPPING_CLEAR_BREAKPOINT_METHOD: &str = "bevy/stepping/clear_breakpoint";
+
+/// The method path for a `bevy/stepping/step` request.
+pub const BRP_STEPPING_STEP_METHOD: &str = "bevy/stepping/step";
+
+/// The method path for a `bevy/stepping/continue` request.
+pub const BRP_STEPPING_CONTINUE_METHOD: &str = "bevy/stepping/continue";
+
+/// The method path for a `bevy/stepping/cursor` request.
+pub const BRP_STEPPING_CURSOR_METHOD: &str = "bevy/stepping/cursor";
+
+/// The method path for a `bevy/system_skips` request.
+pub const BRP_SYSTEM_SKIPS_METHOD: &str = "bevy/system_skips";
+
+/// The method path for a `bevy/asset_dependencies` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_DEPENDENCIES_METHOD: &str = "bevy/asset_dependencies";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
@@ -295,6 +333,253 @@ pub struct BrpMutateResourceParams {
     pub value: Value,
 }
 
+/// `bevy/stepping/enable`: Enables stepping, optionally for additional
+/// schedules.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSteppingEnableParams {
+    /// The names of the schedules to enable stepping for, in addition to
+    /// those it is already enabled for, e.g. `Update`.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+}
+
+/// `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`:
+/// Sets or clears a breakpoint on a system.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingBreakpointParams {
+    /// The name of the schedule containing the system, e.g. `Update`.
+    pub schedule: String,
+
+    /// The system instance or instances to set or clear the breakpoint on.
+    pub system: BrpSteppingSystem,
+}
+
+/// Identifies one or more system instances in a schedule.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum BrpSteppingSystem {
+    /// The index of a single system instance, as reported by
+    /// `bevy/stepping/schedules`.
+    Index(usize),
+
+    /// The name of the system. Either the full name, e.g.
+    /// `my_game::movement`, or a suffix of it following a `::`, e.g.
+    /// `movement`. This refers to every instance of the system in the
+    /// schedule.
+    Name(String),
+}
+
+/// The response to a `bevy/stepping/schedules` request.
+pub type BrpSteppingSchedulesResponse = Vec<BrpSteppingSchedule>;
+
+/// A schedule with stepping enabled, as reported by `bevy/stepping/schedules`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingSchedule {
+    /// The name of the schedule.
+    pub schedule: String,
+
+    /// The systems of the schedule, in the order in which they are stepped.
+    pub systems: Vec<BrpSteppingSystemInfo>,
+}
+
+/// A system in a [`BrpSteppingSchedule`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingSystemInfo {
+    /// The index of the system instance in the schedule.
+    pub index: usize,
+
+    /// The full name of the system.
+    pub name: String,
+
+    /// Whether execution stops before this system when continuing.
+    pub breakpoint: bool,
+}
+
+/// The response to a `bevy/stepping/cursor` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursorResponse {
+    /// Whether stepping is enabled.
+    pub enabled: bool,
+
+    /// The next system to be stepped, if stepping is enabled and the current
+    /// stepping frame has systems left to run.
+    pub cursor: Option<BrpSteppingCursor>,
+}
+
+/// The position of the stepping cursor in a [`BrpSteppingCursorResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursor {
+    /// The name of the schedule containing the next system.
+    pub schedule: String,
+
+    /// The index of the next system instance in the schedule.
+    pub index: usize,
+
+    /// The full name of the next system, if its schedule isn't running.
+    pub name: Option<String>,
+}
+
+/// The response to a `bevy/system_skips` request.
+pub type BrpSystemSkipsResponse = Vec<BrpScheduleSkips>;
+
+/// The skipped systems of a schedule, as reported by `bevy/system_skips`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleSkips {
+    /// The name of the schedule.
+    pub schedule: String,
+
+    /// The systems of the schedule that were skipped, in the order in which
+    /// they were first skipped.
+    pub systems: Vec<BrpSystemSkip>,
+}
+
+/// `bevy/asset_dependencies`: Retrieves the dependency graph of the assets loaded from a path.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependenciesParams {
+    /// The path of the asset, including its asset source and label, if any.
+    pub path: String,
+}
+
+/// The response to a `bevy/asset_dependencies` request.
+///
+/// This contains one graph for every asset loaded from the path, since the
+/// path may be loaded as more than one asset type.
+#[cfg(feature = "bevy_asset")]
+pub type BrpAssetDependenciesResponse = Vec<BrpAssetDependencyGraph>;
+
+/// The dependency graph of an asset, as reported by `bevy/asset_dependencies`.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependencyGraph {
+    /// The ID of the asset loaded from the requested path.
+    pub root: String,
+
+    /// The asset and all of its recursive dependencies, sorted by path.
+    pub nodes: Vec<BrpAssetDependencyNode>,
+}
+
+/// An asset in a [`BrpAssetDependencyGraph`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependencyNode {
+    /// The ID of the asset. This is only meant to identify the asset within
+    /// the response.
+    pub id: String,
+
+    /// The path of the asset, if it has one.
+    pub path: Option<String>,
+
+    /// The load state of the asset: `NotLoaded`, `Loading`, `Loaded` or `Failed`.
+    pub load_state: String,
+
+    /// The load state of the direct dependencies of the asset.
+    pub dependency_load_state: String,
+
+    /// The load state of the recursive dependencies of the asset.
+    pub recursive_dependency_load_state: String,
+
+    /// The error the asset failed to load with, if any.
+    pub error: Option<String>,
+
+    /// The IDs of the direct dependencies of the asset.
+    pub dependencies: Vec<String>,
+
+    /// The IDs of the assets that directly depend on this asset. These may
+    /// not be part of the graph.
+    pub dependents: Vec<String>,
+
+    /// The paths of the assets read by the loader of this asset. This is only
+    /// populated when the asset server is watching for changes.
+    pub loader_dependencies: Vec<String>,
+}
+
+#[cfg(feature = "bevy_asset")]
+impl From<&AssetDependencyNode> for BrpAssetDependencyNode {
+    fn from(node: &AssetDependencyNode) -> Self {
+        let error = match &node.load_state {
+            LoadState::Failed(error) => Some(error.to_string()),
+            _ => None,
+        };
+        let ids = |ids: &[UntypedAssetId]| {
+            let mut ids: Vec<_> = ids.iter().map(ToString::to_string).collect();
+            ids.sort();
+            ids
+        };
+        let mut loader_dependencies: Vec<_> = node
+            .loader_dependencies
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        loader_dependencies.sort();
+
+        BrpAssetDependencyNode {
+            id: node.id.to_string(),
+            path: node.path.as_ref().map(ToString::to_string),
+            load_state: match node.load_state {
+                LoadState::NotLoaded => "NotLoaded",
+                LoadState::Loading => "Loading",
+                LoadState::Loaded => "Loaded",
+                LoadState::Failed(_) => "Failed",
+            }
+            .to_owned(),
+            dependency_load_state: match node.dependency_load_state {
+                DependencyLoadState::NotLoaded => "NotLoaded",
+                DependencyLoadState::Loading => "Loading",
+                DependencyLoadState::Loaded => "Loaded",
+                DependencyLoadState::Failed(_) => "Failed",
+            }
+            .to_owned(),
+            recursive_dependency_load_state: match node.recursive_dependency_load_state {
+                RecursiveDependencyLoadState::NotLoaded => "NotLoaded",
+                RecursiveDependencyLoadState::Loading => "Loading",
+                RecursiveDependencyLoadState::Loaded => "Loaded",
+                RecursiveDependencyLoadState::Failed(_) => "Failed",
+            }
+            .to_owned(),
+            error,
+            dependencies: ids(&node.dependencies),
+            dependents: ids(&node.dependents),
+            loader_dependencies,
+        }
+    }
+}
+
+/// A skipped system in a [`BrpScheduleSkips`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSystemSkip {
+    /// The index of the system instance in the schedule.
+    pub index: usize,
+
+    /// The full name of the system.
+    pub name: String,
+
+    /// The number of times the system was skipped.
+    pub count: u32,
+
+    /// The change tick of the world when the system was last skipped.
+    pub last_tick: u32,
+
+    /// The full type name of the parameter that failed validation the last
+    /// time the system was skipped.
+    pub param: String,
+
+    /// The field of the parameter that failed validation, if it is a
+    /// `#[derive(SystemParam)]` struct, or an empty string.
+    pub field: String,
+
+    /// Why the parameter failed validation.
+    pub message: String,
+
+    /// Whether the parameter skips the system as part of its normal operation,
+    /// rather than treating the failed validation as an error.
+    pub skipped: bool,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -1248,6 +1533,239 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     serde_json::to_value(schemas).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/stepping/schedules` request coming from a client.
+pub fn process_remote_stepping_schedules_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let stepping = get_stepping(world)?;
+    let mut response = BrpSteppingSchedulesResponse::default();
+    let mut labels = Vec::new();
+
+    if let Some(schedules) = world.get_resource::<Schedules>() {
+        for (_, schedule) in schedules.iter() {
+            let label = schedule.label();
+            if !stepping.contains_schedule(label) {
+                continue;
+            }
+            let breakpoints: Vec<NodeId> = stepping.breakpoints(label).collect();
+            let systems = schedule
+                .systems()
+                .into_iter()
+                .flatten()
+                .map(|(node_id, system)| BrpSteppingSystemInfo {
+                    index: node_id.index(),
+                    name: system.name().into_owned(),
+                    breakpoint: breakpoints.contains(&node_id),
+                })
+                .collect();
+            labels.push(label);
+            response.push(BrpSteppingSchedule {
+                schedule: format!("{label:?}"),
+                systems,
+            });
+        }
+    }
+
+    // List the schedules in the order in which they run once it is known, and
+    // by name until then.
+    match stepping.schedules() {
+        Ok(order) => {
+            let position = |label| order.iter().position(|l| *l == label);
+            let mut schedules: Vec<_> = labels.into_iter().zip(response).collect();
+            schedules.sort_by_key(|(label, _)| position(*label));
+            response = schedules
+                .into_iter()
+                .map(|(_, schedule)| schedule)
+                .collect();
+        }
+        Err(_) => response.sort_by(|a, b| a.schedule.cmp(&b.schedule)),
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/enable` request coming from a client.
+///
+/// Inserts the [`Stepping`] resource if it isn't present.
+pub fn process_remote_stepping_enable_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingEnableParams { schedules } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let labels = schedules
+        .iter()
+        .map(|name| get_schedule(world, name).map(Schedule::label))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stepping = world.get_resource_or_init::<Stepping>();
+    for label in labels {
+        stepping.add_schedule(label);
+    }
+    stepping.enable();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/disable` request coming from a client.
+pub fn process_remote_stepping_disable_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.disable();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/set_breakpoint` request coming from a client.
+pub fn process_remote_stepping_set_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams { schedule, system } = parse_some(params)?;
+
+    let (label, nodes) = get_system_nodes(world, &schedule, &system)?;
+    let mut stepping = get_stepping_mut(world)?;
+    for node_id in nodes {
+        stepping.set_breakpoint_node(label, node_id);
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/clear_breakpoint` request coming from a client.
+pub fn process_remote_stepping_clear_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams { schedule, system } = parse_some(params)?;
+
+    let (label, nodes) = get_system_nodes(world, &schedule, &system)?;
+    let mut stepping = get_stepping_mut(world)?;
+    for node_id in nodes {
+        stepping.clear_breakpoint_node(label, node_id);
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step` request coming from a client.
+pub fn process_remote_stepping_step_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.step_frame();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/continue` request coming from a client.
+pub fn process_remote_stepping_continue_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.continue_frame();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/cursor` request coming from a client.
+pub fn process_remote_stepping_cursor_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let stepping = get_stepping(world)?;
+
+    let cursor = stepping.cursor().map(|(label, node_id)| {
+        // The schedule is removed from `Schedules` while it runs.
+        let name = world
+            .get_resource::<Schedules>()
+            .and_then(|schedules| schedules.get(label))
+            .and_then(|schedule| schedule.systems().ok())
+            .and_then(|mut systems| systems.find(|(id, _)| *id == node_id))
+            .map(|(_, system)| system.name().into_owned());
+        BrpSteppingCursor {
+            schedule: format!("{label:?}"),
+            index: node_id.index(),
+            name,
+        }
+    });
+
+    let response = BrpSteppingCursorResponse {
+        enabled: stepping.is_enabled(),
+        cursor,
+    };
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/system_skips` request coming from a client.
+pub fn process_remote_system_skips_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut response = BrpSystemSkipsResponse::default();
+    if let Some(skips) = world.get_resource::<SystemSkips>() {
+        for (label, schedule) in skips.iter() {
+            let systems = schedule
+                .systems()
+                .iter()
+                .map(|system| BrpSystemSkip {
+                    index: system.id.index(),
+                    name: system.name.clone().into_owned(),
+                    count: system.count,
+                    last_tick: system.last_tick.get(),
+                    param: system.reason.param.clone().into_owned(),
+                    field: system.reason.field.clone().into_owned(),
+                    message: system.reason.message.clone().into_owned(),
+                    skipped: system.reason.skipped,
+                })
+                .collect();
+            response.push(BrpScheduleSkips {
+                schedule: format!("{label:?}"),
+                systems,
+            });
+        }
+    }
+    response.sort_by(|a, b| a.schedule.cmp(&b.schedule));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/asset_dependencies` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_dependencies_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetDependenciesParams { path } = parse_some(params)?;
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present(type_name::<AssetServer>()))?;
+    let path = AssetPath::try_parse(&path).map_err(|err| BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message: err.to_string(),
+        data: None,
+    })?;
+
+    let response: BrpAssetDependenciesResponse = asset_server
+        .get_path_ids(path)
+        .into_iter()
+        .filter_map(|id| asset_server.get_dependency_graph(id))
+        .map(|graph| {
+            let mut nodes: Vec<_> = graph.iter().map(BrpAssetDependencyNode::from).collect();
+            nodes.sort_by(|a, b| (&a.path, &a.id).cmp(&(&b.path, &b.id)));
+            BrpAssetDependencyGraph {
+                root: graph.root().to_string(),
+                nodes,
+            }
+        })
+        .collect();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -1264,6 +1782,73 @@ fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_
         .map_err(|_| BrpError::entity_not_found(entity))
 }
 
+/// Immutably retrieves the [`Stepping`] resource, returning an error if it isn't
+/// present.
+fn get_stepping(world: &World) -> Result<&Stepping, BrpError> {
+    world
+        .get_resource::<Stepping>()
+        .ok_or_else(|| BrpError::resource_not_present(type_name::<Stepping>()))
+}
+
+/// Mutably retrieves the [`Stepping`] resource, returning an error if it isn't
+/// present.
+fn get_stepping_mut(world: &mut World) -> Result<Mut<'_, Stepping>, BrpError> {
+    world
+        .get_resource_mut::<Stepping>()
+        .ok_or_else(|| BrpError::resource_not_present(type_name::<Stepping>()))
+}
+
+/// Retrieves the schedule whose label is formatted as `name`, returning an
+/// error if it isn't present.
+///
+/// Note that schedules are not present while they are running.
+fn get_schedule<'w>(world: &'w World, name: &str) -> Result<&'w Schedule, BrpError> {
+    world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| {
+            schedules
+                .iter()
+                .find(|(label, _)| format!("{label:?}") == name)
+        })
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_not_found(name))
+}
+
+/// Returns the label of the schedule named `schedule` and the [`NodeId`]s of
+/// the instances of `system` in it, returning an error if there are none.
+fn get_system_nodes(
+    world: &World,
+    schedule: &str,
+    system: &BrpSteppingSystem,
+) -> Result<(InternedScheduleLabel, Vec<NodeId>), BrpError> {
+    let schedule_ref = get_schedule(world, schedule)?;
+    let nodes: Vec<NodeId> = schedule_ref
+        .systems()
+        .into_iter()
+        .flatten()
+        .filter(|(node_id, system_ref)| match system {
+            BrpSteppingSystem::Index(index) => node_id.index() == *index,
+            BrpSteppingSystem::Name(name) => {
+                let full_name = system_ref.name();
+                full_name == name.as_str()
+                    || full_name
+                        .strip_suffix(name.as_str())
+                        .is_some_and(|prefix| prefix.ends_with("::"))
+            }
+        })
+        .map(|(node_id, _)| node_id)
+        .collect();
+
+    if nodes.is_empty() {
+        let system = match system {
+            BrpSteppingSystem::Index(index) => format!("#{index}"),
+            BrpSteppingSystem::Name(name) => name.clone(),
+        };
+        return Err(BrpError::system_not_found(&system, schedule));
+    }
+    Ok((schedule_ref.label(), nodes))
+}
+
 /// Returns the [`TypeId`] and [`ComponentId`] of the components with the given
 /// full path names.
 ///
@@ -1512,5 +2097,244 @@ mod tests {
         test_serialize_deserialize(BrpListParams {
             entity: Entity::from_raw(0),
         });
+        test_serialize_deserialize(BrpSteppingBreakpointParams {
+            schedule: "Update".to_owned(),
+            system: BrpSteppingSystem::Index(0),
+        });
+        test_serialize_deserialize(BrpSteppingBreakpointParams {
+            schedule: "Update".to_owned(),
+            system: BrpSteppingSystem::Name("movement".to_owned()),
+        });
+    }
+
+    #[test]
+    fn stepping_breakpoints() {
+        use bevy_ecs::{
+            schedule::{IntoScheduleConfigs, ScheduleLabel},
+            system::RunSystemOnce,
+        };
+        use serde_json::json;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct TestSchedule;
+
+        fn first() {}
+        fn second() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems((first, second).chain());
+        schedule.initialize(&mut world).unwrap();
+        world.add_schedule(schedule);
+
+        let error = world
+            .run_system_once_with(process_remote_stepping_step_request, None)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_PRESENT);
+
+        let params = json!({ "schedules": ["TestSchedule"] });
+        world
+            .run_system_once_with(process_remote_stepping_enable_request, Some(params))
+            .unwrap()
+            .unwrap();
+        let params = json!({ "schedule": "TestSchedule", "system": "second" });
+        world
+            .run_system_once_with(process_remote_stepping_set_breakpoint_request, Some(params))
+            .unwrap()
+            .unwrap();
+        let params = json!({ "schedule": "TestSchedule", "system": "third" });
+        let error = world
+            .run_system_once_with(process_remote_stepping_set_breakpoint_request, Some(params))
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_FOUND);
+        let params = json!({ "schedule": "Update", "system": 0 });
+        let error = world
+            .run_system_once_with(
+                process_remote_stepping_clear_breakpoint_request,
+                Some(params),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::SCHEDULE_NOT_FOUND);
+
+        world.run_system_once(Stepping::begin_frame).unwrap();
+        let response = world
+            .run_system_once_with(process_remote_stepping_schedules_request, None)
+            .unwrap()
+            .unwrap();
+        let response: BrpSteppingSchedulesResponse = parse(response).unwrap();
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].schedule, "TestSchedule");
+        let breakpoints: Vec<_> = response[0]
+            .systems
+            .iter()
+            .map(|system| (system.name.rsplit("::").next().unwrap(), system.breakpoint))
+            .collect();
+        assert_eq!(breakpoints, [("first", false), ("second", true)]);
+
+        let params = json!({ "schedule": "TestSchedule", "system": response[0].systems[1].index });
+        world
+            .run_system_once_with(
+                process_remote_stepping_clear_breakpoint_request,
+                Some(params),
+            )
+            .unwrap()
+            .unwrap();
+        world.run_system_once(Stepping::begin_frame).unwrap();
+        let stepping = world.resource::<Stepping>();
+        assert_eq!(stepping.breakpoints(TestSchedule).count(), 0);
+    }
+
+    #[test]
+    fn system_skips() {
+        use bevy_ecs::{
+            component::Component,
+            schedule::ScheduleLabel,
+            system::{RunSystemOnce, Single},
+        };
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct TestSchedule;
+
+        #[derive(Component)]
+        struct Player;
+
+        fn needs_player(_: Single<&Player>) {}
+
+        let mut world = World::new();
+        world.init_resource::<SystemSkips>();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(needs_player);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let response = world
+            .run_system_once_with(process_remote_system_skips_request, None)
+            .unwrap()
+            .unwrap();
+        let response: BrpSystemSkipsResponse = parse(response).unwrap();
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].schedule, "TestSchedule");
+        let skip = &response[0].systems[0];
+        assert!(skip.name.ends_with("needs_player"));
+        assert_eq!(skip.count, 2);
+        assert!(skip.param.contains("Single"));
+        assert!(skip.skipped);
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_dependencies() {
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::{
+            io::{
+                memory::{Dir, MemoryAssetReader},
+                AssetSource, AssetSourceId, Reader,
+            },
+            Asset, AssetApp, AssetLoader, AssetPlugin, AsyncReadExt, Handle, LoadContext,
+        };
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_reflect::TypePath;
+        use std::path::Path;
+
+        /// An asset that depends on the assets whose paths are listed on its lines.
+        #[derive(Asset, TypePath)]
+        struct Lines(#[dependency] Vec<Handle<Lines>>);
+
+        struct LinesLoader;
+
+        impl AssetLoader for LinesLoader {
+            type Asset = Lines;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                reader: &mut dyn Reader,
+                _settings: &(),
+                load_context: &mut LoadContext<'_>,
+            ) -> Result<Lines, Self::Error> {
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                Ok(Lines(
+                    text.lines().map(|path| load_context.load(path)).collect(),
+                ))
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["lines"]
+            }
+        }
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.lines"), "b.lines\nc.lines");
+        dir.insert_asset_text(Path::new("b.lines"), "c.lines");
+        dir.insert_asset_text(Path::new("c.lines"), "");
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Lines>()
+        .register_asset_loader(LinesLoader);
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<Lines>("a.lines");
+        for _ in 0..10000 {
+            app.update();
+            if app
+                .world()
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&handle)
+            {
+                break;
+            }
+        }
+
+        let response = app
+            .world_mut()
+            .run_system_once_with(
+                process_remote_asset_dependencies_request,
+                Some(serde_json::json!({ "path": "a.lines" })),
+            )
+            .unwrap()
+            .unwrap();
+        let response: BrpAssetDependenciesResponse = parse(response).unwrap();
+        assert_eq!(response.len(), 1);
+        let graph = &response[0];
+        let paths: Vec<_> = graph
+            .nodes
+            .iter()
+            .map(|node| node.path.as_deref().unwrap())
+            .collect();
+        assert_eq!(paths, ["a.lines", "b.lines", "c.lines"]);
+        assert_eq!(graph.root, graph.nodes[0].id);
+        assert!(graph.nodes.iter().all(|node| node.load_state == "Loaded"
+            && node.recursive_dependency_load_state == "Loaded"
+            && node.error.is_none()));
+        assert_eq!(graph.nodes[0].dependencies.len(), 2);
+        assert!(graph.nodes[0].dependents.is_empty());
+        let mut c_dependents = graph.nodes[2].dependents.clone();
+        c_dependents.sort();
+        let mut expected = vec![graph.nodes[0].id.clone(), graph.nodes[1].id.clone()];
+        expected.sort();
+        assert_eq!(c_dependents, expected);
+
+        // Paths that aren't loaded have no graphs.
+        let response = app
+            .world_mut()
+            .run_system_once_with(
+                process_remote_asset_dependencies_request,
+                Some(serde_json::json!({ "path": "d.lines" })),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(response, Value::Array(Vec::new()));
     }
 }
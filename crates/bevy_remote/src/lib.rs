@@ -321,6 +321,135 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ## Stepping methods
+//!
+//! These methods drive the [`Stepping`] resource, which can pause the systems of selected
+//! schedules and run them one at a time. Schedules are named by the `Debug` representation of
+//! their label, e.g. `Update`, and the systems in them by their index or name. Stepping only has
+//! an effect when Bevy is compiled with the `bevy_debug_stepping` feature, and changes are
+//! applied at the start of the next frame.
+//!
+//! ### `bevy/stepping/schedules`
+//!
+//! List the schedules with stepping enabled and their systems. This method has no parameters.
+//!
+//! `result`: An array of objects, in the order in which the schedules run, each containing:
+//! - `schedule`: The name of the schedule.
+//! - `systems`: An array of objects, in the order in which the systems are stepped, each containing:
+//!   - `index`: The index of the system instance in the schedule.
+//!   - `name`: The full name of the system.
+//!   - `breakpoint`: Whether execution stops before this system when continuing.
+//!
+//! ### `bevy/stepping/enable`
+//!
+//! Pause the systems of the schedules with stepping enabled, inserting the [`Stepping`]
+//! resource if it isn't present.
+//!
+//! `params` (optional):
+//! - `schedules` (optional): An array of names of schedules to enable stepping for.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/disable`
+//!
+//! Resume normal execution of all systems. This method has no parameters.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/set_breakpoint`
+//!
+//! Stop execution before a system when continuing.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule containing the system.
+//! - `system`: Either the index of a system instance, or the name of a system, which refers to all
+//!   of its instances. Names may be full names, or the part following a `::`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/clear_breakpoint`
+//!
+//! Remove a breakpoint set with `bevy/stepping/set_breakpoint`.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule containing the system.
+//! - `system`: Either the index of a system instance, or the name of a system.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/step`
+//!
+//! Run the system at the cursor during the next frame. This method has no parameters.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/continue`
+//!
+//! Run the remaining systems of the stepping frame during the next frame, stopping before the
+//! next breakpoint. This method has no parameters.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/cursor`
+//!
+//! Report whether stepping is enabled, and the next system to be stepped. This method has no
+//! parameters.
+//!
+//! `result`:
+//! - `enabled`: Whether stepping is enabled.
+//! - `cursor`: null at the end of the stepping frame, otherwise an object containing:
+//!   - `schedule`: The name of the schedule containing the next system.
+//!   - `index`: The index of the system instance in the schedule.
+//!   - `name`: The full name of the system, or null if the schedule is running.
+//!
+//! ## Diagnostics methods
+//!
+//! ### `bevy/system_skips`
+//!
+//! List the systems that were skipped because their parameters failed validation, as recorded
+//! by the [`SystemSkips`] resource, which this plugin inserts. This method has no parameters.
+//!
+//! `result`: An array of objects, sorted by schedule name, each containing:
+//! - `schedule`: The name of the schedule.
+//! - `systems`: An array of objects, in the order in which the systems were first skipped, each
+//!   containing:
+//!   - `index`: The index of the system instance in the schedule.
+//!   - `name`: The full name of the system.
+//!   - `count`: The number of times the system was skipped.
+//!   - `last_tick`: The change tick of the world when the system was last skipped.
+//!   - `param`: The full type name of the parameter that failed validation.
+//!   - `field`: The field of the parameter that failed validation, or an empty string.
+//!   - `message`: Why the parameter failed validation.
+//!   - `skipped`: Whether the parameter skips the system as part of its normal operation, rather
+//!     than treating the failed validation as an error.
+//!
+//! ### `bevy/asset_dependencies`
+//!
+//! Retrieve the dependency graph of the assets loaded from a path, as reported by
+//! [`AssetServer::get_dependency_graph`]. This method is only available with the `bevy_asset`
+//! feature, and requires the [`AssetServer`] resource.
+//!
+//! `params`:
+//! - `path`: The path of the asset, including its asset source and label, if any.
+//!
+//! `result`: An array containing one object for every asset loaded from the path, since the path
+//! may be loaded as more than one asset type. The array is empty if the path isn't loaded. Each
+//! object contains:
+//! - `root`: The ID of the asset loaded from the path.
+//! - `nodes`: An array of objects for the asset and all of its recursive dependencies, sorted by
+//!   path, each containing:
+//!   - `id`: The ID of the asset. This is only meant to identify the asset within the result.
+//!   - `path`: The path of the asset, or null if it has none.
+//!   - `load_state`, `dependency_load_state`, `recursive_dependency_load_state`: The load states
+//!     of the asset, its direct dependencies and its recursive dependencies respectively, each
+//!     one of `NotLoaded`, `Loading`, `Loaded` or `Failed`.
+//!   - `error`: The error the asset failed to load with, or null.
+//!   - `dependencies`: The IDs of the direct dependencies of the asset.
+//!   - `dependents`: The IDs of the assets that directly depend on the asset. These may not be
+//!     part of the graph.
+//!   - `loader_dependencies`: The paths of the assets read by the loader of the asset. This is
+//!     only populated when the asset server is watching for changes.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -361,6 +490,10 @@
 //! handler system will always run with exclusive `World` access.
 //!
 //! [the `serde` documentation]: https://serde.rs/
+//! [`Stepping`]: bevy_ecs::schedule::Stepping
+//! [`SystemSkips`]: bevy_ecs::schedule::SystemSkips
+//! [`AssetServer`]: bevy_asset::AssetServer
+//! [`AssetServer::get_dependency_graph`]: bevy_asset::AssetServer::get_dependency_graph
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
 
@@ -370,7 +503,7 @@ use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     entity::Entity,
     resource::Resource,
-    schedule::{IntoScheduleConfigs, ScheduleLabel, SystemSet},
+    schedule::{IntoScheduleConfigs, ScheduleLabel, SystemSet, SystemSkips},
     system::{Commands, In, IntoSystem, ResMut, System, SystemId},
     world::World,
 };
@@ -438,7 +571,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -511,6 +644,48 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
             )
+            .with_method(
+                builtin_methods::BRP_STEPPING_SCHEDULES_METHOD,
+                builtin_methods::process_remote_stepping_schedules_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_ENABLE_METHOD,
+                builtin_methods::process_remote_stepping_enable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_DISABLE_METHOD,
+                builtin_methods::process_remote_stepping_disable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_SET_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_set_breakpoint_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CLEAR_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_clear_breakpoint_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STEP_METHOD,
+                builtin_methods::process_remote_stepping_step_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CONTINUE_METHOD,
+                builtin_methods::process_remote_stepping_continue_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CURSOR_METHOD,
+                builtin_methods::process_remote_stepping_cursor_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SYSTEM_SKIPS_METHOD,
+                builtin_methods::process_remote_system_skips_request,
+            );
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_ASSET_DEPENDENCIES_METHOD,
+            builtin_methods::process_remote_asset_dependencies_request,
+        );
+        plugin
     }
 }
 
@@ -540,6 +715,7 @@ impl Plugin for RemotePlugin {
 
         app.insert_resource(remote_methods)
             .init_resource::<RemoteWatchingRequests>()
+            .init_resource::<SystemSkips>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
                 RemoteLast,
@@ -824,6 +1000,26 @@ impl BrpError {
         }
     }
 
+    /// Schedule wasn't found.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// System wasn't found in a schedule.
+    #[must_use]
+    pub fn system_not_found(system: &str, schedule: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("System `{system}` not found in Schedule `{schedule}`"),
+            data: None,
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -874,6 +1070,12 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find schedule in the world.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23601;
+
+    /// Could not find system in schedule.
+    pub const SYSTEM_NOT_FOUND: i16 = -23602;
 }
 
 /// The result of a request.
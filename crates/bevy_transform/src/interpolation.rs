@@ -0,0 +1,329 @@
+//! Smooths the movement of entities whose [`Transform`] is updated in fixed timesteps.
+//!
+//! Simulations such as physics usually run in [`FixedUpdate`](bevy_app::FixedUpdate), which runs
+//! zero or more times per frame. Rendering the transform of the last fixed step as-is causes
+//! visible stutter whenever the frame rate and the fixed timestep don't line up.
+//!
+//! Adding [`TransformInterpolation`] or [`TransformExtrapolation`] to an entity records its
+//! [`Transform`] at the end of every fixed step, and replaces it for the rest of the frame with a
+//! blend based on [`Time<Fixed>::overstep_fraction`]. Before the fixed timestep runs again, the
+//! [`Transform`] of the last fixed step is restored, so simulation code never observes the
+//! blended value.
+//!
+//! Changes made to the [`Transform`] outside of the fixed timestep, and calls to
+//! [`TransformInterpolation::reset`] or [`TransformExtrapolation::reset`], are treated as
+//! teleports: the entity snaps to its new transform instead of being blended towards it.
+
+use crate::components::Transform;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::{Component, Mutable},
+    system::{Query, Res},
+};
+use bevy_math::Quat;
+use bevy_time::{Fixed, Time};
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// The transforms recorded for an entity at the end of the last two fixed steps.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct FixedStepTransforms {
+    /// The transform at the end of the second-to-last fixed step.
+    previous: Option<Transform>,
+    /// The transform at the end of the last fixed step.
+    current: Option<Transform>,
+    /// The blended transform written during the current frame.
+    rendered: Option<Transform>,
+    /// Whether the next fixed step should snap to its transform instead of blending towards it.
+    reset: bool,
+}
+
+/// Interpolates the [`Transform`] of an entity between its values at the end of the last two
+/// fixed timesteps, using [`Time<Fixed>::overstep_fraction`].
+///
+/// Interpolation is always smooth, but the rendered transform lags up to one fixed timestep
+/// behind the simulation. Use [`TransformExtrapolation`] to predict the movement instead.
+///
+/// An entity should have at most one of [`TransformInterpolation`] and
+/// [`TransformExtrapolation`]. See the [module docs](self) for details.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug, Clone)
+)]
+#[require(Transform)]
+pub struct TransformInterpolation {
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    steps: FixedStepTransforms,
+}
+
+impl TransformInterpolation {
+    /// Snaps the entity to the [`Transform`] it has at the end of the next fixed step, instead of
+    /// interpolating towards it. Call this when teleporting the entity from within the fixed
+    /// timestep.
+    pub fn reset(&mut self) {
+        self.steps.reset = true;
+    }
+}
+
+/// Extrapolates the [`Transform`] of an entity past its value at the end of the last fixed
+/// timestep, by continuing the movement of that step for [`Time<Fixed>::overstep_fraction`]
+/// of a timestep.
+///
+/// Extrapolation doesn't lag behind the simulation, but it overshoots when the movement of the
+/// entity changes abruptly. Use [`TransformInterpolation`] to avoid this.
+///
+/// An entity should have at most one of [`TransformInterpolation`] and
+/// [`TransformExtrapolation`]. See the [module docs](self) for details.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug, Clone)
+)]
+#[require(Transform)]
+pub struct TransformExtrapolation {
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    steps: FixedStepTransforms,
+}
+
+impl TransformExtrapolation {
+    /// Snaps the entity to the [`Transform`] it has at the end of the next fixed step, and stops
+    /// extrapolating until the step after it. Call this when teleporting the entity from within
+    /// the fixed timestep.
+    pub fn reset(&mut self) {
+        self.steps.reset = true;
+    }
+}
+
+/// A component that blends the [`Transform`] of its entity between fixed timesteps.
+pub(crate) trait FixedStepBlend: Component<Mutability = Mutable> {
+    /// Returns the transforms recorded for the entity.
+    fn steps_mut(&mut self) -> &mut FixedStepTransforms;
+
+    /// Blends the transforms of the last two fixed steps, `fraction` of a timestep past the last.
+    fn blend(previous: &Transform, current: &Transform, fraction: f32) -> Transform;
+}
+
+impl FixedStepBlend for TransformInterpolation {
+    fn steps_mut(&mut self) -> &mut FixedStepTransforms {
+        &mut self.steps
+    }
+
+    fn blend(previous: &Transform, current: &Transform, fraction: f32) -> Transform {
+        Transform {
+            translation: previous.translation.lerp(current.translation, fraction),
+            rotation: previous.rotation.slerp(current.rotation, fraction),
+            scale: previous.scale.lerp(current.scale, fraction),
+        }
+    }
+}
+
+impl FixedStepBlend for TransformExtrapolation {
+    fn steps_mut(&mut self) -> &mut FixedStepTransforms {
+        &mut self.steps
+    }
+
+    fn blend(previous: &Transform, current: &Transform, fraction: f32) -> Transform {
+        let rotation_delta = current.rotation * previous.rotation.inverse();
+        Transform {
+            translation: current.translation
+                + (current.translation - previous.translation) * fraction,
+            rotation: (Quat::IDENTITY.slerp(rotation_delta, fraction) * current.rotation)
+                .normalize(),
+            scale: current.scale + (current.scale - previous.scale) * fraction,
+        }
+    }
+}
+
+/// Restores the [`Transform`] of the last fixed step before the fixed timestep runs, replacing
+/// the blended transform of the previous frame.
+///
+/// If the [`Transform`] was changed since it was blended, the change is kept and treated as a
+/// teleport.
+pub(crate) fn restore_fixed_step_transforms<T: FixedStepBlend>(
+    mut query: Query<(&mut Transform, &mut T)>,
+) {
+    for (mut transform, mut blend) in &mut query {
+        let steps = blend.steps_mut();
+        match (steps.rendered.take(), steps.current) {
+            (Some(rendered), Some(current)) if *transform == rendered => {
+                // The blended transform is only visible outside of the fixed timestep, so
+                // restoring it isn't a change.
+                *transform.bypass_change_detection() = current;
+            }
+            _ => {
+                steps.previous = Some(*transform);
+                steps.current = Some(*transform);
+            }
+        }
+    }
+}
+
+/// Records the [`Transform`] of every entity at the end of a fixed step.
+pub(crate) fn record_fixed_step_transforms<T: FixedStepBlend>(
+    mut query: Query<(&Transform, &mut T)>,
+) {
+    for (transform, mut blend) in &mut query {
+        let steps = blend.steps_mut();
+        steps.previous = if core::mem::take(&mut steps.reset) {
+            Some(*transform)
+        } else {
+            steps.current.or(Some(*transform))
+        };
+        steps.current = Some(*transform);
+    }
+}
+
+/// Blends the [`Transform`] of every entity after the fixed timestep has run, using the
+/// [overstep fraction](Time::overstep_fraction) of [`Time<Fixed>`].
+pub(crate) fn blend_fixed_step_transforms<T: FixedStepBlend>(
+    time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut T)>,
+) {
+    let fraction = time.overstep_fraction();
+    for (mut transform, mut blend) in &mut query {
+        let steps = blend.steps_mut();
+        let (Some(previous), Some(current)) = (steps.previous, steps.current) else {
+            continue;
+        };
+        transform.set_if_neq(T::blend(&previous, &current, fraction));
+        steps.rendered = Some(*transform);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+    use bevy_math::{Quat, Vec3};
+    use bevy_time::{Fixed, Time, TimePlugin, TimeUpdateStrategy};
+    use core::{f32::consts::FRAC_PI_2, time::Duration};
+
+    #[derive(Resource, Default)]
+    struct Teleport(bool);
+
+    /// Moves every entity one unit along the x axis each fixed step, or to x = 100 when
+    /// teleporting.
+    fn movement(
+        mut teleport: ResMut<Teleport>,
+        mut query: Query<(
+            &mut Transform,
+            Option<&mut TransformInterpolation>,
+            Option<&mut TransformExtrapolation>,
+        )>,
+    ) {
+        for (mut transform, interpolation, extrapolation) in &mut query {
+            if teleport.0 {
+                transform.translation.x = 100.0;
+                if let Some(mut interpolation) = interpolation {
+                    interpolation.reset();
+                }
+                if let Some(mut extrapolation) = extrapolation {
+                    extrapolation.reset();
+                }
+            } else {
+                transform.translation.x += 1.0;
+                transform.rotate_z(FRAC_PI_2);
+            }
+        }
+        teleport.0 = false;
+    }
+
+    /// Creates an app with a fixed timestep of 100ms, that advances 50ms per update.
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .init_resource::<Teleport>()
+            .insert_resource(Time::<Fixed>::from_seconds(0.1))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                50,
+            )))
+            .add_systems(FixedUpdate, movement);
+        // Time doesn't advance during the first update.
+        app.update();
+        app
+    }
+
+    fn x(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Transform>(entity).unwrap().translation.x
+    }
+
+    fn assert_rotation(app: &App, entity: Entity, angle: f32) {
+        let rotation = app.world().get::<Transform>(entity).unwrap().rotation;
+        let expected = Quat::from_rotation_z(angle);
+        assert!((rotation * Vec3::X).abs_diff_eq(expected * Vec3::X, 1e-4));
+    }
+
+    #[test]
+    fn interpolation() {
+        let mut app = app();
+        let entity = app
+            .world_mut()
+            .spawn(TransformInterpolation::default())
+            .id();
+
+        // A fixed step runs every other update, so the overstep fraction alternates between 0.5
+        // and 0.0. Interpolation lags one step behind the simulation.
+        for expected in [0.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0] {
+            app.update();
+            assert!((x(&app, entity) - expected).abs() < 1e-4);
+        }
+        assert_rotation(&app, entity, FRAC_PI_2 * 3.0);
+
+        app.update();
+        assert_rotation(&app, entity, FRAC_PI_2 * 3.5);
+    }
+
+    #[test]
+    fn extrapolation() {
+        let mut app = app();
+        let entity = app
+            .world_mut()
+            .spawn(TransformExtrapolation::default())
+            .id();
+
+        // Movement at a constant speed is predicted exactly.
+        for expected in [0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0] {
+            app.update();
+            assert!((x(&app, entity) - expected).abs() < 1e-4);
+        }
+        assert_rotation(&app, entity, FRAC_PI_2 * 4.0);
+
+        app.update();
+        assert_rotation(&app, entity, FRAC_PI_2 * 4.5);
+    }
+
+    #[test]
+    fn teleport() {
+        let mut app = app();
+        let entity = app
+            .world_mut()
+            .spawn(TransformInterpolation::default())
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!((x(&app, entity) - 0.5).abs() < 1e-4);
+
+        // Teleporting within the fixed timestep snaps to the new transform.
+        app.world_mut().resource_mut::<Teleport>().0 = true;
+        app.update();
+        assert_eq!(x(&app, entity), 100.0);
+        app.update();
+        assert_eq!(x(&app, entity), 100.0);
+
+        // So does changing the transform outside of it.
+        app.world_mut()
+            .get_mut::<Transform>(entity)
+            .unwrap()
+            .translation = Vec3::new(-50.0, 0.0, 0.0);
+        app.update();
+        assert_eq!(x(&app, entity), -50.0);
+        app.update();
+        assert!((x(&app, entity) + 49.5).abs() < 1e-4);
+    }
+}
@@ -20,6 +20,9 @@ pub mod components;
 /// Transform related traits
 pub mod traits;
 
+#[cfg(feature = "bevy-support")]
+pub mod interpolation;
+
 /// Transform related plugins
 #[cfg(feature = "bevy-support")]
 pub mod plugins;
@@ -45,6 +48,7 @@ pub mod prelude {
     pub use crate::{
         commands::BuildChildrenTransformExt,
         helper::TransformHelper,
+        interpolation::{TransformExtrapolation, TransformInterpolation},
         plugins::{TransformPlugin, TransformSystem},
         traits::TransformPoint,
     };
@@ -1,6 +1,15 @@
-use crate::systems::{mark_dirty_trees, propagate_parent_transforms, sync_simple_transforms};
-use bevy_app::{App, Plugin, PostStartup, PostUpdate};
-use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use crate::{
+    interpolation::{
+        blend_fixed_step_transforms, record_fixed_step_transforms, restore_fixed_step_transforms,
+        TransformExtrapolation, TransformInterpolation,
+    },
+    systems::{mark_dirty_trees, propagate_parent_transforms, sync_simple_transforms},
+};
+use bevy_app::{
+    App, FixedLast, Plugin, PostStartup, PostUpdate, RunFixedMainLoop, RunFixedMainLoopSystem,
+};
+use bevy_ecs::schedule::{common_conditions::resource_exists, IntoScheduleConfigs, SystemSet};
+use bevy_time::{Fixed, Time};
 
 /// Set enum for the systems relating to transform propagation
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -18,7 +27,9 @@ impl Plugin for TransformPlugin {
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<crate::components::Transform>()
             .register_type::<crate::components::TransformTreeChanged>()
-            .register_type::<crate::components::GlobalTransform>();
+            .register_type::<crate::components::GlobalTransform>()
+            .register_type::<TransformInterpolation>()
+            .register_type::<TransformExtrapolation>();
 
         app
             // add transform systems to startup so the first update is "correct"
@@ -42,6 +53,31 @@ impl Plugin for TransformPlugin {
                 )
                     .chain()
                     .in_set(TransformSystem::TransformPropagate),
+            )
+            // blend the transforms of entities that move in fixed timesteps
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    restore_fixed_step_transforms::<TransformInterpolation>,
+                    restore_fixed_step_transforms::<TransformExtrapolation>,
+                )
+                    .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+            )
+            .add_systems(
+                FixedLast,
+                (
+                    record_fixed_step_transforms::<TransformInterpolation>,
+                    record_fixed_step_transforms::<TransformExtrapolation>,
+                ),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    blend_fixed_step_transforms::<TransformInterpolation>,
+                    blend_fixed_step_transforms::<TransformExtrapolation>,
+                )
+                    .run_if(resource_exists::<Time<Fixed>>)
+                    .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
             );
     }
 }